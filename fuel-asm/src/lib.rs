@@ -45,7 +45,10 @@ pub type RegisterId = usize;
 pub type Word = u64;
 
 pub use panic_instruction::PanicInstruction;
-pub use panic_reason::PanicReason;
+pub use panic_reason::{
+    PanicCategory,
+    PanicReason,
+};
 
 /// Represents a 6-bit register ID, guaranteed to be masked by construction.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -36,6 +36,11 @@ impl PanicInstruction {
     pub const fn instruction(&self) -> &RawInstruction {
         &self.instruction
     }
+
+    /// Underlying instruction decoded, or `None` if it isn't a valid instruction.
+    pub fn decoded_instruction(&self) -> Option<Instruction> {
+        Instruction::try_from(self.instruction).ok()
+    }
 }
 
 /// Helper struct to debug-format a `RawInstruction` in `PanicInstruction::fmt`.
@@ -133,4 +138,21 @@ mod tests {
             out_of_gas_panic_instruction.to_bytes()
         );
     }
+
+    #[test]
+    fn decoded_instruction_returns_the_panicking_instruction() {
+        let instruction = op::divi(0x10, 0x11, 0);
+        let panic_instruction =
+            PanicInstruction::error(PanicReason::ArithmeticError, instruction.into());
+
+        assert_eq!(panic_instruction.decoded_instruction(), Some(instruction));
+    }
+
+    #[test]
+    fn decoded_instruction_is_none_for_invalid_instruction() {
+        let panic_instruction =
+            PanicInstruction::error(PanicReason::InvalidInstruction, u32::MAX);
+
+        assert_eq!(panic_instruction.decoded_instruction(), None);
+    }
 }
@@ -131,7 +131,10 @@ enum_from! {
         /// Caller of this internal context is also expected to be internal,
         /// i.e. $fp->$fp must be non-zero.
         ExpectedNestedCaller = 0x2e,
-        /// During memory growth, the stack overlapped with the heap
+        /// During memory growth, the stack overlapped with the heap.
+        /// Raised when growing the stack past `$hp` or the heap below `$sp`,
+        /// unlike [`PanicReason::UninitalizedMemoryAccess`] that is raised when
+        /// accessing the unallocated memory between them.
         MemoryGrowthOverlap = 0x2f,
         /// Attempting to read or write uninitialized memory.
         /// Also occurs when boundary crosses from stack to heap.
@@ -168,6 +171,107 @@ enum_from! {
         InvalidUpgradePurposeType = 0x3f,
         /// Cannot get gas price in predicate
         CanNotGetGasPriceInPredicate = 0x40,
+        /// The call would exceed the maximum call depth of the interpreter.
+        MaxCallDepthReached = 0x41,
+    }
+}
+
+/// Coarse classification of a [`PanicReason`], see [`PanicReason::category`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum PanicCategory {
+    /// The program reverted on purpose with the `RVRT` instruction.
+    Revert,
+    /// The transaction or the program is invalid, e.g. it refers to a missing input,
+    /// contract or metadata field, or uses an instruction in the wrong context.
+    InvalidInput,
+    /// A resource limit was exhausted, e.g. the gas, the call depth or the balance.
+    Resource,
+    /// An arithmetic operation overflowed or received invalid arguments.
+    Arithmetic,
+    /// An access or allocation violated the memory rules of the VM.
+    Memory,
+    /// A cryptographic primitive received unsupported or invalid arguments.
+    Crypto,
+    /// The VM, the storage or an extension is in an unexpected state.
+    Internal,
+}
+
+impl PanicReason {
+    /// Returns the category of the panic reason.
+    ///
+    /// Useful to decide how to handle a panic, e.g. to reject the transaction or to
+    /// retry it with more gas, without matching on every reason.
+    pub const fn category(&self) -> PanicCategory {
+        match self {
+            Self::Revert => PanicCategory::Revert,
+            Self::TransactionValidity
+            | Self::ContractNotFound
+            | Self::ExpectedInternalContext
+            | Self::AssetIdNotFound
+            | Self::InputNotFound
+            | Self::OutputNotFound
+            | Self::WitnessNotFound
+            | Self::TransactionMaturity
+            | Self::InvalidMetadataIdentifier
+            | Self::MalformedCallStructure
+            | Self::ReservedRegisterNotWritable
+            | Self::InvalidFlags
+            | Self::InvalidImmediateValue
+            | Self::ExpectedCoinInput
+            | Self::ContractNotInInputs
+            | Self::TransferAmountCannotBeZero
+            | Self::ExpectedOutputVariable
+            | Self::ExpectedParentInternalContext
+            | Self::PredicateReturnedNonOne
+            | Self::ContractIdAlreadyDeployed
+            | Self::ContractMismatch
+            | Self::ContractInstructionNotAllowed
+            | Self::TransferZeroCoins
+            | Self::InvalidInstruction
+            | Self::PolicyIsNotSet
+            | Self::PolicyNotFound
+            | Self::InvalidBlockHeight
+            | Self::ExpectedNestedCaller
+            | Self::OverridingConsensusParameters
+            | Self::UnknownStateTransactionBytecodeRoot
+            | Self::OverridingStateTransactionBytecode
+            | Self::BytecodeAlreadyUploaded
+            | Self::ThePartIsNotSequentiallyConnected
+            | Self::BlobNotFound
+            | Self::BlobIdAlreadyUploaded
+            | Self::CanNotGetGasPriceInPredicate => PanicCategory::InvalidInput,
+            Self::OutOfGas
+            | Self::NotEnoughBalance
+            | Self::ContractMaxSize
+            | Self::MaxStaticContractsReached
+            | Self::MessageDataTooLong
+            | Self::TooManyReceipts
+            | Self::TooManySlots
+            | Self::MaxCallDepthReached => PanicCategory::Resource,
+            Self::ArithmeticOverflow
+            | Self::InternalBalanceOverflow
+            | Self::ArithmeticError
+            | Self::BalanceOverflow => PanicCategory::Arithmetic,
+            Self::MemoryOverflow
+            | Self::MemoryOwnership
+            | Self::MemoryWriteOverlap
+            | Self::ExpectedUnallocatedStack
+            | Self::MemoryNotExecutable
+            | Self::MemoryGrowthOverlap
+            | Self::UninitalizedMemoryAccess => PanicCategory::Memory,
+            Self::UnsupportedCurveId
+            | Self::UnsupportedOperationType
+            | Self::InvalidEllipticCurvePoint => PanicCategory::Crypto,
+            Self::UnknownPanicReason
+            | Self::EcalError
+            | Self::GasCostNotDefined
+            | Self::InputContractDoesNotExist
+            | Self::StorageSlotsNotFound
+            | Self::ProofInUploadNotFound
+            | Self::InvalidUpgradePurposeType => PanicCategory::Internal,
+        }
     }
 }
 
@@ -205,4 +309,30 @@ mod tests {
             assert_eq!(PanicReason::UnknownPanicReason as u8, i2);
         }
     }
+
+    #[test]
+    fn test_panic_reason_category() {
+        assert_eq!(PanicReason::Revert.category(), PanicCategory::Revert);
+        assert_eq!(PanicReason::OutOfGas.category(), PanicCategory::Resource);
+        assert_eq!(
+            PanicReason::ContractNotInInputs.category(),
+            PanicCategory::InvalidInput
+        );
+        assert_eq!(
+            PanicReason::ArithmeticOverflow.category(),
+            PanicCategory::Arithmetic
+        );
+        assert_eq!(
+            PanicReason::MemoryOverflow.category(),
+            PanicCategory::Memory
+        );
+        assert_eq!(
+            PanicReason::InvalidEllipticCurvePoint.category(),
+            PanicCategory::Crypto
+        );
+        assert_eq!(
+            PanicReason::UnknownPanicReason.category(),
+            PanicCategory::Internal
+        );
+    }
 }
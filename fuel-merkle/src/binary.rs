@@ -8,15 +8,22 @@ pub(crate) use hash::{
     empty_sum,
     node_sum,
 };
-pub(crate) use node::Node;
 
-pub use hash::leaf_sum;
+pub use hash::{
+    Hasher,
+    Sha256Hasher,
+    leaf_sum,
+};
 pub use merkle_tree::{
     MerkleTree,
     MerkleTreeError,
 };
+pub use node::Node;
 pub use primitive::Primitive;
 pub mod in_memory;
 pub mod root_calculator;
 
-pub use verify::verify;
+pub use verify::{
+    expected_proof_len,
+    verify,
+};
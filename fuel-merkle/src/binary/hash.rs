@@ -37,3 +37,27 @@ pub fn leaf_sum(data: &[u8]) -> Bytes32 {
 
     hash.finalize().into()
 }
+
+/// The hash functions used to compute the leaves and the internal nodes of a binary
+/// Merkle tree.
+pub trait Hasher {
+    /// Computes the hash of a leaf from its data.
+    fn hash_leaf(data: &[u8]) -> Bytes32;
+
+    /// Computes the hash of an internal node from the hashes of its children.
+    fn hash_node(lhs: &Bytes32, rhs: &Bytes32) -> Bytes32;
+}
+
+/// The default [`Hasher`], based on SHA-256 with the leaf and node prefixes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Sha256Hasher;
+
+impl Hasher for Sha256Hasher {
+    fn hash_leaf(data: &[u8]) -> Bytes32 {
+        leaf_sum(data)
+    }
+
+    fn hash_node(lhs: &Bytes32, rhs: &Bytes32) -> Bytes32 {
+        node_sum(lhs, rhs)
+    }
+}
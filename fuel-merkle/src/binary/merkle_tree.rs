@@ -1,7 +1,9 @@
 use crate::{
     binary::{
+        Hasher,
         Node,
         Primitive,
+        Sha256Hasher,
         empty_sum,
         in_memory::NodesTable,
     },
@@ -52,15 +54,23 @@ impl<StorageError> From<StorageError> for MerkleTreeError<StorageError> {
     }
 }
 
+/// The binary Merkle tree.
+///
+/// The leaves and the internal nodes are hashed with `HasherType`, which defaults to
+/// [`Sha256Hasher`]. The root of an empty tree is always [`Self::empty_root`].
 #[derive(Debug, Clone)]
-pub struct MerkleTree<TableType, StorageType> {
+pub struct MerkleTree<TableType, StorageType, HasherType = Sha256Hasher> {
     storage: StorageType,
     nodes: MerkleRootCalculator,
     leaves_count: u64,
     phantom_table: PhantomData<TableType>,
+    phantom_hasher: PhantomData<HasherType>,
 }
 
-impl<TableType, StorageType> MerkleTree<TableType, StorageType> {
+impl<TableType, StorageType, HasherType> MerkleTree<TableType, StorageType, HasherType>
+where
+    HasherType: Hasher,
+{
     pub const fn empty_root() -> &'static Bytes32 {
         empty_sum()
     }
@@ -80,6 +90,29 @@ impl<TableType, StorageType> MerkleTree<TableType, StorageType> {
         self.leaves_count
     }
 
+    /// Returns the MMR peaks of the tree, ordered from left to right, so they can be
+    /// persisted and passed to [`MerkleTree::load_from_peaks`] later. The peaks only
+    /// depend on the leaves of the tree.
+    pub fn export_peaks(&self) -> Vec<Primitive> {
+        self.nodes.stack().iter().map(Primitive::from).collect()
+    }
+
+    /// Returns the number of nodes persisted in storage for the current leaves count.
+    ///
+    /// Every leaf is persisted, along with every node at the head of a balanced
+    /// subtree. Each of the balanced subtrees with `k` leaves persists `2k - 1` nodes,
+    /// and there is one such subtree per bit set in the leaves count, so the total is
+    /// `2N - popcount(N)`.
+    pub fn persisted_node_count(&self) -> u64 {
+        let leaves_count = self.leaves_count;
+        // The leaves count is bounded by the in-order index of the leaves, so the
+        // result fits in a u64, and `popcount(N) <= N`.
+        #[allow(clippy::arithmetic_side_effects)]
+        {
+            leaves_count + (leaves_count - u64::from(leaves_count.count_ones()))
+        }
+    }
+
     /// The root node is generated by joining all MMR peaks, where a peak is
     /// defined as the head of a balanced subtree. A tree can be composed of a
     /// single balanced subtree, in which case the tree is itself balanced, or
@@ -112,7 +145,7 @@ impl<TableType, StorageType> MerkleTree<TableType, StorageType> {
                 .position()
                 .parent()
                 .map_err(|_| MerkleTreeError::TooLarge)?;
-            head = Node::create_node(parent, node, &head);
+            head = Node::create_node_with_hasher::<HasherType>(parent, node, &head);
             StorageMutateInfallible::insert(
                 scratch_storage,
                 &head.key(),
@@ -130,11 +163,42 @@ where
     StorageType: StorageInspect<TableType, Error = StorageError>,
 {
     pub fn new(storage: StorageType) -> Self {
+        Self::with_hasher(storage)
+    }
+
+    /// See [`Self::load_with_hasher`].
+    pub fn load(
+        storage: StorageType,
+        leaves_count: u64,
+    ) -> Result<Self, MerkleTreeError<StorageError>> {
+        Self::load_with_hasher(storage, leaves_count)
+    }
+
+    /// See [`Self::load_from_peaks_with_hasher`].
+    pub fn load_from_peaks(
+        storage: StorageType,
+        leaves_count: u64,
+        peaks: Vec<Node>,
+    ) -> Result<Self, MerkleTreeError<StorageError>> {
+        Self::load_from_peaks_with_hasher(storage, leaves_count, peaks)
+    }
+}
+
+impl<TableType, StorageType, HasherType, StorageError>
+    MerkleTree<TableType, StorageType, HasherType>
+where
+    TableType: Mappable<Key = u64, Value = Primitive, OwnedValue = Primitive>,
+    StorageType: StorageInspect<TableType, Error = StorageError>,
+    HasherType: Hasher,
+{
+    /// Creates an empty tree which hashes its nodes with `HasherType`.
+    pub fn with_hasher(storage: StorageType) -> Self {
         Self {
             storage,
             nodes: MerkleRootCalculator::new(),
             leaves_count: 0,
             phantom_table: Default::default(),
+            phantom_hasher: Default::default(),
         }
     }
 
@@ -217,7 +281,7 @@ where
     ///
     /// By excluding the root position `07`, we have established the set of
     /// side positions `03`, `09`, and `12`, matching our set of MMR peaks.
-    pub fn load(
+    pub fn load_with_hasher(
         storage: StorageType,
         leaves_count: u64,
     ) -> Result<Self, MerkleTreeError<StorageError>> {
@@ -238,6 +302,40 @@ where
             nodes: MerkleRootCalculator::new_with_stack(nodes),
             leaves_count,
             phantom_table: Default::default(),
+            phantom_hasher: Default::default(),
+        })
+    }
+
+    /// Build a tree from MMR peaks tracked by the caller, instead of loading
+    /// them from the storage like [`Self::load_with_hasher`] does. The `peaks` must be
+    /// ordered from left to right and match the peak positions for the given
+    /// `leaves_count`.
+    ///
+    /// Returns [`MerkleTreeError::LoadError`] with the key of the first
+    /// mismatching peak if the supplied peaks don't match the expected ones.
+    pub fn load_from_peaks_with_hasher(
+        storage: StorageType,
+        leaves_count: u64,
+        peaks: Vec<Node>,
+    ) -> Result<Self, MerkleTreeError<StorageError>> {
+        let positions = peak_positions(leaves_count).ok_or(MerkleTreeError::TooLarge)?;
+        for (index, position) in positions.iter().enumerate() {
+            let key = position.in_order_index();
+            match peaks.get(index) {
+                Some(peak) if peak.key() == key => {}
+                _ => return Err(MerkleTreeError::LoadError(key)),
+            }
+        }
+        if let Some(peak) = peaks.get(positions.len()) {
+            return Err(MerkleTreeError::LoadError(peak.key()))
+        }
+
+        Ok(Self {
+            storage,
+            nodes: MerkleRootCalculator::new_with_stack(peaks),
+            leaves_count,
+            phantom_table: Default::default(),
+            phantom_hasher: Default::default(),
         })
     }
 
@@ -291,36 +389,47 @@ where
     }
 }
 
-impl<TableType, StorageType, StorageError> MerkleTree<TableType, StorageType>
+impl<TableType, StorageType, HasherType, StorageError>
+    MerkleTree<TableType, StorageType, HasherType>
 where
     TableType: Mappable<Key = u64, Value = Primitive, OwnedValue = Primitive>,
     StorageType: StorageMutate<TableType, Error = StorageError>,
+    HasherType: Hasher,
 {
     /// Adds a new leaf node to the tree.
-    /// # WARNING
-    /// This code might modify the storage, and then return an error.
-    /// TODO: fix this issue
+    ///
+    /// The new nodes are computed before anything is written, so the storage is
+    /// left untouched if the tree is too large to accept another leaf. The tree
+    /// itself is only updated once all the new nodes are persisted.
     pub fn push(&mut self, data: &[u8]) -> Result<(), MerkleTreeError<StorageError>> {
-        let new_node = Node::create_leaf(self.leaves_count, data)
-            .ok_or(MerkleTreeError::TooLarge)?;
+        let new_node =
+            Node::create_leaf_with_hasher::<HasherType>(self.leaves_count, data)
+                .ok_or(MerkleTreeError::TooLarge)?;
+
+        let mut nodes = self.nodes.clone();
+        let mut created_nodes = Vec::new();
+        nodes
+            .push_with_callback::<HasherType, _, Infallible>(new_node, |node| {
+                created_nodes.push(node.clone());
+                Ok(())
+            })
+            .map_err(|err| match err {
+                NodeStackPushError::Callback(err) => match err {},
+                NodeStackPushError::TooLarge => MerkleTreeError::TooLarge,
+            })?;
 
+        for node in created_nodes.iter() {
+            self.storage.insert(&node.key(), &node.into())?;
+        }
+
+        self.nodes = nodes;
         // u64 cannot overflow, as memory is finite
         #[allow(clippy::arithmetic_side_effects)]
         {
             self.leaves_count += 1;
         }
 
-        self.nodes
-            .push_with_callback(new_node, |node| {
-                self.storage
-                    .insert(&node.key(), &node.into())
-                    .map_err(MerkleTreeError::StorageError)
-                    .map(|_| ())
-            })
-            .map_err(|err| match err {
-                NodeStackPushError::Callback(err) => err,
-                NodeStackPushError::TooLarge => MerkleTreeError::TooLarge,
-            })
+        Ok(())
     }
 }
 
@@ -362,13 +471,19 @@ mod test {
     };
     use crate::{
         binary::{
+            Hasher,
             Node,
             Primitive,
+            Sha256Hasher,
             empty_sum,
             leaf_sum,
             node_sum,
+            root_calculator::MerkleRootCalculator,
+        },
+        common::{
+            Bytes32,
+            StorageMap,
         },
-        common::StorageMap,
     };
     use fuel_merkle_test_helpers::TEST_DATA;
     use fuel_storage::{
@@ -377,7 +492,10 @@ mod test {
         StorageMutate,
     };
 
-    use alloc::vec::Vec;
+    use alloc::{
+        borrow::Cow,
+        vec::Vec,
+    };
 
     #[derive(Debug)]
     struct TestTable;
@@ -451,6 +569,22 @@ mod test {
         assert_eq!(*Node::from(s_node_3.into_owned()).hash(), node_3);
     }
 
+    #[test]
+    fn persisted_node_count_returns_the_number_of_nodes_in_storage() {
+        let mut storage_map = StorageMap::<TestTable>::new();
+        let mut tree = MerkleTree::new(&mut storage_map);
+
+        let data = &TEST_DATA[0..7]; // 7 leaves
+        for datum in data.iter() {
+            tree.push(datum).unwrap();
+        }
+
+        // The leaves 00, 02, 04, 06, 08, 10, 12 and the nodes 01, 03, 05, 09
+        let persisted_node_count = tree.persisted_node_count();
+        assert_eq!(persisted_node_count, 11);
+        assert_eq!(persisted_node_count, storage_map.len() as u64);
+    }
+
     #[test]
     fn load_returns_a_valid_tree() {
         const LEAVES_COUNT: u64 = 2u64.pow(16) - 1;
@@ -510,6 +644,97 @@ mod test {
         assert!(matches!(err, MerkleTreeError::LoadError(_)));
     }
 
+    #[test]
+    fn load_from_peaks_returns_a_valid_tree() {
+        const LEAVES_COUNT: usize = 7;
+
+        let mut storage_map = StorageMap::<TestTable>::new();
+        let mut tree = MerkleTree::new(&mut storage_map);
+        for datum in TEST_DATA[0..LEAVES_COUNT].iter() {
+            let _ = tree.push(datum);
+        }
+        let expected_root = tree.root();
+        let peaks = tree.nodes.stack().clone();
+
+        let mut tree =
+            MerkleTree::load_from_peaks(&mut storage_map, LEAVES_COUNT as u64, peaks)
+                .unwrap();
+        assert_eq!(expected_root, tree.root());
+
+        // The loaded tree can be extended and proven like a regular one
+        let _ = tree.push(TEST_DATA[LEAVES_COUNT]);
+        let mut expected_storage_map = StorageMap::<TestTable>::new();
+        let mut expected_tree = MerkleTree::new(&mut expected_storage_map);
+        for datum in TEST_DATA[0..=LEAVES_COUNT].iter() {
+            let _ = expected_tree.push(datum);
+        }
+        assert_eq!(expected_tree.root(), tree.root());
+        assert_eq!(expected_tree.prove(3).unwrap(), tree.prove(3).unwrap());
+    }
+
+    #[test]
+    fn export_peaks_round_trips_through_load_from_peaks() {
+        const LEAVES_COUNT: usize = 7;
+
+        let mut storage_map = StorageMap::<TestTable>::new();
+        let mut tree = MerkleTree::new(&mut storage_map);
+        for datum in TEST_DATA[0..LEAVES_COUNT].iter() {
+            let _ = tree.push(datum);
+        }
+        let expected_root = tree.root();
+
+        let serialized: Vec<u8> = tree
+            .export_peaks()
+            .iter()
+            .flat_map(|(key, hash)| key.to_be_bytes().into_iter().chain(*hash))
+            .collect();
+        let peaks = serialized
+            .chunks_exact(8 + 32)
+            .map(|chunk| {
+                let key = u64::from_be_bytes(chunk[..8].try_into().unwrap());
+                let hash: Bytes32 = chunk[8..].try_into().unwrap();
+                Node::from((key, hash))
+            })
+            .collect();
+
+        let tree =
+            MerkleTree::load_from_peaks(&mut storage_map, LEAVES_COUNT as u64, peaks)
+                .unwrap();
+        assert_eq!(expected_root, tree.root());
+        let keys: Vec<_> = tree.export_peaks().iter().map(|(key, _)| *key).collect();
+        assert_eq!(keys, [3, 9, 12]);
+    }
+
+    #[test]
+    fn load_from_peaks_returns_a_load_error_if_the_peak_count_is_not_valid() {
+        const LEAVES_COUNT: u64 = 7;
+        let data = &TEST_DATA[0..7];
+
+        let mut storage_map = StorageMap::<TestTable>::new();
+        let mut tree = MerkleTree::new(&mut storage_map);
+        for datum in data.iter() {
+            let _ = tree.push(datum);
+        }
+        let peaks = tree.nodes.stack().clone();
+
+        let mut missing_peak = peaks.clone();
+        let expected_key = missing_peak.pop().unwrap().key();
+        let err =
+            MerkleTree::load_from_peaks(&mut storage_map, LEAVES_COUNT, missing_peak)
+                .expect_err("Expected load_from_peaks() to return Error; got Ok");
+        assert_eq!(err, MerkleTreeError::LoadError(expected_key));
+
+        let mut extra_peak = peaks.clone();
+        extra_peak.push(Node::create_leaf(LEAVES_COUNT, TEST_DATA[0]).unwrap());
+        let err = MerkleTree::load_from_peaks(&mut storage_map, LEAVES_COUNT, extra_peak)
+            .expect_err("Expected load_from_peaks() to return Error; got Ok");
+        assert_eq!(err, MerkleTreeError::LoadError(LEAVES_COUNT * 2));
+
+        let err = MerkleTree::load_from_peaks(&mut storage_map, LEAVES_COUNT + 1, peaks)
+            .expect_err("Expected load_from_peaks() to return Error; got Ok");
+        assert!(matches!(err, MerkleTreeError::LoadError(_)));
+    }
+
     #[test]
     fn root_returns_the_empty_root_for_0_leaves() {
         let mut storage_map = StorageMap::<TestTable>::new();
@@ -579,6 +804,64 @@ mod test {
         assert_eq!(root, node_7);
     }
 
+    #[test]
+    fn root_with_the_default_hasher_matches_root() {
+        let data = &TEST_DATA[0..7]; // 7 leaves
+        let mut storage_map = StorageMap::<TestTable>::new();
+        let mut tree = MerkleTree::new(&mut storage_map);
+        let mut hasher_storage_map = StorageMap::<TestTable>::new();
+        let mut hasher_tree =
+            MerkleTree::<_, _, Sha256Hasher>::with_hasher(&mut hasher_storage_map);
+
+        for datum in data.iter() {
+            tree.push(datum).unwrap();
+            hasher_tree.push(datum).unwrap();
+        }
+
+        assert_eq!(hasher_tree.root(), tree.root());
+    }
+
+    #[test]
+    fn root_uses_the_custom_hasher() {
+        /// Hashes the leaves with a domain prefix and swaps the children of the nodes.
+        #[derive(Debug, Clone)]
+        struct DomainHasher;
+
+        impl Hasher for DomainHasher {
+            fn hash_leaf(data: &[u8]) -> Bytes32 {
+                leaf_sum(&[b"domain".as_slice(), data].concat())
+            }
+
+            fn hash_node(lhs: &Bytes32, rhs: &Bytes32) -> Bytes32 {
+                node_sum(rhs, lhs)
+            }
+        }
+
+        let data = &TEST_DATA[0..3]; // 3 leaves
+        let mut storage_map = StorageMap::<TestTable>::new();
+        let mut tree = MerkleTree::<_, _, DomainHasher>::with_hasher(&mut storage_map);
+        for datum in data.iter() {
+            tree.push(datum).unwrap();
+        }
+
+        //       03
+        //      /  \
+        //    01    \
+        //   /  \    \
+        // 00  02   04
+        let leaf_0 = DomainHasher::hash_leaf(data[0]);
+        let leaf_1 = DomainHasher::hash_leaf(data[1]);
+        let leaf_2 = DomainHasher::hash_leaf(data[2]);
+        let node_1 = DomainHasher::hash_node(&leaf_0, &leaf_1);
+        let node_3 = DomainHasher::hash_node(&node_1, &leaf_2);
+
+        assert_eq!(tree.root(), node_3);
+        assert_ne!(
+            tree.root(),
+            MerkleRootCalculator::new().root_from_iterator(data.iter())
+        );
+    }
+
     #[test]
     fn prove_returns_invalid_proof_index_error_for_0_leaves() {
         let mut storage_map = StorageMap::<TestTable>::new();
@@ -908,4 +1191,107 @@ mod test {
         // Then
         assert_eq!(result, Err(MerkleTreeError::TooLarge));
     }
+
+    #[test]
+    fn push_does_not_modify_storage_on_overflow() {
+        // Given
+        let mut storage_map = StorageMap::<TestTable>::new();
+        const LEAVES_COUNT: u64 = u64::MAX / 2;
+        loop {
+            let result = MerkleTree::load(&mut storage_map, LEAVES_COUNT).map(|_| ());
+
+            if let Err(MerkleTreeError::LoadError(index)) = result {
+                storage_map.insert(&index, &Primitive::default()).unwrap();
+            } else {
+                break;
+            }
+        }
+        let mut tree = MerkleTree::load(storage_map, LEAVES_COUNT)
+            .expect("Expected `load()` to succeed");
+        tree.push(&[]).expect("Expected `push()` to succeed");
+        let nodes_count = tree.storage.len();
+        let root = tree.root();
+
+        // When
+        let result = tree.push(&[]);
+
+        // Then
+        assert_eq!(result, Err(MerkleTreeError::TooLarge));
+        assert_eq!(tree.storage.len(), nodes_count);
+        assert_eq!(tree.leaves_count(), LEAVES_COUNT + 1);
+        assert_eq!(tree.root(), root);
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct InsertLimitReached;
+
+    /// Storage that fails all inserts after `inserts_left` successful ones.
+    struct LimitedStorage {
+        map: StorageMap<TestTable>,
+        inserts_left: usize,
+    }
+
+    impl StorageInspect<TestTable> for LimitedStorage {
+        type Error = InsertLimitReached;
+
+        fn get(&self, key: &u64) -> Result<Option<Cow<'_, Primitive>>, Self::Error> {
+            Ok(self.map.get(key).unwrap())
+        }
+
+        fn contains_key(&self, key: &u64) -> Result<bool, Self::Error> {
+            Ok(self.map.contains_key(key).unwrap())
+        }
+    }
+
+    impl StorageMutate<TestTable> for LimitedStorage {
+        fn replace(
+            &mut self,
+            key: &u64,
+            value: &Primitive,
+        ) -> Result<Option<Primitive>, Self::Error> {
+            self.inserts_left =
+                self.inserts_left.checked_sub(1).ok_or(InsertLimitReached)?;
+            Ok(self.map.replace(key, value).unwrap())
+        }
+
+        fn take(&mut self, key: &u64) -> Result<Option<Primitive>, Self::Error> {
+            Ok(self.map.take(key).unwrap())
+        }
+    }
+
+    #[test]
+    fn push_does_not_modify_the_tree_on_storage_error() {
+        // Given
+        let storage = LimitedStorage {
+            map: StorageMap::new(),
+            inserts_left: 3,
+        };
+        let mut tree = MerkleTree::new(storage);
+        // Pushing the first leaf writes 1 node, the second one writes 2 nodes
+        tree.push(TEST_DATA[0]).unwrap();
+        tree.push(TEST_DATA[1]).unwrap();
+        let result = tree.push(TEST_DATA[2]);
+        assert_eq!(
+            result,
+            Err(MerkleTreeError::StorageError(InsertLimitReached))
+        );
+        // The third leaf writes 1 node, the fourth one writes 3 nodes
+        tree.storage.inserts_left = 2;
+        tree.push(TEST_DATA[2]).unwrap();
+
+        // When
+        let result = tree.push(TEST_DATA[3]);
+
+        // Then
+        assert_eq!(
+            result,
+            Err(MerkleTreeError::StorageError(InsertLimitReached))
+        );
+        assert_eq!(tree.leaves_count(), 3);
+        let mut expected_tree = MerkleTree::new(StorageMap::<TestTable>::new());
+        for datum in TEST_DATA[0..3].iter() {
+            expected_tree.push(datum).unwrap();
+        }
+        assert_eq!(tree.root(), expected_tree.root());
+    }
 }
@@ -1,7 +1,7 @@
 use crate::{
     binary::{
-        leaf_sum,
-        node_sum,
+        Hasher,
+        Sha256Hasher,
     },
     common::{
         Bytes32,
@@ -24,8 +24,13 @@ impl Node {
 
     /// Returns `None` if the leaf cannot be created due to incorrect position.
     pub fn create_leaf(index: u64, data: &[u8]) -> Option<Self> {
+        Self::create_leaf_with_hasher::<Sha256Hasher>(index, data)
+    }
+
+    /// Same as [`Self::create_leaf`], but hashes the data with `H`.
+    pub fn create_leaf_with_hasher<H: Hasher>(index: u64, data: &[u8]) -> Option<Self> {
         let position = Position::from_leaf_index(index)?;
-        let hash = leaf_sum(data);
+        let hash = H::hash_leaf(data);
         Some(Self { position, hash })
     }
 
@@ -41,7 +46,16 @@ impl Node {
         left_child: &Self,
         right_child: &Self,
     ) -> Self {
-        let hash = node_sum(left_child.hash(), right_child.hash());
+        Self::create_node_with_hasher::<Sha256Hasher>(position, left_child, right_child)
+    }
+
+    /// Same as [`Self::create_node`], but hashes the children with `H`.
+    pub fn create_node_with_hasher<H: Hasher>(
+        position: Position,
+        left_child: &Self,
+        right_child: &Self,
+    ) -> Self {
+        let hash = H::hash_node(left_child.hash(), right_child.hash());
         Self { position, hash }
     }
 
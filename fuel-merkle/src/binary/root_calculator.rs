@@ -2,7 +2,9 @@ use core::convert::Infallible;
 
 use crate::{
     binary::{
+        Hasher,
         Node,
+        Sha256Hasher,
         empty_sum,
     },
     common::Bytes32,
@@ -36,7 +38,7 @@ impl MerkleRootCalculator {
         let mut calculator = Self::new();
         leaf_hashes.for_each(|leaf| {
             calculator
-                .push_with_callback::<_, Infallible>(
+                .push_with_callback::<Sha256Hasher, _, Infallible>(
                     Node::create_leaf_with_hash(0, leaf)
                         .expect("Zero is a valid index for a leaf; qed"),
                     |_| Ok(()),
@@ -52,12 +54,13 @@ impl MerkleRootCalculator {
 
     /// Push a leaf to stack of nodes, propagating changes through the tree.
     /// Calls `node_created` for each new node created, stopping on first error.
-    pub(crate) fn push_with_callback<F, E>(
+    pub(crate) fn push_with_callback<H, F, E>(
         &mut self,
         node: Node,
         mut node_created: F,
     ) -> Result<(), NodeStackPushError<E>>
     where
+        H: Hasher,
         F: FnMut(&Node) -> Result<(), E>,
     {
         node_created(&node).map_err(NodeStackPushError::Callback)?;
@@ -76,7 +79,7 @@ impl MerkleRootCalculator {
                 .position()
                 .parent()
                 .map_err(|_| NodeStackPushError::TooLarge)?;
-            let new = Node::create_node(parent_pos, lhs, rhs);
+            let new = Node::create_node_with_hasher::<H>(parent_pos, lhs, rhs);
             node_created(&new).map_err(NodeStackPushError::Callback)?;
             let _ = self.stack.pop();
             let _ = self.stack.pop();
@@ -91,25 +94,30 @@ impl MerkleRootCalculator {
     /// In practice this never occurs, as you'd run out of memory first.
     pub fn push(&mut self, data: &[u8]) {
         let node = Node::create_leaf(0, data).expect("Zero is a valid index for a leaf");
-        self.push_with_callback::<_, Infallible>(node, |_| Ok(()))
+        self.push_with_callback::<Sha256Hasher, _, Infallible>(node, |_| Ok(()))
             .expect("Tree too large");
     }
 
-    pub fn root(mut self) -> Bytes32 {
-        if self.stack.is_empty() {
+    pub fn root(self) -> Bytes32 {
+        self.current_root()
+    }
+
+    /// Compute the root of the leaves pushed so far, without consuming the
+    /// calculator. More leaves can be pushed afterwards.
+    pub fn current_root(&self) -> Bytes32 {
+        let mut peaks = self.stack.iter().rev();
+        let Some(last_peak) = peaks.next() else {
             return empty_sum().to_owned()
-        }
-        while self.stack.len() > 1 {
-            let right_child = self.stack.pop().expect("Checked in loop bound");
-            let left_child = self.stack.pop().expect("Checked in loop bound");
+        };
+        let mut current = last_peak.clone();
+        for left_child in peaks {
             let merged_pos = left_child
                 .position()
                 .parent()
                 .expect("Left child has no parent");
-            let merged_node = Node::create_node(merged_pos, &left_child, &right_child);
-            self.stack.push(merged_node);
+            current = Node::create_node(merged_pos, left_child, &current);
         }
-        self.stack.pop().unwrap().hash().to_owned()
+        current.hash().to_owned()
     }
 
     pub fn root_from_iterator<I: Iterator<Item = T>, T: AsRef<[u8]>>(
@@ -217,6 +225,21 @@ mod test {
         assert_eq!(new_calculate_root.root(), root);
     }
 
+    #[test]
+    fn current_root_returns_the_merkle_root_after_each_push() {
+        let mut tree = MerkleTree::new();
+        let mut calculate_root = MerkleRootCalculator::new();
+        assert_eq!(tree.root(), calculate_root.current_root());
+
+        for datum in TEST_DATA.iter() {
+            tree.push(datum);
+            calculate_root.push(datum);
+            assert_eq!(tree.root(), calculate_root.current_root());
+        }
+
+        assert_eq!(tree.root(), calculate_root.root());
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn test_serialize_deserialize() {
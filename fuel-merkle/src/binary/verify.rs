@@ -43,6 +43,23 @@ fn path_length_from_key(key: u64, num_leaves: u64) -> Option<usize> {
     path_length_from_key(subtree_key, subtree_leaves)?.checked_add(1)
 }
 
+/// Returns the number of elements a valid proof set must contain for the leaf at
+/// `index` in a tree with `leaves_count` leaves, following the same path as
+/// `MerkleTree::prove`. Returns `None` if `index` is out of range.
+///
+/// This allows rejecting a proof set of the wrong length before any hashing.
+pub fn expected_proof_len(leaves_count: u64, index: u64) -> Option<usize> {
+    if index >= leaves_count {
+        return None;
+    }
+
+    if leaves_count == 1 {
+        return Some(0);
+    }
+
+    path_length_from_key(index, leaves_count)
+}
+
 pub fn verify<T: AsRef<[u8]>>(
     root: &Bytes32,
     data: &T,
@@ -50,15 +67,7 @@ pub fn verify<T: AsRef<[u8]>>(
     proof_index: u64,
     num_leaves: u64,
 ) -> bool {
-    if num_leaves <= 1 {
-        if !proof_set.is_empty() {
-            return false;
-        }
-    } else if Some(proof_set.len()) != path_length_from_key(proof_index, num_leaves) {
-        return false;
-    }
-
-    if proof_index >= num_leaves {
+    if Some(proof_set.len()) != expected_proof_len(num_leaves, proof_index) {
         return false;
     }
 
@@ -132,7 +141,10 @@ pub fn verify<T: AsRef<[u8]>>(
 
 #[cfg(test)]
 mod test {
-    use super::verify;
+    use super::{
+        expected_proof_len,
+        verify,
+    };
     use crate::{
         binary::{
             MerkleTree,
@@ -260,4 +272,56 @@ mod test {
         );
         assert!(!verification);
     }
+
+    #[test]
+    fn expected_proof_len_matches_the_length_of_generated_proofs() {
+        for leaves_count in 1..=TEST_DATA.len() {
+            let mut storage_map = StorageMap::<TestTable>::new();
+            let mut tree = MerkleTree::new(&mut storage_map);
+            for datum in &TEST_DATA[0..leaves_count] {
+                tree.push(datum).unwrap();
+            }
+
+            for index in 0..leaves_count as u64 {
+                let (_, proof_set) = tree.prove(index).unwrap();
+                assert_eq!(
+                    expected_proof_len(leaves_count as u64, index),
+                    Some(proof_set.len()),
+                    "leaves_count: {leaves_count}, index: {index}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn expected_proof_len_returns_none_for_out_of_range_index() {
+        assert_eq!(expected_proof_len(0, 0), None);
+        assert_eq!(expected_proof_len(5, 5), None);
+        assert_eq!(expected_proof_len(5, u64::MAX), None);
+    }
+
+    #[test]
+    fn verify_returns_false_when_the_proof_set_is_padded() {
+        let mut storage_map = StorageMap::<TestTable>::new();
+        let mut tree = MerkleTree::new(&mut storage_map);
+
+        const PROOF_INDEX: usize = 2;
+        const LEAVES_COUNT: usize = 5;
+
+        for datum in &TEST_DATA[0..LEAVES_COUNT] {
+            tree.push(datum).unwrap();
+        }
+
+        let (root, mut proof_set) = tree.prove(PROOF_INDEX as u64).unwrap();
+        proof_set.push(Default::default());
+
+        let verification = verify(
+            &root,
+            &TEST_DATA[PROOF_INDEX],
+            &proof_set,
+            PROOF_INDEX as u64,
+            LEAVES_COUNT as u64,
+        );
+        assert!(!verification);
+    }
 }
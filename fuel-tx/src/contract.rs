@@ -115,6 +115,19 @@ impl Contract {
     ///
     /// <https://github.com/FuelLabs/fuel-specs/blob/master/src/identifiers/contract-id.md>
     pub fn id(&self, salt: &Salt, root: &Bytes32, state_root: &Bytes32) -> ContractId {
+        Self::compute_id(salt, root, state_root)
+    }
+
+    /// Calculate the contract id of the `bytecode` deployed with the given salt and
+    /// state root, without building a [`Contract`] first.
+    ///
+    /// The result is the same id a `Create` transaction deploying `bytecode` produces.
+    pub fn predict_id(bytecode: &[u8], salt: &Salt, state_root: &Bytes32) -> ContractId {
+        let root = Self::root_from_code(bytecode);
+        Self::compute_id(salt, &root, state_root)
+    }
+
+    fn compute_id(salt: &Salt, root: &Bytes32, state_root: &Bytes32) -> ContractId {
         let mut hasher = Hasher::default();
 
         hasher.input(ContractId::SEED);
@@ -229,6 +242,16 @@ mod tests {
         code_root == contract_root
     }
 
+    #[quickcheck]
+    fn predict_id_matches_contract_id(code: Vec<u8>, salt: [u8; 32]) -> bool {
+        let salt = Salt::from(salt);
+        let state_root = Contract::default_state_root();
+        let contract = Contract::from(code.clone());
+        let expected = contract.id(&salt, &contract.root(), &state_root);
+
+        Contract::predict_id(&code, &salt, &state_root) == expected
+    }
+
     #[rstest]
     fn state_root_snapshot(
         #[values(Vec::new(), vec![Bytes64::new([1u8; 64])])] state_slot_bytes: Vec<
@@ -88,7 +88,7 @@ fn main() {
                                 .unwrap_or_else(|| "???".to_owned()),
                         );
                     }
-                    DebugEval::Continue => {}
+                    DebugEval::Continue | DebugEval::LogMatch(_) => {}
                 }
                 t = vm.resume().expect("panicked");
             }
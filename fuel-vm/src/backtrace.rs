@@ -21,6 +21,10 @@ use crate::interpreter::{
     Memory,
     MemoryInstance,
 };
+use fuel_asm::{
+    Instruction,
+    PanicReason,
+};
 use fuel_tx::ScriptExecutionResult;
 use fuel_types::{
     ContractId,
@@ -128,3 +132,40 @@ impl Backtrace {
         )
     }
 }
+
+/// The location of the instruction that produced a `Panic` receipt, see
+/// [`crate::transactor::Transactor::decode_panic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PanicDetail {
+    /// The contract that panicked, or `None` if the panic happened in the script.
+    pub contract_id: Option<ContractId>,
+    /// The offset of the instruction in the bytecode of the contract or of the
+    /// script, in bytes.
+    pub offset: Word,
+    /// The instruction that panicked.
+    pub instruction: Instruction,
+    /// The reason of the panic.
+    pub reason: PanicReason,
+}
+
+impl PanicDetail {
+    /// Decodes the instruction at `offset` of the `bytecode`, if any.
+    pub(crate) fn decode(
+        contract_id: Option<ContractId>,
+        bytecode: &[u8],
+        offset: Word,
+        reason: PanicReason,
+    ) -> Option<Self> {
+        let start = usize::try_from(offset).ok()?;
+        let end = start.checked_add(Instruction::SIZE)?;
+        let raw: [u8; Instruction::SIZE] = bytecode.get(start..end)?.try_into().ok()?;
+        let instruction = Instruction::try_from(raw).ok()?;
+
+        Some(Self {
+            contract_id,
+            offset,
+            instruction,
+            reason,
+        })
+    }
+}
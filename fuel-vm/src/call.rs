@@ -63,13 +63,37 @@ impl Call {
     }
 }
 
+/// Observes the call frames entered with `CALL` and left with `RET` or `RETD`.
+///
+/// The paired events allow reconstructing the call graph of a transaction and
+/// attributing the gas used to each call. The frames that are still open when the
+/// execution reverts or panics are not reported as returned.
+pub trait CallObserver: core::fmt::Debug + Send + Sync {
+    /// Called after `CALL` entered the `frame`, with the remaining global gas before
+    /// the `CALL` instruction was charged.
+    fn on_call(&self, frame: &CallFrame, gas_before: Word);
+
+    /// Called after `RET` or `RETD` left the current frame, with the remaining global
+    /// gas.
+    fn on_return(&self, gas_after: Word);
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Call frame representation in the VM stack.
 ///
+/// The canonical encoding, i.e. [`Serialize::to_bytes`] and [`Deserialize::from_bytes`],
+/// is the layout written to the memory by `CALL`, described by the `*_offset`
+/// methods. It can be used to inspect the frame at `$fp`.
+///
 /// <https://github.com/FuelLabs/fuel-specs/blob/master/src/fuel-vm/index.md#call-frames>
 pub struct CallFrame {
     to: ContractId,
     asset_id: AssetId,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; VM_REGISTER_COUNT]>")
+    )]
     registers: [Word; VM_REGISTER_COUNT],
     code_size_padded: usize,
     a: Word,
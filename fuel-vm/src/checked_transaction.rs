@@ -13,7 +13,11 @@ use fuel_tx::{
     Script,
     Transaction,
     ValidityError,
-    field::Expiration,
+    field::{
+        Expiration,
+        Maturity,
+        Policies,
+    },
 };
 use fuel_types::{
     BlockHeight,
@@ -22,6 +26,7 @@ use fuel_types::{
 
 use alloc::{
     boxed::Box,
+    sync::Arc,
     vec::Vec,
 };
 use core::{
@@ -31,7 +36,10 @@ use core::{
 };
 use fuel_tx::{
     ConsensusParameters,
-    field::MaxFeeLimit,
+    field::{
+        MaxFeeLimit,
+        Tip,
+    },
 };
 
 mod balances;
@@ -48,6 +56,7 @@ use crate::{
         MemoryInstance,
     },
     pool::VmMemoryPool,
+    predicate::PredicateResultCache,
     prelude::*,
     storage::predicate::{
         EmptyStorage,
@@ -96,19 +105,28 @@ pub struct Checked<Tx: IntoChecked> {
     transaction: Tx,
     metadata: Tx::Metadata,
     checks_bitmask: Checks,
+    /// The signatures were deliberately not verified, see
+    /// `IntoChecked::into_checked_skip_signatures`.
+    signatures_skipped: bool,
 }
 
 impl<Tx: IntoChecked> Checked<Tx> {
-    fn new(transaction: Tx, metadata: Tx::Metadata, checks_bitmask: Checks) -> Self {
+    fn new(
+        transaction: Tx,
+        metadata: Tx::Metadata,
+        checks_bitmask: Checks,
+        signatures_skipped: bool,
+    ) -> Self {
         Checked {
             transaction,
             metadata,
             checks_bitmask,
+            signatures_skipped,
         }
     }
 
     pub(crate) fn basic(transaction: Tx, metadata: Tx::Metadata) -> Self {
-        Checked::new(transaction, metadata, Checks::Basic)
+        Checked::new(transaction, metadata, Checks::Basic, false)
     }
 
     /// Returns reference on inner transaction.
@@ -126,11 +144,19 @@ impl<Tx: IntoChecked> Checked<Tx> {
         &self.checks_bitmask
     }
 
+    /// Returns `true` if the signature verification was deliberately skipped with
+    /// `IntoChecked::into_checked_skip_signatures` and not performed since. Such a
+    /// transaction is refused by `Checked::into_ready`.
+    pub fn signatures_skipped(&self) -> bool {
+        self.signatures_skipped
+    }
+
     /// Performs check of signatures, if not yet done.
     pub fn check_signatures(mut self, chain_id: &ChainId) -> Result<Self, CheckError> {
         if !self.checks_bitmask.contains(Checks::Signatures) {
             self.transaction.check_signatures(chain_id)?;
             self.checks_bitmask.insert(Checks::Signatures);
+            self.signatures_skipped = false;
         }
         Ok(self)
     }
@@ -154,7 +180,7 @@ impl<Tx: IntoChecked> Ready<Tx> {
             metadata,
             checks_bitmask,
         } = self;
-        let checked = Checked::new(transaction, metadata, checks_bitmask);
+        let checked = Checked::new(transaction, metadata, checks_bitmask, false);
         (gas_price, checked)
     }
 
@@ -162,6 +188,11 @@ impl<Tx: IntoChecked> Ready<Tx> {
     pub fn gas_price(&self) -> Word {
         self.gas_price
     }
+
+    /// Returns the metadata generated during the check for transaction.
+    pub fn metadata(&self) -> &Tx::Metadata {
+        &self.metadata
+    }
 }
 
 #[cfg(feature = "test-helpers")]
@@ -172,6 +203,7 @@ impl<Tx: IntoChecked> Checked<Tx> {
             transaction,
             metadata,
             checks_bitmask,
+            ..
         } = self;
         Ready {
             gas_price: 0,
@@ -182,20 +214,107 @@ impl<Tx: IntoChecked> Checked<Tx> {
     }
 }
 
+/// The maximum fee of a transaction split into its components, which add up to the
+/// total.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FeeBreakdown {
+    /// The fee for the gas used by the inputs, the VM initialization and the execution,
+    /// including the gas of the unused witness limit.
+    pub gas_fee: Word,
+    /// The fee for the size of the transaction.
+    pub byte_fee: Word,
+    /// The tip for the block producer.
+    pub tip: Word,
+    /// The maximum fee deducted from the base asset before the execution. The fee of
+    /// the unused gas is returned to the change output.
+    pub total: Word,
+}
+
 impl<Tx: IntoChecked + Chargeable> Checked<Tx> {
+    /// Splits the maximum fee of the transaction at `gas_price` into its components.
+    /// The total is the fee verified by [`Self::into_ready`].
+    ///
+    /// Returns `None` if the fee overflows.
+    pub fn fee_breakdown(
+        &self,
+        gas_price: Word,
+        gas_costs: &GasCosts,
+        fee_parameters: &FeeParameters,
+    ) -> Option<FeeBreakdown> {
+        let fee = TransactionFee::checked_from_tx(
+            gas_costs,
+            fee_parameters,
+            &self.transaction,
+            gas_price,
+        )?;
+
+        let total = fee.max_fee();
+        let tip = self.transaction.tip();
+        let bytes_gas = fee_parameters
+            .gas_per_byte()
+            .saturating_mul(self.transaction.metered_bytes_size() as Word);
+        let byte_fee = u128::from(bytes_gas)
+            .checked_mul(u128::from(gas_price))?
+            .div_ceil(u128::from(fee_parameters.gas_price_factor()))
+            .try_into()
+            .ok()?;
+        let gas_fee = total.checked_sub(tip)?.checked_sub(byte_fee)?;
+
+        Some(FeeBreakdown {
+            gas_fee,
+            byte_fee,
+            tip,
+            total,
+        })
+    }
+
     /// Run final checks on `Checked` using dynamic values, e.g. `gas_price`
+    ///
+    /// Fails with `CheckError::SignaturesNotVerified` if the transaction was checked
+    /// with `IntoChecked::into_checked_skip_signatures` and the signatures were not
+    /// verified afterwards.
     pub fn into_ready(
         self,
         gas_price: Word,
         gas_costs: &GasCosts,
         fee_parameters: &FeeParameters,
         block_height: Option<BlockHeight>,
+    ) -> Result<Ready<Tx>, CheckError> {
+        self.into_ready_inner(gas_price, gas_costs, fee_parameters, block_height, true)
+    }
+
+    /// Same as [`Checked::into_ready`], but doesn't require the max fee policy to
+    /// cover the fee for the `gas_price`, so the execution can be estimated under gas
+    /// limits the transaction can't pay for.
+    pub(crate) fn into_ready_ignoring_max_fee(
+        self,
+        gas_price: Word,
+        gas_costs: &GasCosts,
+        fee_parameters: &FeeParameters,
+        block_height: Option<BlockHeight>,
+    ) -> Result<Ready<Tx>, CheckError> {
+        self.into_ready_inner(gas_price, gas_costs, fee_parameters, block_height, false)
+    }
+
+    fn into_ready_inner(
+        self,
+        gas_price: Word,
+        gas_costs: &GasCosts,
+        fee_parameters: &FeeParameters,
+        block_height: Option<BlockHeight>,
+        check_max_fee: bool,
     ) -> Result<Ready<Tx>, CheckError> {
         let Checked {
             transaction,
             metadata,
             checks_bitmask,
+            signatures_skipped,
         } = self;
+
+        if signatures_skipped {
+            return Err(CheckError::SignaturesNotVerified);
+        }
+
         let fee = TransactionFee::checked_from_tx(
             gas_costs,
             fee_parameters,
@@ -213,7 +332,7 @@ impl<Tx: IntoChecked + Chargeable> Checked<Tx> {
             }
         }
 
-        if max_fee_from_gas_price > max_fee_from_policies {
+        if check_max_fee && max_fee_from_gas_price > max_fee_from_policies {
             Err(CheckError::InsufficientMaxFee {
                 max_fee_from_policies,
                 max_fee_from_gas_price,
@@ -229,6 +348,134 @@ impl<Tx: IntoChecked + Chargeable> Checked<Tx> {
     }
 }
 
+impl<Tx> Checked<Tx>
+where
+    Tx: IntoChecked + Policies,
+    Tx::Metadata: HeightDependentMetadata,
+{
+    /// Re-validates the transaction at `block_height`, reusing the metadata computed by
+    /// the previous check.
+    ///
+    /// Only the rules that depend on the block height, i.e. the maturity and the
+    /// expiration, are checked again. The fees, the transaction id, the signatures and
+    /// the predicates are not recomputed. The result is identical to checking the
+    /// transaction at `block_height` with the consensus parameters of the previous
+    /// check.
+    pub fn recheck_with_metadata(
+        self,
+        block_height: BlockHeight,
+    ) -> Result<Self, CheckError> {
+        let Checked {
+            transaction,
+            mut metadata,
+            checks_bitmask,
+            signatures_skipped,
+        } = self;
+
+        if transaction.maturity() > block_height {
+            return Err(ValidityError::TransactionMaturity.into());
+        }
+
+        if transaction.expiration() < block_height {
+            return Err(ValidityError::TransactionExpiration.into());
+        }
+
+        metadata.set_block_height(block_height);
+
+        Ok(Checked::new(
+            transaction,
+            metadata,
+            checks_bitmask,
+            signatures_skipped,
+        ))
+    }
+}
+
+impl<Tx> Checked<Tx>
+where
+    Tx: IntoChecked,
+    Tx::Metadata: crate::interpreter::CheckedMetadata,
+{
+    /// Returns `true` if the maximal gas of the transaction, including the gas limit
+    /// of the script and the gas used by the predicates, fits in the
+    /// `block_gas_limit`.
+    ///
+    /// Uses the gas computed during the check, so no fee math is repeated.
+    pub fn fits_block_gas_limit(&self, block_gas_limit: Word) -> bool {
+        crate::interpreter::CheckedMetadata::max_gas(&self.metadata) <= block_gas_limit
+    }
+}
+
+impl<Tx> Checked<Tx>
+where
+    Tx: IntoChecked + crate::interpreter::ExecutableTransaction,
+{
+    /// Returns the hash of the script bytecode, or `None` if the transaction isn't a
+    /// script.
+    ///
+    /// The hash is computed with the [`Hasher`] of the crate, so it can be used to key
+    /// caches of the data derived from the bytecode.
+    pub fn script_bytecode_hash(&self) -> Option<Bytes32> {
+        use fuel_tx::field::Script as ScriptField;
+
+        self.transaction
+            .as_script()
+            .map(|script| Hasher::hash(script.script()))
+    }
+}
+
+impl<Tx> Checked<Tx>
+where
+    Tx: IntoChecked + ExecutableTransaction,
+    Tx::Metadata: crate::interpreter::CheckedMetadata,
+{
+    /// Computes the `Change` outputs, in the order of the outputs, that a successful
+    /// execution using `gas_used` would produce, without executing the transaction.
+    ///
+    /// Applies the same computation as the VM after the execution, assuming the script
+    /// doesn't spend any of the free balances, e.g. by transferring them to contracts.
+    /// Under this assumption the result matches the change of an execution using
+    /// the same gas.
+    pub fn preview_change(
+        &self,
+        gas_used: Word,
+        gas_price: Word,
+        gas_costs: &GasCosts,
+        fee_params: &FeeParameters,
+    ) -> Result<Vec<(AssetId, Word)>, ValidityError> {
+        use crate::interpreter::{
+            CheckedMetadata,
+            RuntimeBalances,
+        };
+
+        let initial_balances = self.metadata.balances();
+        let balances = RuntimeBalances::try_from(initial_balances.clone())?;
+        let mut tx = self.transaction.clone();
+        tx.update_outputs(
+            false,
+            gas_used,
+            &initial_balances,
+            &balances,
+            gas_costs,
+            fee_params,
+            &self.metadata.base_asset_id(),
+            gas_price,
+        )?;
+
+        let change = tx
+            .outputs()
+            .iter()
+            .filter_map(|output| match output {
+                Output::Change {
+                    asset_id, amount, ..
+                } => Some((*asset_id, *amount)),
+                _ => None,
+            })
+            .collect();
+        Ok(change)
+    }
+}
+
 impl<Tx: IntoChecked + UniqueIdentifier> Checked<Tx> {
     /// Returns the transaction ID from the computed metadata
     pub fn id(&self) -> TxId {
@@ -297,6 +544,8 @@ pub enum CheckError {
         /// The max fee calculated from the gas price and gas used by the transaction.
         max_fee_from_gas_price: Word,
     },
+    /// The transaction was checked without verifying its signatures.
+    SignaturesNotVerified,
 }
 
 /// Performs checks for a transaction
@@ -339,6 +588,31 @@ pub trait IntoChecked: FormatValidityChecks + Sized {
             .check_predicates(&check_predicate_params, memory, storage)
     }
 
+    /// Returns transaction that passed all `Checks` except `Checks::Signatures`.
+    ///
+    /// Useful to validate a transaction before it is signed. The result is marked with
+    /// `Checked::signatures_skipped` and can't be executed until
+    /// `Checked::check_signatures` succeeds.
+    fn into_checked_skip_signatures(
+        self,
+        block_height: BlockHeight,
+        consensus_params: &ConsensusParameters,
+    ) -> Result<Checked<Self>, CheckError>
+    where
+        Checked<Self>: CheckPredicates,
+    {
+        let check_predicate_params = consensus_params.into();
+        let mut checked = self
+            .into_checked_basic(block_height, consensus_params)?
+            .check_predicates(
+                &check_predicate_params,
+                MemoryInstance::new(),
+                &EmptyStorage,
+            )?;
+        checked.signatures_skipped = true;
+        Ok(checked)
+    }
+
     /// Returns transaction that passed only `Checks::Basic`.
     fn into_checked_basic(
         self,
@@ -370,6 +644,17 @@ pub struct CheckPredicateParams {
     pub fee_params: FeeParameters,
     /// Base Asset ID
     pub base_asset_id: AssetId,
+    /// Maximum total size of the witnesses in bytes, lower than the maximum size of
+    /// the transaction. The transactions above it are rejected before running the
+    /// predicates. `None` means the witnesses are only bounded by the transaction size.
+    pub max_witness_bytes: Option<u64>,
+    /// Skips the verification of the predicates that succeeded before, see
+    /// [`PredicateResultCache`]. `None` means every predicate is executed.
+    pub predicate_result_cache: Option<Arc<dyn PredicateResultCache>>,
+    /// Fails the predicates with [`PredicateVerificationFailed::CryptoFailure`] when a
+    /// signature recovery or verification fails, see
+    /// [`crate::interpreter::InterpreterParams::crypto_failure_to_host`].
+    pub crypto_failure_to_host: bool,
 }
 
 #[cfg(feature = "test-helpers")]
@@ -398,6 +683,9 @@ impl From<&ConsensusParameters> for CheckPredicateParams {
             tx_offset: value.tx_params().tx_offset(),
             fee_params: *(value.fee_params()),
             base_asset_id: *value.base_asset_id(),
+            max_witness_bytes: None,
+            predicate_result_cache: None,
+            crypto_failure_to_host: false,
         }
     }
 }
@@ -414,6 +702,9 @@ pub trait CheckPredicates: Sized {
     ) -> Result<Self, CheckError>;
 
     /// Performs predicates verification of the transaction in parallel.
+    ///
+    /// The memory of each predicate is taken from the `pool`, so any [`VmMemoryPool`],
+    /// e.g. a warmed one, can be used instead of allocating fresh instances.
     async fn check_predicates_async<E: ParallelExecutor>(
         self,
         params: &CheckPredicateParams,
@@ -434,6 +725,9 @@ pub trait EstimatePredicates: Sized {
     ) -> Result<(), CheckError>;
 
     /// Estimates predicates of the transaction in parallel.
+    ///
+    /// The memory of each predicate is taken from the `pool`, so any [`VmMemoryPool`],
+    /// e.g. a warmed one, can be used instead of allocating fresh instances.
     async fn estimate_predicates_async<E: ParallelExecutor>(
         &mut self,
         params: &CheckPredicateParams,
@@ -705,27 +999,58 @@ impl From<Checked<Transaction>> for CheckedTransaction {
             transaction,
             metadata,
             checks_bitmask,
+            signatures_skipped,
         } = checked;
 
         // # Dev note: Avoid wildcard pattern to be sure that all variants are covered.
         match (transaction, metadata) {
             (Transaction::Script(transaction), CheckedMetadata::Script(metadata)) => {
-                Self::Script(Checked::new(transaction, metadata, checks_bitmask))
+                Self::Script(Checked::new(
+                    transaction,
+                    metadata,
+                    checks_bitmask,
+                    signatures_skipped,
+                ))
             }
             (Transaction::Create(transaction), CheckedMetadata::Create(metadata)) => {
-                Self::Create(Checked::new(transaction, metadata, checks_bitmask))
+                Self::Create(Checked::new(
+                    transaction,
+                    metadata,
+                    checks_bitmask,
+                    signatures_skipped,
+                ))
             }
             (Transaction::Mint(transaction), CheckedMetadata::Mint(metadata)) => {
-                Self::Mint(Checked::new(transaction, metadata, checks_bitmask))
+                Self::Mint(Checked::new(
+                    transaction,
+                    metadata,
+                    checks_bitmask,
+                    signatures_skipped,
+                ))
             }
             (Transaction::Upgrade(transaction), CheckedMetadata::Upgrade(metadata)) => {
-                Self::Upgrade(Checked::new(transaction, metadata, checks_bitmask))
+                Self::Upgrade(Checked::new(
+                    transaction,
+                    metadata,
+                    checks_bitmask,
+                    signatures_skipped,
+                ))
             }
             (Transaction::Upload(transaction), CheckedMetadata::Upload(metadata)) => {
-                Self::Upload(Checked::new(transaction, metadata, checks_bitmask))
+                Self::Upload(Checked::new(
+                    transaction,
+                    metadata,
+                    checks_bitmask,
+                    signatures_skipped,
+                ))
             }
             (Transaction::Blob(transaction), CheckedMetadata::Blob(metadata)) => {
-                Self::Blob(Checked::new(transaction, metadata, checks_bitmask))
+                Self::Blob(Checked::new(
+                    transaction,
+                    metadata,
+                    checks_bitmask,
+                    signatures_skipped,
+                ))
             }
             // The code should produce the `CheckedMetadata` for the corresponding
             // transaction variant. It is done in the implementation of the
@@ -784,32 +1109,68 @@ impl From<CheckedTransaction> for Checked<Transaction> {
                 transaction,
                 metadata,
                 checks_bitmask,
-            }) => Checked::new(transaction.into(), metadata.into(), checks_bitmask),
+                signatures_skipped,
+            }) => Checked::new(
+                transaction.into(),
+                metadata.into(),
+                checks_bitmask,
+                signatures_skipped,
+            ),
             CheckedTransaction::Create(Checked {
                 transaction,
                 metadata,
                 checks_bitmask,
-            }) => Checked::new(transaction.into(), metadata.into(), checks_bitmask),
+                signatures_skipped,
+            }) => Checked::new(
+                transaction.into(),
+                metadata.into(),
+                checks_bitmask,
+                signatures_skipped,
+            ),
             CheckedTransaction::Mint(Checked {
                 transaction,
                 metadata,
                 checks_bitmask,
-            }) => Checked::new(transaction.into(), metadata.into(), checks_bitmask),
+                signatures_skipped,
+            }) => Checked::new(
+                transaction.into(),
+                metadata.into(),
+                checks_bitmask,
+                signatures_skipped,
+            ),
             CheckedTransaction::Upgrade(Checked {
                 transaction,
                 metadata,
                 checks_bitmask,
-            }) => Checked::new(transaction.into(), metadata.into(), checks_bitmask),
+                signatures_skipped,
+            }) => Checked::new(
+                transaction.into(),
+                metadata.into(),
+                checks_bitmask,
+                signatures_skipped,
+            ),
             CheckedTransaction::Upload(Checked {
                 transaction,
                 metadata,
                 checks_bitmask,
-            }) => Checked::new(transaction.into(), metadata.into(), checks_bitmask),
+                signatures_skipped,
+            }) => Checked::new(
+                transaction.into(),
+                metadata.into(),
+                checks_bitmask,
+                signatures_skipped,
+            ),
             CheckedTransaction::Blob(Checked {
                 transaction,
                 metadata,
                 checks_bitmask,
-            }) => Checked::new(transaction.into(), metadata.into(), checks_bitmask),
+                signatures_skipped,
+            }) => Checked::new(
+                transaction.into(),
+                metadata.into(),
+                checks_bitmask,
+                signatures_skipped,
+            ),
         }
     }
 }
@@ -994,6 +1355,157 @@ mod tests {
         );
     }
 
+    #[test]
+    fn fee_breakdown__adds_up_to_the_max_fee() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let gas_price = 3;
+        let params = params(2);
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(1000)
+            .max_fee_limit(1_000_000_000)
+            .tip(11)
+            .add_unsigned_coin_input(
+                SecretKey::random(rng),
+                rng.r#gen(),
+                1_000_000_000,
+                AssetId::default(),
+                rng.r#gen(),
+            )
+            .finalize();
+        let fee = TransactionFee::checked_from_tx(
+            params.gas_costs(),
+            params.fee_params(),
+            &tx,
+            gas_price,
+        )
+        .unwrap();
+        let checked = tx
+            .into_checked(Default::default(), &params)
+            .expect("Expected valid transaction");
+
+        let breakdown = checked
+            .fee_breakdown(gas_price, params.gas_costs(), params.fee_params())
+            .expect("The fee should not overflow");
+
+        assert_eq!(breakdown.total, fee.max_fee());
+        assert_eq!(breakdown.tip, 11);
+        assert_eq!(
+            breakdown.gas_fee + breakdown.byte_fee + breakdown.tip,
+            breakdown.total
+        );
+        assert!(breakdown.byte_fee > 0);
+        assert!(breakdown.gas_fee > 0);
+    }
+
+    #[test]
+    fn fits_block_gas_limit__compares_the_max_gas_with_the_limit() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let params = ConsensusParameters::standard();
+        let gas_limit = 1000;
+        let tx = valid_coin_tx(rng, gas_limit, 1000, 10, 500);
+        let max_gas = tx.max_gas(params.gas_costs(), params.fee_params());
+        let checked = tx
+            .into_checked(Default::default(), &params)
+            .expect("Expected valid transaction");
+
+        // When
+        let at_limit = checked.fits_block_gas_limit(max_gas);
+        let below_limit = checked.fits_block_gas_limit(max_gas - 1);
+
+        // Then
+        assert!(max_gas > gas_limit);
+        assert!(at_limit);
+        assert!(!below_limit);
+    }
+
+    #[test]
+    fn script_bytecode_hash__hashes_the_script_of_script_transactions() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let params = ConsensusParameters::standard();
+        let script: Vec<u8> = [op::noop(), op::ret(1)].into_iter().collect();
+        let checked_script = TransactionBuilder::script(script.clone(), vec![])
+            .add_fee_input()
+            .finalize()
+            .into_checked(Default::default(), &params)
+            .expect("Expected valid transaction");
+        let bytecode: Witness = rng.r#gen();
+        let checked_create = TransactionBuilder::create(bytecode, rng.r#gen(), vec![])
+            .add_fee_input()
+            .add_contract_created()
+            .finalize()
+            .into_checked(Default::default(), &params)
+            .expect("Expected valid transaction");
+
+        // When
+        let script_hash = checked_script.script_bytecode_hash();
+        let create_hash = checked_create.script_bytecode_hash();
+
+        // Then
+        assert_eq!(script_hash, Some(Hasher::hash(&script)));
+        assert_eq!(create_hash, None);
+    }
+
+    #[test]
+    fn recheck_with_metadata__matches_full_check_at_new_height() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let params = ConsensusParameters::standard();
+        let tx = tx_with_validity_window(rng, 5.into(), 20.into());
+
+        let checked = tx
+            .clone()
+            .into_checked(10.into(), &params)
+            .expect("Expected valid transaction");
+
+        // When
+        let rechecked = checked
+            .recheck_with_metadata(15.into())
+            .expect("Expected valid transaction");
+
+        // Then
+        let expected = tx
+            .into_checked(15.into(), &params)
+            .expect("Expected valid transaction");
+        assert_eq!(rechecked, expected);
+    }
+
+    #[test]
+    fn recheck_with_metadata__fails_before_maturity() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let tx = tx_with_validity_window(rng, 5.into(), 20.into());
+        let checked = tx
+            .into_checked(10.into(), &ConsensusParameters::standard())
+            .expect("Expected valid transaction");
+
+        // When
+        let result = checked.recheck_with_metadata(4.into());
+
+        // Then
+        assert_eq!(
+            result,
+            Err(CheckError::Validity(ValidityError::TransactionMaturity))
+        );
+    }
+
+    #[test]
+    fn recheck_with_metadata__fails_after_expiration() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let tx = tx_with_validity_window(rng, 5.into(), 20.into());
+        let checked = tx
+            .into_checked(10.into(), &ConsensusParameters::standard())
+            .expect("Expected valid transaction");
+
+        // When
+        let result = checked.recheck_with_metadata(21.into());
+
+        // Then
+        assert_eq!(
+            result,
+            Err(CheckError::Validity(ValidityError::TransactionExpiration))
+        );
+    }
+
     #[test]
     fn into_checked__tx_accepts_valid_signed_message_coin_for_fees() {
         // simple test to ensure a tx that only has a message input can cover fees
@@ -1995,6 +2507,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn skip_signatures_check_requires_signatures_before_execution() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let block_height = 1.into();
+        let max_fee_limit = 0;
+
+        let mut tx = valid_coin_tx(&mut rng, 100000, 1000000, 10, max_fee_limit);
+        // Invalidate the signature, it must not be verified.
+        tx.witnesses_mut()[0] = vec![0u8; 64].into();
+
+        let chain_id = ChainId::default();
+        let checked = tx
+            .into_checked_skip_signatures(
+                block_height,
+                &ConsensusParameters::standard_with_id(chain_id),
+            )
+            .expect("Signatures should not be verified");
+        assert!(checked.signatures_skipped());
+        assert!(!checked.checks().contains(Checks::Signatures));
+
+        let err = checked
+            .clone()
+            .into_ready(0, &GasCosts::default(), &FeeParameters::default(), None)
+            .expect_err("Expected signatures to be required");
+        assert_eq!(err, CheckError::SignaturesNotVerified);
+
+        checked
+            .check_signatures(&chain_id)
+            .expect_err("Expected invalid signature");
+    }
+
+    #[test]
+    fn skip_signatures_check_can_be_completed_by_signatures_check() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let block_height = 1.into();
+        let max_fee_limit = 0;
+
+        let tx = valid_coin_tx(&mut rng, 100000, 1000000, 10, max_fee_limit);
+        let chain_id = ChainId::default();
+        let checked = tx
+            .into_checked_skip_signatures(
+                block_height,
+                &ConsensusParameters::standard_with_id(chain_id),
+            )
+            .unwrap()
+            .check_signatures(&chain_id)
+            .unwrap();
+
+        assert!(
+            checked
+                .checks()
+                .contains(Checks::Basic | Checks::Signatures)
+        );
+        assert!(!checked.signatures_skipped());
+        checked
+            .into_ready(0, &GasCosts::default(), &FeeParameters::default(), None)
+            .expect("Signatures were verified");
+    }
+
     fn is_valid_max_fee(
         tx: &Script,
         gas_price: u64,
@@ -2077,6 +2648,25 @@ mod tests {
         Ok(min_fee == calculated_min_fee)
     }
 
+    fn tx_with_validity_window(
+        rng: &mut StdRng,
+        maturity: BlockHeight,
+        expiration: BlockHeight,
+    ) -> Script {
+        TransactionBuilder::script(vec![], vec![])
+            .max_fee_limit(500)
+            .maturity(maturity)
+            .expiration(expiration)
+            .add_unsigned_coin_input(
+                SecretKey::random(rng),
+                rng.r#gen(),
+                1000,
+                AssetId::default(),
+                rng.r#gen(),
+            )
+            .finalize()
+    }
+
     fn valid_coin_tx(
         rng: &mut StdRng,
         gas_limit: u64,
@@ -10,13 +10,44 @@ pub use self::{
 use alloc::collections::BTreeMap;
 use fuel_types::{
     AssetId,
+    BlockHeight,
     Word,
 };
 
+/// The metadata that records the block height the transaction was checked at.
+///
+/// Used by [`Checked::recheck_with_metadata`](super::Checked::recheck_with_metadata)
+/// to reuse the rest of the metadata at another block height.
+pub trait HeightDependentMetadata {
+    /// Sets the block height the transaction was verified with.
+    fn set_block_height(&mut self, block_height: BlockHeight);
+}
+
+macro_rules! impl_height_dependent_metadata {
+    ($($metadata:ty),*) => {
+        $(
+            impl HeightDependentMetadata for $metadata {
+                fn set_block_height(&mut self, block_height: BlockHeight) {
+                    self.block_height = block_height;
+                }
+            }
+        )*
+    };
+}
+
+impl_height_dependent_metadata!(
+    BlobCheckedMetadata,
+    CreateCheckedMetadata,
+    ScriptCheckedMetadata,
+    UpgradeCheckedMetadata,
+    UploadCheckedMetadata
+);
+
 /// The spendable unrestricted initial assets.
 /// More information about it in the specification:
 /// <https://github.com/FuelLabs/fuel-specs/blob/master/src/protocol/tx-validity.md#sufficient-balance>
 #[derive(Default, Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NonRetryableFreeBalances(pub(crate) BTreeMap<AssetId, Word>);
 
 impl From<NonRetryableFreeBalances> for BTreeMap<AssetId, Word> {
@@ -37,6 +68,7 @@ impl core::ops::Deref for NonRetryableFreeBalances {
 /// More information about it in the specification:
 /// <https://github.com/FuelLabs/fuel-specs/blob/master/src/protocol/tx-validity.md#sufficient-balance>
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RetryableAmount {
     pub(crate) amount: Word,
     pub(crate) base_asset_id: AssetId,
@@ -1,4 +1,22 @@
+use fuel_asm::Word;
+
 /// Converts value to usize is a way that's consistet on 32-bit and 64-bit platforms.
 pub(crate) fn to_usize(value: u64) -> Option<usize> {
-    usize::try_from(u32::try_from(value).ok()?).ok()
+    usize::try_from(word_to_u32(value)?).ok()
+}
+
+/// Converts value to u16, or returns `None` if it doesn't fit.
+pub(crate) fn word_to_u16(value: Word) -> Option<u16> {
+    u16::try_from(value).ok()
+}
+
+/// Converts value to u32, or returns `None` if it doesn't fit.
+pub(crate) fn word_to_u32(value: Word) -> Option<u32> {
+    u32::try_from(value).ok()
+}
+
+/// Adds `delta` to the `base` memory address, or returns `None` if the result doesn't
+/// fit in a [`Word`].
+pub(crate) fn checked_offset(base: usize, delta: usize) -> Option<Word> {
+    Word::try_from(base.checked_add(delta)?).ok()
 }
@@ -1,7 +1,11 @@
 //! Crypto implementations for the instructions
 
 use fuel_merkle::binary::root_calculator::MerkleRootCalculator as MerkleTree;
-use fuel_types::Bytes32;
+use fuel_types::{
+    Bytes32,
+    Bytes64,
+    Word,
+};
 
 /// Calculate a binary merkle root with in-memory storage
 pub fn ephemeral_merkle_root<L, I>(leaves: I) -> Bytes32
@@ -14,6 +18,42 @@ where
     tree.root().into()
 }
 
+/// The number of message bytes verified by `ED19` for the length operand `len`.
+///
+/// For backwards compatibility with old contracts, a zero length is treated as 32
+/// bytes, i.e. the size of a message digest, rather than an empty message.
+pub const fn ed25519_message_len(len: Word) -> Word {
+    if len == 0 { 32 } else { len }
+}
+
+/// Verifies the ed25519 `signature` of the `message` with the `pub_key`, exactly as
+/// the `ED19` instruction does.
+///
+/// The `message` must be the bytes read by `ED19`, whose length is given by
+/// [`ed25519_message_len`]: a zero length operand verifies the 32 bytes at the
+/// message address, never an empty message.
+pub fn ed25519_verify(pub_key: &Bytes32, signature: &Bytes64, message: &[u8]) -> bool {
+    fuel_crypto::ed25519::verify(pub_key, signature, message).is_ok()
+}
+
+#[test]
+fn ed25519_verify_treats_zero_length_as_32_bytes() {
+    use ed25519_dalek::Signer;
+
+    let signing_key = ed25519_dalek::SigningKey::from_bytes(&[7; 32]);
+    let pub_key = Bytes32::from(signing_key.verifying_key().to_bytes());
+    let memory = [1u8; 64];
+
+    let len = usize::try_from(ed25519_message_len(0)).unwrap();
+    let signature = Bytes64::from(signing_key.sign(&memory[..len]).to_bytes());
+
+    assert_eq!(len, 32);
+    assert_eq!(ed25519_message_len(16), 16);
+    assert!(ed25519_verify(&pub_key, &signature, &memory[..len]));
+    assert!(!ed25519_verify(&pub_key, &signature, &[]));
+    assert!(!ed25519_verify(&pub_key, &signature, &memory));
+}
+
 #[test]
 #[cfg(feature = "random")]
 fn ephemeral_merkle_root_returns_the_expected_root() {
@@ -1,6 +1,8 @@
 //! Runtime interpreter error implementation
 
 use fuel_asm::{
+    Instruction,
+    Opcode,
     PanicInstruction,
     PanicReason,
     RawInstruction,
@@ -21,7 +23,10 @@ use core::{
     fmt,
 };
 
-use crate::storage::predicate;
+use crate::{
+    storage::predicate,
+    verification::VerificationError,
+};
 
 /// Interpreter runtime error variants.
 #[derive(Debug, derive_more::Display)]
@@ -62,6 +67,47 @@ pub enum InterpreterError<StorageError> {
         /// Actual gas price
         actual: Word,
     },
+    /// The receipts of the executed transaction were rejected by a receipt verifier
+    #[display(fmt = "Receipt verification failed: {_0}")]
+    ReceiptVerification(VerificationError),
+    /// The instruction couldn't be decoded. Only reported when
+    /// `InterpreterParams::error_on_unknown_opcode` is set, the VM panics otherwise.
+    #[display(fmt = "Unknown opcode in the instruction {_0:#010x}")]
+    UnknownOpcode(RawInstruction),
+    /// A signature recovery or verification instruction failed in a predicate. Only
+    /// reported when `InterpreterParams::crypto_failure_to_host` is set, the
+    /// instruction sets `$err` and the execution continues otherwise.
+    #[display(fmt = "Cryptographic operation failed: {_0}")]
+    CryptoFailure(CryptoFailure),
+}
+
+/// The signature recovery or verification instruction that failed, reported with
+/// [`InterpreterError::CryptoFailure`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CryptoFailure {
+    /// `ECK1` couldn't recover a secp256k1 public key from the signature.
+    #[display(fmt = "secp256k1 public key recovery")]
+    Secp256k1Recover,
+    /// `ECR1` couldn't recover a secp256r1 public key from the signature.
+    #[display(fmt = "secp256r1 public key recovery")]
+    Secp256r1Recover,
+    /// `ED19` rejected the ed25519 signature.
+    #[display(fmt = "ed25519 signature verification")]
+    Ed25519Verify,
+}
+
+impl CryptoFailure {
+    /// Returns the failure reported by the instruction with the `opcode`, if it is a
+    /// signature recovery or verification instruction.
+    pub const fn from_opcode(opcode: Opcode) -> Option<Self> {
+        match opcode {
+            Opcode::ECK1 => Some(Self::Secp256k1Recover),
+            Opcode::ECR1 => Some(Self::Secp256r1Recover),
+            Opcode::ED19 => Some(Self::Ed25519Verify),
+            _ => None,
+        }
+    }
 }
 
 impl<StorageError> InterpreterError<StorageError> {
@@ -95,6 +141,14 @@ impl<StorageError> InterpreterError<StorageError> {
         }
     }
 
+    /// Return the decoded instruction that caused this error, if applicable.
+    pub fn decoded_instruction(&self) -> Option<Instruction> {
+        match self {
+            Self::PanicInstruction(result) => result.decoded_instruction(),
+            _ => None,
+        }
+    }
+
     /// Return the underlying `InstructionResult` if this instance is
     /// `PanicInstruction`; returns `None` otherwise.
     pub fn instruction_result(&self) -> Option<PanicInstruction> {
@@ -125,6 +179,11 @@ where
                     actual: *actual,
                 }
             }
+            Self::ReceiptVerification(e) => {
+                InterpreterError::ReceiptVerification(e.clone())
+            }
+            Self::UnknownOpcode(e) => InterpreterError::UnknownOpcode(*e),
+            Self::CryptoFailure(e) => InterpreterError::CryptoFailure(*e),
         }
     }
 }
@@ -150,6 +209,9 @@ where
             (Self::NoTransactionInitialized, Self::NoTransactionInitialized) => true,
             (Self::Storage(a), Self::Storage(b)) => a == b,
             (Self::DebugStateNotInitialized, Self::DebugStateNotInitialized) => true,
+            (Self::ReceiptVerification(a), Self::ReceiptVerification(b)) => a == b,
+            (Self::UnknownOpcode(a), Self::UnknownOpcode(b)) => a == b,
+            (Self::CryptoFailure(a), Self::CryptoFailure(b)) => a == b,
 
             _ => false,
         }
@@ -281,6 +343,14 @@ pub enum PredicateVerificationFailed {
     /// The transaction's `max_gas` is greater than the global gas limit.
     #[display(fmt = "Transaction exceeds total gas allowance {_0:?}")]
     TransactionExceedsTotalGasAllowance(Word),
+    /// The total size of the transaction's witnesses exceeds the limit.
+    #[display(fmt = "Witnesses size {size} exceeds the limit {max}")]
+    WitnessesTooLarge {
+        /// The total size of the witnesses in bytes
+        size: u64,
+        /// The maximum total size of the witnesses in bytes
+        max: u64,
+    },
     /// The cumulative gas overflowed the u64 accumulator
     #[display(fmt = "Cumulative gas computation overflowed the u64 accumulator")]
     GasOverflow,
@@ -309,6 +379,15 @@ pub enum PredicateVerificationFailed {
         /// Input index of the predicate
         index: usize,
     },
+    /// A signature recovery or verification failed in the predicate. Only reported
+    /// when `InterpreterParams::crypto_failure_to_host` is set.
+    #[display(fmt = "Predicate {index} failed the {failure}")]
+    CryptoFailure {
+        /// Input index of the predicate
+        index: usize,
+        /// The failed operation
+        failure: CryptoFailure,
+    },
 }
 
 impl PredicateVerificationFailed {
@@ -328,9 +407,23 @@ impl PredicateVerificationFailed {
             }
             InterpreterError::Bug(bug) => Self::Bug(bug),
             InterpreterError::Storage(_) => Self::Storage { index },
+            InterpreterError::CryptoFailure(failure) => {
+                Self::CryptoFailure { index, failure }
+            }
             _ => Self::False { index },
         }
     }
+
+    /// Return the decoded instruction that caused the predicate to panic, if
+    /// applicable.
+    pub fn decoded_instruction(&self) -> Option<Instruction> {
+        match self {
+            Self::PanicInstruction { instruction, .. } => {
+                instruction.decoded_instruction()
+            }
+            _ => None,
+        }
+    }
 }
 
 impl From<Bug> for PredicateVerificationFailed {
@@ -1,7 +1,10 @@
 //! [`Interpreter`] implementation
 
 use crate::{
-    call::CallFrame,
+    call::{
+        CallFrame,
+        CallObserver,
+    },
     checked_transaction::{
         BlobCheckedMetadata,
         CheckPredicateParams,
@@ -11,10 +14,15 @@ use crate::{
     context::Context,
     error::SimpleResult,
     state::Debugger,
+    storage::{
+        AccessSet,
+        StorageObserver,
+    },
     verification,
 };
 use alloc::{
     collections::BTreeSet,
+    sync::Arc,
     vec::Vec,
 };
 use core::{
@@ -25,10 +33,12 @@ use core::{
 use fuel_asm::{
     Flags,
     PanicReason,
+    RegId,
 };
 use fuel_tx::{
     Blob,
     Chargeable,
+    Contract,
     Create,
     Executable,
     FeeParameters,
@@ -47,6 +57,7 @@ use fuel_tx::{
 };
 use fuel_types::{
     AssetId,
+    BlockHeight,
     Bytes32,
     ChainId,
     ContractId,
@@ -69,6 +80,7 @@ mod internal;
 mod log;
 mod memory;
 mod metadata;
+mod paused;
 mod post_execution;
 mod receipts;
 
@@ -76,16 +88,29 @@ mod debug;
 mod ecal;
 
 pub use balances::RuntimeBalances;
+#[cfg(feature = "frame-revert")]
+pub use ecal::FRAME_REVERT_ECAL_ID;
 pub use ecal::{
     EcalHandler,
+    EcalRecord,
     PredicateErrorEcal,
+    ReplayEcal,
 };
 pub use executors::predicates;
+pub use gas::{
+    GasRefundPolicy,
+    OpcodeGasObserver,
+};
+#[cfg(feature = "memory-profiling")]
+pub use memory::MemoryProfile;
 pub use memory::{
     Memory,
+    MemoryGrowthError,
     MemoryInstance,
     MemoryRange,
+    ReadUtf8Error,
 };
+pub use paused::PausedExecution;
 
 use crate::checked_transaction::{
     CreateCheckedMetadata,
@@ -106,6 +131,7 @@ use self::receipts::ReceiptsCtx;
 
 /// ECAL opcode is not supported and return an error if you try to call.
 #[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NotSupportedEcal;
 
 /// VM interpreter.
@@ -136,10 +162,45 @@ pub struct Interpreter<M, S, Tx = (), Ecal = NotSupportedEcal, V = verification:
     panic_context: PanicContext,
     ecal_state: Ecal,
     verifier: V,
+    /// The contract whose code is kept in memory to avoid reading it from the storage
+    /// on every `CALL`. See [`Interpreter::with_preloaded_contract`].
+    preloaded_contract: Option<(ContractId, Contract)>,
+    /// Notified about the contract state accesses. See
+    /// [`Interpreter::set_storage_observer`].
+    storage_observer: Option<Arc<dyn StorageObserver>>,
+    /// The contract state slots accessed since the initialization, if enabled. See
+    /// [`Interpreter::set_access_set_enabled`].
+    access_set: Option<AccessSet>,
+    /// The effects of the ECAL instructions executed since the initialization, if
+    /// enabled. See [`Interpreter::set_ecal_recording_enabled`].
+    ecal_records: Option<Vec<EcalRecord>>,
+    /// Notified about the call frames entered and left. See
+    /// [`Interpreter::set_call_observer`].
+    call_observer: Option<Arc<dyn CallObserver>>,
+    /// Decides the gas refunded for the cleared storage slots. See
+    /// [`Interpreter::set_gas_refund_policy`].
+    gas_refund_policy: Option<Arc<dyn GasRefundPolicy>>,
+    /// Notified about the gas charged by the instructions of the observed opcodes. See
+    /// [`Interpreter::set_opcode_gas_observer`].
+    opcode_gas_observer: Option<(
+        Arc<dyn OpcodeGasObserver>,
+        hashbrown::HashSet<fuel_asm::Opcode>,
+    )>,
+    /// The gas refunds accumulated by the current execution.
+    gas_refund: Word,
+    /// The gas limit the current execution was initialized with.
+    initial_gas: Word,
+    /// The gas charged by the instructions of each called contract since the
+    /// initialization, if enabled. See [`Interpreter::set_gas_by_contract_enabled`].
+    gas_by_contract: Option<hashbrown::HashMap<ContractId, Word>>,
+    /// The number of executed instructions per opcode since the initialization.
+    #[cfg(feature = "opcode-histogram")]
+    opcode_histogram: hashbrown::HashMap<fuel_asm::Opcode, u64>,
 }
 
 /// Interpreter parameters
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterpreterParams {
     /// Gas Price
     pub gas_price: Word,
@@ -158,7 +219,74 @@ pub struct InterpreterParams {
     /// Fee parameters
     pub fee_params: FeeParameters,
     /// Base Asset ID
+    ///
+    /// Written into the VM memory during initialization and returned by
+    /// `GM` with `GMArgs::BaseAssetId`. Defaults to the value from the
+    /// `ConsensusParameters` when constructed with [`InterpreterParams::new`].
     pub base_asset_id: AssetId,
+    /// Maximum number of nested call frames. `CALL` panics with
+    /// `PanicReason::MaxCallDepthReached` when it is reached. `None` means no limit.
+    pub max_call_depth: Option<usize>,
+    /// Overrides the block information provided by the storage. `None` means the
+    /// storage is used.
+    pub block_context: Option<BlockContext>,
+    /// Allows the `LOG` and `LOGD` instructions in predicates, to debug them with the
+    /// receipts they produce. Must be `false` for the consensus-relevant verification.
+    pub allow_logs_in_predicates: bool,
+    /// Maximum number of receipts a transaction can produce, including the ones ending
+    /// the script. Instructions emitting a receipt panic with
+    /// `PanicReason::TooManyReceipts` when it is reached. Defaults to, and is capped
+    /// by, [`ReceiptsCtx::MAX_RECEIPTS`].
+    pub max_receipts: usize,
+    /// Leaves the heap grown by `ALOC` uninitialized instead of zeroing it. The memory
+    /// is still bounds-checked, but may expose data written by previous transactions.
+    ///
+    /// Only meant to measure the cost of the zeroing in benchmarks, so it requires the
+    /// `skip-alloc-zeroing` feature. It changes the execution results, so it must be
+    /// `false` for the consensus-relevant execution.
+    #[cfg(feature = "skip-alloc-zeroing")]
+    pub skip_alloc_zeroing: bool,
+    /// Stops the execution with [`InterpreterError::UnknownOpcode`] when an
+    /// instruction can't be decoded, instead of panicking with
+    /// `PanicReason::InvalidInstruction`.
+    ///
+    /// Meant for fuzzing, to tell the invalid programs apart from the programs that
+    /// revert. Must be `false` for the consensus-relevant execution.
+    pub error_on_unknown_opcode: bool,
+    /// Makes `ECAL` revert the storage changes made since the current call frame was
+    /// entered when its first register holds [`FRAME_REVERT_ECAL_ID`], instead of
+    /// calling the [`EcalHandler`]. The execution continues in the same frame, which
+    /// usually returns an error code to its caller. The other `ECAL`s still go to the
+    /// handler.
+    ///
+    /// Requires a storage tracking the changes per frame, like the one of
+    /// [`Interpreter::add_recording`], `ECAL` panics with `PanicReason::EcalError`
+    /// otherwise. The balances of the transaction held by the VM are not reverted.
+    /// Not part of the consensus rules, so it must be `false` for the
+    /// consensus-relevant execution.
+    #[cfg(feature = "frame-revert")]
+    pub frame_revert_ecal: bool,
+    /// Stops the predicates with [`InterpreterError::CryptoFailure`] when `ECK1`,
+    /// `ECR1` or `ED19` fails, instead of setting `$err` and continuing.
+    ///
+    /// Lets the host tell an invalid signature apart from a predicate returning
+    /// `false`. Scripts and contracts are not affected. Must be `false` for the
+    /// consensus-relevant verification.
+    pub crypto_failure_to_host: bool,
+}
+
+/// The block information used by the `BHEI`, `TIME` and `CB` instructions instead of
+/// the one provided by the storage, e.g. to replay historical transactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockContext {
+    /// The height of the block the transaction is executed in.
+    pub height: BlockHeight,
+    /// The TAI64 timestamp of the block at `height`. Timestamps of previous blocks
+    /// are still provided by the storage.
+    pub time: Word,
+    /// The block producer.
+    pub coinbase: ContractId,
 }
 
 #[cfg(feature = "test-helpers")]
@@ -175,6 +303,16 @@ impl Default for InterpreterParams {
             chain_id: ChainId::default(),
             fee_params: FeeParameters::default(),
             base_asset_id: Default::default(),
+            max_call_depth: None,
+            block_context: None,
+            allow_logs_in_predicates: false,
+            max_receipts: ReceiptsCtx::MAX_RECEIPTS,
+            #[cfg(feature = "skip-alloc-zeroing")]
+            skip_alloc_zeroing: false,
+            error_on_unknown_opcode: false,
+            #[cfg(feature = "frame-revert")]
+            frame_revert_ecal: false,
+            crypto_failure_to_host: false,
         }
     }
 }
@@ -193,8 +331,141 @@ impl InterpreterParams {
             chain_id: params.chain_id,
             fee_params: params.fee_params,
             base_asset_id: params.base_asset_id,
+            max_call_depth: None,
+            block_context: None,
+            allow_logs_in_predicates: false,
+            max_receipts: ReceiptsCtx::MAX_RECEIPTS,
+            #[cfg(feature = "skip-alloc-zeroing")]
+            skip_alloc_zeroing: false,
+            error_on_unknown_opcode: false,
+            #[cfg(feature = "frame-revert")]
+            frame_revert_ecal: false,
+            crypto_failure_to_host: params.crypto_failure_to_host,
         }
     }
+
+    /// Creates a [`InterpreterParamsBuilder`] with the defaults taken from `params`.
+    pub fn builder<T: Into<CheckPredicateParams>>(params: T) -> InterpreterParamsBuilder {
+        InterpreterParamsBuilder::new(params)
+    }
+}
+
+/// Builder of [`InterpreterParams`] to override only some of the parameters.
+///
+/// ```
+/// use fuel_vm::{
+///     fuel_tx::ConsensusParameters,
+///     fuel_types::AssetId,
+///     interpreter::InterpreterParams,
+/// };
+///
+/// let consensus_params = ConsensusParameters::standard();
+/// let base_asset_id = AssetId::new([1; 32]);
+///
+/// let params = InterpreterParams::builder(&consensus_params)
+///     .gas_price(10)
+///     .base_asset_id(base_asset_id)
+///     .build();
+///
+/// assert_eq!(params.gas_price, 10);
+/// assert_eq!(params.base_asset_id, base_asset_id);
+/// assert_eq!(params.max_inputs, consensus_params.tx_params().max_inputs());
+/// ```
+#[derive(Debug, Clone)]
+pub struct InterpreterParamsBuilder {
+    params: InterpreterParams,
+}
+
+impl InterpreterParamsBuilder {
+    /// Creates a builder with the defaults taken from `params` and a zero gas price.
+    pub fn new<T: Into<CheckPredicateParams>>(params: T) -> Self {
+        Self {
+            params: InterpreterParams::new(0, params),
+        }
+    }
+
+    /// Sets the gas price.
+    pub fn gas_price(&mut self, gas_price: Word) -> &mut Self {
+        self.params.gas_price = gas_price;
+        self
+    }
+
+    /// Sets the gas costs.
+    pub fn gas_costs(&mut self, gas_costs: GasCosts) -> &mut Self {
+        self.params.gas_costs = gas_costs;
+        self
+    }
+
+    /// Sets the fee parameters.
+    pub fn fee_params(&mut self, fee_params: FeeParameters) -> &mut Self {
+        self.params.fee_params = fee_params;
+        self
+    }
+
+    /// Sets the base asset id.
+    pub fn base_asset_id(&mut self, base_asset_id: AssetId) -> &mut Self {
+        self.params.base_asset_id = base_asset_id;
+        self
+    }
+
+    /// Sets the maximum number of nested call frames.
+    pub fn max_call_depth(&mut self, max_call_depth: usize) -> &mut Self {
+        self.params.max_call_depth = Some(max_call_depth);
+        self
+    }
+
+    /// Overrides the block information provided by the storage.
+    pub fn block_context(&mut self, block_context: BlockContext) -> &mut Self {
+        self.params.block_context = Some(block_context);
+        self
+    }
+
+    /// Allows the `LOG` and `LOGD` instructions in predicates.
+    pub fn allow_logs_in_predicates(&mut self, allow: bool) -> &mut Self {
+        self.params.allow_logs_in_predicates = allow;
+        self
+    }
+
+    /// Sets the maximum number of receipts a transaction can produce.
+    pub fn max_receipts(&mut self, max_receipts: usize) -> &mut Self {
+        self.params.max_receipts = max_receipts;
+        self
+    }
+
+    /// Leaves the heap grown by `ALOC` uninitialized. Only for benchmarks, see
+    /// [`InterpreterParams::skip_alloc_zeroing`].
+    #[cfg(feature = "skip-alloc-zeroing")]
+    pub fn skip_alloc_zeroing(&mut self, skip: bool) -> &mut Self {
+        self.params.skip_alloc_zeroing = skip;
+        self
+    }
+
+    /// Reports the undecodable instructions as errors instead of panics, see
+    /// [`InterpreterParams::error_on_unknown_opcode`].
+    pub fn error_on_unknown_opcode(&mut self, error: bool) -> &mut Self {
+        self.params.error_on_unknown_opcode = error;
+        self
+    }
+
+    /// Makes `ECAL` revert the storage changes of the current call frame, see
+    /// [`InterpreterParams::frame_revert_ecal`].
+    #[cfg(feature = "frame-revert")]
+    pub fn frame_revert_ecal(&mut self, enabled: bool) -> &mut Self {
+        self.params.frame_revert_ecal = enabled;
+        self
+    }
+
+    /// Reports the failed signature recoveries and verifications of the predicates as
+    /// errors, see [`InterpreterParams::crypto_failure_to_host`].
+    pub fn crypto_failure_to_host(&mut self, enabled: bool) -> &mut Self {
+        self.params.crypto_failure_to_host = enabled;
+        self
+    }
+
+    /// Returns the built parameters.
+    pub fn build(&self) -> InterpreterParams {
+        self.params.clone()
+    }
 }
 
 /// Sometimes it is possible to add some additional context information
@@ -202,6 +473,7 @@ impl InterpreterParams {
 // TODO: Move this enum into `fuel-tx` and use it inside of the `Receipt::Panic` as meta
 //  information. Maybe better to have `Vec<PanicContext>` to provide more information.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum PanicContext {
     /// No additional information.
     None,
@@ -214,6 +486,26 @@ impl<M: Memory, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V> {
     pub fn memory(&self) -> &MemoryInstance {
         self.memory.as_ref()
     }
+
+    /// Returns the base asset ID written into the VM memory during initialization, at
+    /// [`VM_MEMORY_BASE_ASSET_ID_OFFSET`]. `None` if the memory isn't initialized.
+    ///
+    /// Unlike [`Interpreter::base_asset_id`], it is read from the memory, i.e. it is
+    /// the value that `GM` with `GMArgs::BaseAssetId` points the programs to.
+    pub fn memory_base_asset_id(&self) -> Option<AssetId> {
+        self.memory()
+            .read_bytes(VM_MEMORY_BASE_ASSET_ID_OFFSET)
+            .ok()
+            .map(AssetId::new)
+    }
+
+    /// The id of the current transaction, as written by the VM at the start of the
+    /// memory.
+    ///
+    /// Will be `None` if no transaction was initialized.
+    pub fn transaction_id(&self) -> Option<Bytes32> {
+        self.memory().read_bytes(0u64).ok().map(Bytes32::new)
+    }
 }
 
 impl<M: AsMut<MemoryInstance>, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V> {
@@ -234,6 +526,20 @@ impl<M, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V> {
         &mut self.registers
     }
 
+    /// The `$pc` register as left by the last executed instruction.
+    ///
+    /// After a revert or a panic it points at the faulting instruction, while a
+    /// successful `RET` or `RETD` leaves it right after the returning instruction.
+    pub fn final_pc(&self) -> Word {
+        self.registers[RegId::PC]
+    }
+
+    /// The `$is` register as left by the last executed instruction, i.e. the start
+    /// of the instructions of the last executed context.
+    pub fn final_is(&self) -> Word {
+        self.registers[RegId::IS]
+    }
+
     pub(crate) fn call_stack(&self) -> &[CallFrame] {
         self.frames.as_slice()
     }
@@ -243,22 +549,63 @@ impl<M, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V> {
         &self.debugger
     }
 
+    /// The context the VM is executing in, e.g. a predicate, a script or a call.
+    pub const fn context(&self) -> &Context {
+        &self.context
+    }
+
     /// The current transaction.
     pub fn transaction(&self) -> &Tx {
         &self.tx
     }
 
+    /// Mutable access to the current transaction for testing purposes.
+    ///
+    /// Mutating the transaction in the middle of an execution is unsupported, since
+    /// the VM memory keeps the serialized transaction it was initialized with.
+    #[cfg(any(test, feature = "test-helpers"))]
+    pub fn transaction_mut(&mut self) -> &mut Tx {
+        &mut self.tx
+    }
+
     /// The initial balances.
     pub fn initial_balances(&self) -> &InitialBalances {
         &self.initial_balances
     }
 
+    /// The free balances of the transaction at the current point of the execution,
+    /// sorted by asset id. The coins transferred or forwarded to the contracts are
+    /// not part of them.
+    pub fn runtime_balances(&self) -> Vec<(AssetId, Word)> {
+        let mut balances: Vec<_> = self
+            .balances
+            .as_ref()
+            .iter()
+            .map(|(asset_id, balance)| (*asset_id, balance.value()))
+            .collect();
+        balances.sort_unstable_by_key(|(asset_id, _)| *asset_id);
+        balances
+    }
+
+    /// The index of the `Output::Contract` that corresponds to the contract input at
+    /// `input_idx`, as returned by `GTFArgs::InputContractOutputIndex`.
+    ///
+    /// Returns `None` if the input is not a contract input or the VM is not
+    /// initialized with a transaction.
+    pub fn input_contract_output_index(&self, input_idx: u16) -> Option<u16> {
+        self.input_contracts_index_to_output_index
+            .get(&input_idx)
+            .copied()
+    }
+
     /// Get max_inputs value
     pub fn max_inputs(&self) -> u16 {
         self.interpreter_params.max_inputs
     }
 
     /// Gas price for current block
+    ///
+    /// Same value as `GM` with `GMArgs::GetGasPrice` outside of the predicates.
     pub fn gas_price(&self) -> Word {
         self.interpreter_params.gas_price
     }
@@ -280,16 +627,138 @@ impl<M, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V> {
     }
 
     /// Get the base Asset ID
+    ///
+    /// Same value as the one written at the address returned by `GM` with
+    /// `GMArgs::BaseAssetId`.
     pub fn base_asset_id(&self) -> &AssetId {
         &self.interpreter_params.base_asset_id
     }
 
+    /// The gas charged by the instructions of each called contract since the
+    /// transaction was initialized. The gas of an instruction is attributed to the
+    /// contract owning the current call frame, including the instructions of the
+    /// reverted frames. The gas charged by the script or the predicates is not
+    /// part of the map. `None` if the collection is disabled.
+    pub fn gas_by_contract(&self) -> Option<&hashbrown::HashMap<ContractId, Word>> {
+        self.gas_by_contract.as_ref()
+    }
+
+    /// Enables or disables the collection of the gas charged by each contract called
+    /// by the next transactions, see [`Interpreter::gas_by_contract`].
+    pub fn set_gas_by_contract_enabled(&mut self, enabled: bool) {
+        self.gas_by_contract = enabled.then(Default::default);
+    }
+
+    /// The number of instructions executed per opcode since the transaction was
+    /// initialized, including the instructions of the called contracts.
+    #[cfg(feature = "opcode-histogram")]
+    pub fn opcode_histogram(&self) -> &hashbrown::HashMap<fuel_asm::Opcode, u64> {
+        &self.opcode_histogram
+    }
+
+    /// Get the parameters of the interpreter
+    pub(crate) fn interpreter_params(&self) -> &InterpreterParams {
+        &self.interpreter_params
+    }
+
+    /// Get the block information override, if any
+    pub fn block_context(&self) -> Option<&BlockContext> {
+        self.interpreter_params.block_context.as_ref()
+    }
+
+    /// Sets the block information used instead of the one provided by the storage
+    /// for the next transactions.
+    pub fn set_block_context(&mut self, block_context: Option<BlockContext>) {
+        self.interpreter_params.block_context = block_context;
+    }
+
+    /// Get the observer of the contract state accesses, if any
+    pub fn storage_observer(&self) -> Option<&Arc<dyn StorageObserver>> {
+        self.storage_observer.as_ref()
+    }
+
+    /// Sets the observer notified about every contract state slot read or written by
+    /// the next transactions. `None` removes the observer.
+    pub fn set_storage_observer(
+        &mut self,
+        storage_observer: Option<Arc<dyn StorageObserver>>,
+    ) {
+        self.storage_observer = storage_observer;
+    }
+
+    /// The contract state slots read and written since the transaction was
+    /// initialized, or `None` if the collection is disabled.
+    pub fn access_set(&self) -> Option<&AccessSet> {
+        self.access_set.as_ref()
+    }
+
+    /// Enables or disables the collection of the contract state slots accessed by the
+    /// next transactions, see [`Interpreter::access_set`].
+    pub fn set_access_set_enabled(&mut self, enabled: bool) {
+        self.access_set = enabled.then(AccessSet::default);
+    }
+
+    /// The effects of the ECAL instructions executed since the transaction was
+    /// initialized, or `None` if the recording is disabled.
+    pub fn ecal_records(&self) -> Option<&[EcalRecord]> {
+        self.ecal_records.as_deref()
+    }
+
+    /// Enables or disables the recording of the effects of the ECAL instructions of
+    /// the next transactions, see [`Interpreter::ecal_records`] and [`ReplayEcal`].
+    pub fn set_ecal_recording_enabled(&mut self, enabled: bool) {
+        self.ecal_records = enabled.then(Vec::new);
+    }
+
+    /// Get the observer of the call frames, if any
+    pub fn call_observer(&self) -> Option<&Arc<dyn CallObserver>> {
+        self.call_observer.as_ref()
+    }
+
+    /// Sets the observer notified about every call frame entered and left by the next
+    /// transactions. `None` removes the observer.
+    pub fn set_call_observer(&mut self, call_observer: Option<Arc<dyn CallObserver>>) {
+        self.call_observer = call_observer;
+    }
+
+    /// Get the policy of the gas refunds for the cleared storage slots, if any
+    pub fn gas_refund_policy(&self) -> Option<&Arc<dyn GasRefundPolicy>> {
+        self.gas_refund_policy.as_ref()
+    }
+
+    /// Sets the policy of the gas refunds for the storage slots cleared by the next
+    /// transactions. `None` disables the refunds.
+    pub fn set_gas_refund_policy(
+        &mut self,
+        gas_refund_policy: Option<Arc<dyn GasRefundPolicy>>,
+    ) {
+        self.gas_refund_policy = gas_refund_policy;
+    }
+
+    /// Sets the observer notified about the gas charged by each instruction with one
+    /// of the `opcodes` in the next transactions. `None` or an empty set of opcodes
+    /// removes the observer, so the other instructions aren't slowed down.
+    pub fn set_opcode_gas_observer<I>(
+        &mut self,
+        observer: Option<Arc<dyn OpcodeGasObserver>>,
+        opcodes: I,
+    ) where
+        I: IntoIterator<Item = fuel_asm::Opcode>,
+    {
+        let opcodes: hashbrown::HashSet<_> = opcodes.into_iter().collect();
+        self.opcode_gas_observer = observer
+            .filter(|_| !opcodes.is_empty())
+            .map(|observer| (observer, opcodes));
+    }
+
     /// Get contract_max_size value
     pub fn contract_max_size(&self) -> u64 {
         self.interpreter_params.contract_max_size
     }
 
     /// Get tx_offset value
+    ///
+    /// Same value as `GM` with `GMArgs::TxStart`.
     pub fn tx_offset(&self) -> usize {
         self.interpreter_params.tx_offset
     }
@@ -300,6 +769,8 @@ impl<M, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V> {
     }
 
     /// Get the chain id
+    ///
+    /// Same value as `GM` with `GMArgs::GetChainId`.
     pub fn chain_id(&self) -> ChainId {
         self.interpreter_params.chain_id
     }
@@ -338,6 +809,28 @@ pub(crate) fn is_unsafe_math(flag: Reg<FLAG>) -> bool {
     flags(flag).contains(Flags::UNSAFEMATH)
 }
 
+impl<M, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V>
+where
+    Tx: ExecutableTransaction,
+{
+    /// The tip of the current transaction, or zero if the `Tip` policy is not set.
+    ///
+    /// Unlike `GTFArgs::PolicyTip`, this never fails with `PolicyIsNotSet`.
+    pub fn resolved_tip(&self) -> Word {
+        use fuel_tx::field::Tip;
+        self.tx.tip()
+    }
+
+    /// The max fee of the current transaction, or zero if the `MaxFee` policy is not
+    /// set.
+    ///
+    /// Unlike `GTFArgs::PolicyMaxFee`, this never fails with `PolicyIsNotSet`.
+    pub fn resolved_max_fee(&self) -> Word {
+        use fuel_tx::field::MaxFeeLimit;
+        self.tx.max_fee_limit()
+    }
+}
+
 impl<M, S, Tx, Ecal, V> AsRef<S> for Interpreter<M, S, Tx, Ecal, V> {
     fn as_ref(&self) -> &S {
         &self.storage
@@ -628,6 +1121,7 @@ impl ExecutableTransaction for Blob {
 
 /// The initial balances of the transaction.
 #[derive(Default, Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitialBalances {
     /// See [`NonRetryableFreeBalances`].
     pub non_retryable: NonRetryableFreeBalances,
@@ -636,9 +1130,20 @@ pub struct InitialBalances {
 }
 
 /// Methods that should be implemented by the checked metadata of supported transactions.
-pub trait CheckedMetadata {
+pub trait CheckedMetadata: Clone {
     /// Returns the initial balances from the checked metadata of the transaction.
     fn balances(&self) -> InitialBalances;
+
+    /// Returns the minimal gas of the transaction, including the gas used by
+    /// predicates, computed during the check.
+    fn min_gas(&self) -> Word;
+
+    /// Returns the maximal gas of the transaction, i.e. the minimal gas plus the gas
+    /// limit of the script and of the unused witness bytes, computed during the check.
+    fn max_gas(&self) -> Word;
+
+    /// Returns the base asset the transaction was checked with.
+    fn base_asset_id(&self) -> AssetId;
 }
 
 impl CheckedMetadata for ScriptCheckedMetadata {
@@ -648,6 +1153,18 @@ impl CheckedMetadata for ScriptCheckedMetadata {
             retryable: Some(self.retryable_balance),
         }
     }
+
+    fn min_gas(&self) -> Word {
+        self.min_gas
+    }
+
+    fn max_gas(&self) -> Word {
+        self.max_gas
+    }
+
+    fn base_asset_id(&self) -> AssetId {
+        self.base_asset_id
+    }
 }
 
 impl CheckedMetadata for CreateCheckedMetadata {
@@ -657,6 +1174,18 @@ impl CheckedMetadata for CreateCheckedMetadata {
             retryable: None,
         }
     }
+
+    fn min_gas(&self) -> Word {
+        self.min_gas
+    }
+
+    fn max_gas(&self) -> Word {
+        self.max_gas
+    }
+
+    fn base_asset_id(&self) -> AssetId {
+        self.base_asset_id
+    }
 }
 
 impl CheckedMetadata for UpgradeCheckedMetadata {
@@ -666,6 +1195,18 @@ impl CheckedMetadata for UpgradeCheckedMetadata {
             retryable: None,
         }
     }
+
+    fn min_gas(&self) -> Word {
+        self.min_gas
+    }
+
+    fn max_gas(&self) -> Word {
+        self.max_gas
+    }
+
+    fn base_asset_id(&self) -> AssetId {
+        self.base_asset_id
+    }
 }
 
 impl CheckedMetadata for UploadCheckedMetadata {
@@ -675,6 +1216,18 @@ impl CheckedMetadata for UploadCheckedMetadata {
             retryable: None,
         }
     }
+
+    fn min_gas(&self) -> Word {
+        self.min_gas
+    }
+
+    fn max_gas(&self) -> Word {
+        self.max_gas
+    }
+
+    fn base_asset_id(&self) -> AssetId {
+        self.base_asset_id
+    }
 }
 
 impl CheckedMetadata for BlobCheckedMetadata {
@@ -684,4 +1237,16 @@ impl CheckedMetadata for BlobCheckedMetadata {
             retryable: None,
         }
     }
+
+    fn min_gas(&self) -> Word {
+        self.min_gas
+    }
+
+    fn max_gas(&self) -> Word {
+        self.max_gas
+    }
+
+    fn base_asset_id(&self) -> AssetId {
+        self.base_asset_id
+    }
 }
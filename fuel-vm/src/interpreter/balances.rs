@@ -29,6 +29,7 @@ use super::{
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Balance {
     value: Word,
     offset: usize,
@@ -64,6 +65,7 @@ impl Balance {
 
 /// Structure to encapsulate asset balances for VM runtime
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RuntimeBalances {
     state: HashMap<AssetId, Balance>,
 }
@@ -10,6 +10,7 @@ use crate::{
         SimpleResult,
     },
     interpreter::{
+        BlockContext,
         ExecutableTransaction,
         Interpreter,
         Memory,
@@ -43,6 +44,7 @@ use crate::{
         ContractsRawCode,
         ContractsStateData,
         InterpreterStorage,
+        state_slot_keys,
     },
     verification::Verifier,
 };
@@ -55,7 +57,6 @@ use fuel_asm::{
     PanicReason,
     RegId,
 };
-use fuel_storage::StorageSize;
 use fuel_tx::{
     BlobId,
     ContractIdExt,
@@ -252,6 +253,7 @@ where
         let owner = self.ownership_registers();
         coinbase(
             &self.storage,
+            self.interpreter_params.block_context.as_ref(),
             self.memory.as_mut(),
             owner,
             self.registers.pc_mut(),
@@ -308,18 +310,43 @@ where
         rb: RegId,
         c: Word,
     ) -> IoResult<(), S::DataError> {
+        let observed = self.observed_state_slot(a);
+        let cleared = self.cleared_state_slots(a, c);
         let contract_id = self.internal_contract();
         let (SystemRegisters { pc, .. }, mut w) = split_registers(&mut self.registers);
         let result = &mut w[WriteRegKey::try_from(rb)?];
 
         let input = StateClearQWord::new(a, c)?;
+        let num_slots = input.num_slots;
         let &mut Self {
             ref mut storage,
             ref memory,
             ..
         } = self;
 
-        state_clear_qword(&contract_id?, storage, memory.as_ref(), pc, result, input)
+        state_clear_qword(&contract_id?, storage, memory.as_ref(), pc, result, input)?;
+
+        if let (Some(observer), Some((contract, key))) =
+            (&self.storage_observer, observed)
+        {
+            for key in state_slot_keys(&key, num_slots) {
+                observer.on_write(&contract, &key, None);
+            }
+        }
+
+        if let (Some(set), Some((contract, key))) = (&mut self.access_set, observed) {
+            let keys = state_slot_keys(&key, num_slots).map(|key| (contract, key));
+            set.writes.extend(keys);
+        }
+
+        if let Some(policy) = &self.gas_refund_policy {
+            for (contract, key) in cleared {
+                let refund = policy.refund_on_clear(&contract, &key);
+                self.gas_refund = self.gas_refund.saturating_add(refund);
+            }
+        }
+
+        Ok(())
     }
 
     pub(crate) fn state_read_word(
@@ -328,6 +355,7 @@ where
         rb: RegId,
         c: Word,
     ) -> IoResult<(), S::DataError> {
+        let observed = self.observed_state_slot(c);
         let (SystemRegisters { fp, pc, .. }, mut w) =
             split_registers(&mut self.registers);
         let (result, got_result) = w
@@ -352,7 +380,19 @@ where
             result,
             got_result,
             c,
-        )
+        )?;
+
+        if let (Some(observer), Some((contract, key))) =
+            (&self.storage_observer, observed)
+        {
+            observer.on_read(&contract, &key);
+        }
+
+        if let (Some(set), Some(slot)) = (&mut self.access_set, observed) {
+            set.reads.insert(slot);
+        }
+
+        Ok(())
     }
 
     pub(crate) fn state_read_qword(
@@ -362,6 +402,7 @@ where
         c: Word,
         d: Word,
     ) -> IoResult<(), S::DataError> {
+        let observed = self.observed_state_slot(c);
         let owner = self.ownership_registers();
         let (SystemRegisters { pc, fp, .. }, mut w) =
             split_registers(&mut self.registers);
@@ -387,7 +428,23 @@ where
                 origin_key_pointer: c,
                 num_slots: d,
             },
-        )
+        )?;
+
+        if let (Some(observer), Some((contract, key))) =
+            (&self.storage_observer, observed)
+        {
+            for key in state_slot_keys(&key, convert::to_usize(d).unwrap_or_default()) {
+                observer.on_read(&contract, &key);
+            }
+        }
+
+        if let (Some(set), Some((contract, key))) = (&mut self.access_set, observed) {
+            let num_slots = convert::to_usize(d).unwrap_or_default();
+            let keys = state_slot_keys(&key, num_slots).map(|key| (contract, key));
+            set.reads.extend(keys);
+        }
+
+        Ok(())
     }
 
     pub(crate) fn state_write_word(
@@ -396,6 +453,8 @@ where
         rb: RegId,
         c: Word,
     ) -> IoResult<(), S::DataError> {
+        let observed = self.observed_state_slot(a);
+        let clearing = self.clearing_state_slot(a, c);
         let new_storage_gas_per_byte = self.gas_costs().new_storage_per_byte();
         let (
             SystemRegisters {
@@ -410,7 +469,7 @@ where
             ref context,
             ..
         } = self;
-        state_write_word(
+        let cleared = state_write_word(
             StateWriteWordCtx {
                 storage,
                 memory: memory.as_ref(),
@@ -424,7 +483,28 @@ where
             a,
             exists,
             c,
-        )
+        )?;
+
+        if let (Some(observer), Some((contract, key))) =
+            (&self.storage_observer, observed)
+        {
+            let mut value = Bytes32::zeroed();
+            value.as_mut()[..WORD_SIZE].copy_from_slice(&c.to_be_bytes());
+            observer.on_write(&contract, &key, Some(value.as_ref()));
+        }
+
+        if let (Some(set), Some(slot)) = (&mut self.access_set, observed) {
+            set.writes.insert(slot);
+        }
+
+        if let (Some(policy), Some((contract, key))) =
+            (&self.gas_refund_policy, clearing.filter(|_| cleared))
+        {
+            let refund = policy.refund_on_clear(&contract, &key);
+            self.gas_refund = self.gas_refund.saturating_add(refund);
+        }
+
+        Ok(())
     }
 
     pub(crate) fn state_write_qword(
@@ -434,6 +514,7 @@ where
         c: Word,
         d: Word,
     ) -> IoResult<(), S::DataError> {
+        let observed = self.observed_state_slot(a);
         let new_storage_per_byte = self.gas_costs().new_storage_per_byte();
         let contract_id = self.internal_contract();
         let (SystemRegisters { cgas, ggas, pc, .. }, mut w) =
@@ -462,14 +543,138 @@ where
             pc,
             result,
             input,
-        )
+        )?;
+
+        if let (Some(observer), Some((contract, key))) =
+            (&self.storage_observer, observed)
+        {
+            let num_slots = convert::to_usize(d).unwrap_or_default();
+            let values = self
+                .memory
+                .as_ref()
+                .read(c, num_slots.saturating_mul(Bytes32::LEN))?;
+            for (key, value) in
+                state_slot_keys(&key, num_slots).zip(values.chunks_exact(Bytes32::LEN))
+            {
+                observer.on_write(&contract, &key, Some(value));
+            }
+        }
+
+        if let (Some(set), Some((contract, key))) = (&mut self.access_set, observed) {
+            let num_slots = convert::to_usize(d).unwrap_or_default();
+            let keys = state_slot_keys(&key, num_slots).map(|key| (contract, key));
+            set.writes.extend(keys);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the code root of the contract being executed, read from the storage.
+    ///
+    /// This is the value `CROO` writes for the contract ID of the current call frame.
+    /// Fails with `PanicReason::ExpectedInternalContext` outside of a contract call.
+    pub fn contract_code_root(&self) -> Result<Bytes32, RuntimeError<S::DataError>> {
+        // An uninitialized context is internal, but there is no call frame to read.
+        if !matches!(self.context, Context::Call { .. }) {
+            return Err(PanicReason::ExpectedInternalContext.into())
+        }
+        let contract_id = self.internal_contract()?;
+        let root = self
+            .storage
+            .storage_contract(&contract_id)
+            .transpose()
+            .ok_or(PanicReason::ContractNotFound)?
+            .map_err(RuntimeError::Storage)?
+            .root();
+        Ok(root)
+    }
+
+    /// Returns the contract and the key of the state slot pointed by `key_pointer`,
+    /// if a storage observer is set or the access set is enabled, and the VM is in an
+    /// internal context.
+    ///
+    /// Must be called before executing the instruction, because it may overwrite the
+    /// memory holding the key.
+    fn observed_state_slot(&self, key_pointer: Word) -> Option<(ContractId, Bytes32)> {
+        if self.storage_observer.is_none() && self.access_set.is_none() {
+            return None;
+        }
+        let contract = self.internal_contract().ok()?;
+        let key = Bytes32::new(self.memory.as_ref().read_bytes(key_pointer).ok()?);
+        Some((contract, key))
+    }
+
+    /// Returns the contract and the key of the state slot that writing `value` at
+    /// `key_pointer` may clear, if a [`GasRefundPolicy`](super::GasRefundPolicy) is set
+    /// and the `value` is zero. The slot is cleared if it held a nonzero value, which
+    /// is reported by the write itself.
+    ///
+    /// Must be called before executing the instruction, like
+    /// [`Self::observed_state_slot`].
+    fn clearing_state_slot(
+        &self,
+        key_pointer: Word,
+        value: Word,
+    ) -> Option<(ContractId, Bytes32)> {
+        self.gas_refund_policy.as_ref()?;
+        if value != 0 {
+            return None;
+        }
+        let contract = self.internal_contract().ok()?;
+        let key = Bytes32::new(self.memory.as_ref().read_bytes(key_pointer).ok()?);
+        Some((contract, key))
+    }
+
+    /// Returns the contract and the keys of the state slots holding a nonzero value
+    /// among the `num_slots` slots starting at the key at `key_pointer`, if a
+    /// [`GasRefundPolicy`](super::GasRefundPolicy) is set.
+    ///
+    /// Must be called before clearing the slots, like [`Self::observed_state_slot`].
+    fn cleared_state_slots(
+        &self,
+        key_pointer: Word,
+        num_slots: Word,
+    ) -> Vec<(ContractId, Bytes32)> {
+        if self.gas_refund_policy.is_none() {
+            return Vec::new()
+        }
+        let (Ok(contract), Ok(start_key), Some(num_slots)) = (
+            self.internal_contract(),
+            self.memory.as_ref().read_bytes(key_pointer),
+            convert::to_usize(num_slots),
+        ) else {
+            return Vec::new()
+        };
+        let start_key = Bytes32::new(start_key);
+        let Ok(values) = self
+            .storage
+            .contract_state_range(&contract, &start_key, num_slots)
+        else {
+            return Vec::new()
+        };
+        state_slot_keys(&start_key, num_slots)
+            .zip(values)
+            .filter(|(_, value)| {
+                value
+                    .as_ref()
+                    .is_some_and(|value| value.0.iter().any(|byte| *byte != 0))
+            })
+            .map(|(key, _)| (contract, key))
+            .collect()
     }
 
     pub(crate) fn timestamp(&mut self, ra: RegId, b: Word) -> IoResult<(), S::DataError> {
         let block_height = self.get_block_height()?;
         let (SystemRegisters { pc, .. }, mut w) = split_registers(&mut self.registers);
         let result = &mut w[WriteRegKey::try_from(ra)?];
-        timestamp(&self.storage, block_height, pc, result, b)
+        timestamp(
+            &self.storage,
+            self.interpreter_params.block_context.as_ref(),
+            block_height,
+            pc,
+            result,
+            b,
+        )
     }
 
     pub(crate) fn message_output(
@@ -566,7 +771,7 @@ impl<S, V> LoadContractCodeCtx<'_, S, V> {
         )?;
 
         // Fetch the storage contract
-        let contract_len = contract_size(&self.storage, &contract_id)?;
+        let contract_len = contract_size(self.storage, &contract_id)?;
         let charge_len = core::cmp::max(contract_len as u64, length);
         dependent_gas_charge_without_base(
             self.cgas,
@@ -904,7 +1109,7 @@ impl<S, V> CodeCopyCtx<'_, S, V> {
             &contract_id,
         )?;
 
-        let contract_len = contract_size(&self.storage, &contract_id)?;
+        let contract_len = contract_size(self.storage, &contract_id)?;
         let charge_len = core::cmp::max(contract_len as u64, length);
         dependent_gas_charge_without_base(
             self.cgas,
@@ -965,12 +1170,16 @@ pub(crate) fn block_height(
 
 pub(crate) fn coinbase<S: InterpreterStorage>(
     storage: &S,
+    block_context: Option<&BlockContext>,
     memory: &mut MemoryInstance,
     owner: OwnershipRegisters,
     pc: RegMut<PC>,
     a: Word,
 ) -> IoResult<(), S::DataError> {
-    let coinbase = storage.coinbase().map_err(RuntimeError::Storage)?;
+    let coinbase = match block_context {
+        Some(block_context) => block_context.coinbase,
+        None => storage.coinbase().map_err(RuntimeError::Storage)?,
+    };
     memory.write_bytes(owner, a, *coinbase)?;
     inc_pc(pc)?;
     Ok(())
@@ -1043,9 +1252,9 @@ impl<S, V> CodeSizeCtx<'_, S, V> {
         self,
         result: &mut Word,
         b: Word,
-    ) -> Result<(), RuntimeError<S::Error>>
+    ) -> Result<(), RuntimeError<S::DataError>>
     where
-        S: StorageSize<ContractsRawCode>,
+        S: InterpreterStorage,
         V: Verifier,
     {
         let contract_id = ContractId::new(self.memory.read_bytes(b)?);
@@ -1121,6 +1330,7 @@ pub(crate) struct StateWriteWordCtx<'vm, S> {
     pub pc: RegMut<'vm, PC>,
 }
 
+/// Returns `true` if zero was written over a nonzero value.
 pub(crate) fn state_write_word<S: InterpreterStorage>(
     StateWriteWordCtx {
         storage,
@@ -1135,7 +1345,7 @@ pub(crate) fn state_write_word<S: InterpreterStorage>(
     a: Word,
     created_new: &mut Word,
     c: Word,
-) -> IoResult<(), S::DataError> {
+) -> IoResult<bool, S::DataError> {
     let key = Bytes32::new(memory.read_bytes(a)?);
     let contract = internal_contract(context, fp, memory)?;
 
@@ -1159,11 +1369,15 @@ pub(crate) fn state_write_word<S: InterpreterStorage>(
         )?;
     }
 
-    Ok(inc_pc(pc)?)
+    inc_pc(pc)?;
+
+    let cleared = prev.is_some_and(|prev| prev.iter().any(|byte| *byte != 0));
+    Ok(cleared && c == 0)
 }
 
 pub(crate) fn timestamp<S: InterpreterStorage>(
     storage: &S,
+    block_context: Option<&BlockContext>,
     block_height: BlockHeight,
     pc: RegMut<PC>,
     result: &mut Word,
@@ -1176,7 +1390,10 @@ pub(crate) fn timestamp<S: InterpreterStorage>(
         .then_some(())
         .ok_or(PanicReason::TransactionValidity)?;
 
-    *result = storage.timestamp(b).map_err(RuntimeError::Storage)?;
+    *result = match block_context {
+        Some(block_context) if block_context.height == b => block_context.time,
+        _ => storage.timestamp(b).map_err(RuntimeError::Storage)?,
+    };
 
     Ok(inc_pc(pc)?)
 }
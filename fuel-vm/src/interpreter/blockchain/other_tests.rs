@@ -214,7 +214,7 @@ fn test_coinbase() {
         prev_hp: 3000,
     };
     let mut pc = 4;
-    coinbase(&storage, &mut memory, owner, RegMut::new(&mut pc), 20).unwrap();
+    coinbase(&storage, None, &mut memory, owner, RegMut::new(&mut pc), 20).unwrap();
     assert_eq!(pc, 8);
     assert_eq!(memory[20..20 + 32], [0u8; 32]);
 }
@@ -292,6 +292,7 @@ fn test_timestamp() {
     let mut result = 0;
     let _ = timestamp(
         &storage,
+        None,
         Default::default(),
         RegMut::new(&mut pc),
         &mut result,
@@ -300,6 +301,7 @@ fn test_timestamp() {
     .expect_err("Height is greater then current block height");
     let _ = timestamp(
         &storage,
+        None,
         u32::MAX.into(),
         RegMut::new(&mut pc),
         &mut result,
@@ -310,6 +312,7 @@ fn test_timestamp() {
 
     timestamp(
         &storage,
+        None,
         Default::default(),
         RegMut::new(&mut pc),
         &mut result,
@@ -318,6 +321,14 @@ fn test_timestamp() {
     .unwrap();
     assert_eq!(pc, 8);
 
-    timestamp(&storage, 20.into(), RegMut::new(&mut pc), &mut result, 19).unwrap();
+    timestamp(
+        &storage,
+        None,
+        20.into(),
+        RegMut::new(&mut pc),
+        &mut result,
+        19,
+    )
+    .unwrap();
     assert_eq!(pc, 12);
 }
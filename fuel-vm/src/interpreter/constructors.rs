@@ -8,19 +8,24 @@ use super::{
 };
 use super::{
     Interpreter,
+    Memory,
     RuntimeBalances,
 };
 use crate::{
     consts::*,
     context::Context,
+    error::InterpreterError,
     interpreter::{
         InterpreterParams,
         PanicContext,
     },
     state::Debugger,
+    storage::InterpreterStorage,
 };
 
 use alloc::vec;
+use fuel_asm::PanicReason;
+use fuel_types::ContractId;
 
 #[cfg(feature = "test-helpers")]
 use crate::{
@@ -48,6 +53,37 @@ where
     }
 }
 
+impl<M, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V>
+where
+    S: InterpreterStorage,
+    Tx: Default,
+    Ecal: Default,
+    V: Default,
+{
+    /// Create a new interpreter instance which keeps the code of the provided contract
+    /// in memory, so calls to it don't read the code from the storage.
+    ///
+    /// Useful when the same contract is called by many transactions, e.g. in
+    /// benchmarks. Fails if the contract isn't deployed in the storage.
+    pub fn with_preloaded_contract(
+        memory: M,
+        storage: S,
+        interpreter_params: InterpreterParams,
+        contract_id: ContractId,
+    ) -> Result<Self, InterpreterError<S::DataError>> {
+        let contract = storage
+            .storage_contract(&contract_id)
+            .map_err(InterpreterError::Storage)?
+            .ok_or(InterpreterError::Panic(PanicReason::ContractNotFound))?
+            .into_owned();
+
+        let mut interpreter = Self::with_storage(memory, storage, interpreter_params);
+        interpreter.preloaded_contract = Some((contract_id, contract));
+
+        Ok(interpreter)
+    }
+}
+
 impl<M, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V>
 where
     Tx: Default,
@@ -81,7 +117,65 @@ where
             panic_context: PanicContext::None,
             ecal_state,
             verifier: Default::default(),
+            preloaded_contract: None,
+            storage_observer: None,
+            access_set: None,
+            ecal_records: None,
+            call_observer: None,
+            gas_refund_policy: None,
+            opcode_gas_observer: None,
+            gas_refund: 0,
+            initial_gas: 0,
+            gas_by_contract: None,
+            #[cfg(feature = "opcode-histogram")]
+            opcode_histogram: Default::default(),
+        }
+    }
+}
+
+impl<M, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V>
+where
+    M: Memory,
+    Tx: Default,
+    V: Default,
+{
+    /// Installs the `storage` and clears the state left by the previous executions,
+    /// so the interpreter can be reused as if it was just created. The parameters,
+    /// the breakpoints, the ECAL handler and the storage observer are kept. The log
+    /// filter of the debugger is removed. The preloaded contract is dropped, since the
+    /// new storage may not contain it.
+    ///
+    /// Returns the previous storage.
+    pub fn reset(&mut self, storage: S) -> S {
+        self.registers = [0; VM_REGISTER_COUNT];
+        self.memory.as_mut().reset();
+        self.frames.clear();
+        self.receipts = Default::default();
+        self.tx = Default::default();
+        self.initial_balances = Default::default();
+        self.input_contracts.clear();
+        self.input_contracts_index_to_output_index.clear();
+        self.context = Context::default();
+        self.balances = RuntimeBalances::default();
+        self.panic_context = PanicContext::None;
+        self.verifier = Default::default();
+        self.preloaded_contract = None;
+        self.gas_refund = 0;
+        self.initial_gas = 0;
+        if let Some(gas_by_contract) = &mut self.gas_by_contract {
+            gas_by_contract.clear();
         }
+        self.debugger.clear_log_filter();
+        if let Some(access_set) = &mut self.access_set {
+            access_set.clear();
+        }
+        if let Some(ecal_records) = &mut self.ecal_records {
+            ecal_records.clear();
+        }
+        #[cfg(feature = "opcode-histogram")]
+        self.opcode_histogram.clear();
+
+        core::mem::replace(&mut self.storage, storage)
     }
 }
 
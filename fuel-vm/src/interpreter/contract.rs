@@ -30,7 +30,6 @@ use crate::{
     storage::{
         BlobData,
         ContractsAssetsStorage,
-        ContractsRawCode,
         InterpreterStorage,
     },
     verification::Verifier,
@@ -372,12 +371,12 @@ impl<S, Tx, V> TransferCtx<'_, S, Tx, V> {
 pub(crate) fn contract_size<S>(
     storage: &S,
     contract: &ContractId,
-) -> IoResult<usize, S::Error>
+) -> IoResult<usize, S::DataError>
 where
-    S: StorageSize<ContractsRawCode> + ?Sized,
+    S: InterpreterStorage + ?Sized,
 {
     let size = storage
-        .size_of_value(contract)
+        .storage_contract_size(contract)
         .map_err(RuntimeError::Storage)?
         .ok_or(PanicReason::ContractNotFound)?;
     Ok(size)
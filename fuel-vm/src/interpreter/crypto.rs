@@ -148,6 +148,8 @@ pub(crate) fn secp256k1_recover(
 ) -> SimpleResult<()> {
     let sig = Bytes64::from(memory.read_bytes(b)?);
     let msg = Bytes32::from(memory.read_bytes(c)?);
+    // Fail before the recovery if the public key can't be written
+    memory.require(a, PublicKey::LEN as Word)?;
 
     let signature = Signature::from_bytes_ref(&sig);
     let message = Message::from_bytes_ref(&msg);
@@ -177,6 +179,8 @@ pub(crate) fn secp256r1_recover(
 ) -> SimpleResult<()> {
     let sig = Bytes64::from(memory.read_bytes(b)?);
     let msg = Bytes32::from(memory.read_bytes(c)?);
+    // Fail before the recovery if the public key can't be written
+    memory.require(a, PublicKey::LEN as Word)?;
     let message = Message::from_bytes_ref(&msg);
 
     match fuel_crypto::secp256r1::recover(&sig, message) {
@@ -206,7 +210,7 @@ pub(crate) fn ed25519_verify(
     let sig = Bytes64::from(memory.read_bytes(b)?);
     let msg = memory.read(c, len)?;
 
-    if fuel_crypto::ed25519::verify(&pub_key, &sig, msg).is_ok() {
+    if crate::crypto::ed25519_verify(&pub_key, &sig, msg) {
         clear_err(err);
     } else {
         set_err(err);
@@ -227,8 +231,12 @@ pub(crate) fn keccak256(
         Digest,
         Keccak256,
     };
+    let data = memory.read(b, c)?;
+    // Fail before hashing if the hash can't be written
+    memory.require(a, Bytes32::LEN as Word)?;
+
     let mut h = Keccak256::new();
-    h.update(memory.read(b, c)?);
+    h.update(data);
 
     memory.write_bytes(owner, a, *h.finalize().as_ref())?;
 
@@ -243,21 +251,33 @@ pub(crate) fn sha256(
     b: Word,
     c: Word,
 ) -> SimpleResult<()> {
-    memory.write_bytes(owner, a, *Hasher::hash(memory.read(b, c)?))?;
+    let data = memory.read(b, c)?;
+    // Fail before hashing if the hash can't be written
+    memory.require(a, Bytes32::LEN as Word)?;
+
+    let hash = Hasher::hash(data);
+    memory.write_bytes(owner, a, *hash)?;
     Ok(inc_pc(pc)?)
 }
 
+/// Reads `N` consecutive big endian coordinates, as required by the library.
+fn read_fq_alt_bn_128<const N: usize>(
+    memory: &MemoryInstance,
+    ptr: Word,
+) -> SimpleResult<[Fq; N]> {
+    let mut coordinates = [Fq::zero(); N];
+    for (coordinate, bytes) in coordinates.iter_mut().zip(memory.chunks32(ptr, N)?) {
+        *coordinate = Fq::from_slice(bytes)
+            .map_err(|_| fuel_tx::PanicReason::InvalidEllipticCurvePoint)?;
+    }
+    Ok(coordinates)
+}
+
 fn read_g1_point_alt_bn_128(
     memory: &MemoryInstance,
     point_ptr: Word,
 ) -> SimpleResult<G1> {
-    // Big endian required by the library
-    let arg_bytes: [u8; 2 * 32] = memory.read_bytes(point_ptr)?;
-
-    let px = Fq::from_slice(&arg_bytes[..32])
-        .map_err(|_| fuel_tx::PanicReason::InvalidEllipticCurvePoint)?;
-    let py = Fq::from_slice(&arg_bytes[32..64])
-        .map_err(|_| fuel_tx::PanicReason::InvalidEllipticCurvePoint)?;
+    let [px, py] = read_fq_alt_bn_128(memory, point_ptr)?;
 
     Ok(if px == Fq::zero() && py == Fq::zero() {
         G1::zero()
@@ -272,17 +292,7 @@ fn read_g2_point_alt_bn_128(
     memory: &MemoryInstance,
     point_ptr: Word,
 ) -> SimpleResult<G2> {
-    // Big endian required by the library
-    let arg_bytes: [u8; 4 * 32] = memory.read_bytes(point_ptr)?;
-
-    let ay = Fq::from_slice(&arg_bytes[..32])
-        .map_err(|_| fuel_tx::PanicReason::InvalidEllipticCurvePoint)?;
-    let ax = Fq::from_slice(&arg_bytes[32..64])
-        .map_err(|_| fuel_tx::PanicReason::InvalidEllipticCurvePoint)?;
-    let by = Fq::from_slice(&arg_bytes[64..96])
-        .map_err(|_| fuel_tx::PanicReason::InvalidEllipticCurvePoint)?;
-    let bx = Fq::from_slice(&arg_bytes[96..128])
-        .map_err(|_| fuel_tx::PanicReason::InvalidEllipticCurvePoint)?;
+    let [ay, ax, by, bx] = read_fq_alt_bn_128(memory, point_ptr)?;
 
     let a = Fq2::new(ax, ay);
     let b = Fq2::new(bx, by);
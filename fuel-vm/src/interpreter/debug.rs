@@ -39,6 +39,20 @@ where
         self.debugger.remove_breakpoint(breakpoint)
     }
 
+    /// Pause the execution with [`DebugEval::LogMatch`] after each `LOG` or `LOGD`
+    /// instruction whose receipt matches the `filter`.
+    pub fn set_log_filter<F>(&mut self, filter: F)
+    where
+        F: Fn(&Receipt) -> bool + Send + Sync + 'static,
+    {
+        self.debugger.set_log_filter(filter)
+    }
+
+    /// Remove the log filter, if existent.
+    pub fn clear_log_filter(&mut self) {
+        self.debugger.clear_log_filter()
+    }
+
     pub(crate) fn eval_debugger_state(&mut self) -> DebugEval {
         let debugger = &mut self.debugger;
 
@@ -208,6 +222,77 @@ mod tests {
         assert_eq!(stops, vec![0, 4, 8, 4, 8, 4, 8, 4, 8, 4, 8, 12]);
     }
 
+    #[test]
+    #[allow(non_snake_case)]
+    fn log_filter__pauses_after_the_matching_log() {
+        use fuel_asm::op;
+        use fuel_tx::ConsensusParameters;
+
+        // Given
+        let mut vm = Interpreter::<_, _, _>::with_memory_storage();
+        let consensus_params = ConsensusParameters::standard();
+        let script = [
+            op::movi(0x10, 1),
+            op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+            op::movi(0x10, 2),
+            op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+            op::movi(0x10, 3),
+            op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+            op::ret(RegId::ONE),
+        ]
+        .into_iter()
+        .collect();
+        let tx = TransactionBuilder::script(script, vec![])
+            .script_gas_limit(1_000_000)
+            .add_fee_input()
+            .finalize()
+            .into_checked(Default::default(), &consensus_params)
+            .expect("failed to generate checked tx")
+            .into_ready(
+                0,
+                consensus_params.gas_costs(),
+                consensus_params.fee_params(),
+                None,
+            )
+            .unwrap();
+        vm.set_log_filter(|receipt| matches!(receipt, Receipt::Log { ra: 2, .. }));
+
+        // When
+        let state = vm
+            .transact(tx)
+            .map(ProgramState::from)
+            .expect("Failed to execute script!");
+
+        // Then
+        let expected = DebugEval::LogMatch(Breakpoint::script(3));
+        assert_eq!(state, ProgramState::RunProgram(expected));
+        let logs: Vec<_> = vm
+            .receipts()
+            .iter()
+            .filter_map(|receipt| match receipt {
+                Receipt::Log { ra, .. } => Some(*ra),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(logs, vec![1, 2]);
+        let state = vm.resume().expect("Failed to resume");
+        assert_eq!(state, ProgramState::Return(1));
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn log_filter__is_cleared_by_reset() {
+        // Given
+        let mut vm = Interpreter::<_, _, Script>::with_memory_storage();
+        vm.set_log_filter(|_| true);
+
+        // When
+        vm.reset(MemoryStorage::default());
+
+        // Then
+        assert!(!vm.debugger.has_log_filter());
+    }
+
     #[test]
     fn resume_without_debug() {
         use fuel_asm::op;
@@ -8,12 +8,15 @@ use fuel_storage::{
 };
 use fuel_tx::ConsensusParameters;
 use fuel_types::{
+    AssetId,
     BlobId,
     BlockHeight,
     Bytes32,
     ContractId,
 };
 
+#[cfg(feature = "frame-revert")]
+use crate::storage::state_slot_keys;
 use crate::storage::{
     BlobBytes,
     BlobData,
@@ -80,7 +83,13 @@ pub(super) trait StorageType: Mappable {
 }
 
 #[derive(Debug)]
-pub struct Record<S>(pub(super) S, pub(super) Vec<StorageDelta>)
+pub struct Record<S>(
+    pub(super) S,
+    pub(super) Vec<StorageDelta>,
+    /// The number of recorded changes when each call frame was entered.
+    #[cfg(feature = "frame-revert")]
+    pub(super) Vec<usize>,
+)
 where
     S: InterpreterStorage;
 
@@ -111,6 +120,18 @@ where
             interpreter_params: self.interpreter_params,
             ecal_state: self.ecal_state,
             verifier: self.verifier,
+            preloaded_contract: self.preloaded_contract,
+            storage_observer: self.storage_observer,
+            access_set: self.access_set,
+            ecal_records: self.ecal_records,
+            call_observer: self.call_observer,
+            gas_refund_policy: self.gas_refund_policy,
+            opcode_gas_observer: self.opcode_gas_observer,
+            gas_refund: self.gas_refund,
+            initial_gas: self.initial_gas,
+            gas_by_contract: self.gas_by_contract,
+            #[cfg(feature = "opcode-histogram")]
+            opcode_histogram: self.opcode_histogram,
         }
     }
 
@@ -201,6 +222,18 @@ where
             interpreter_params: self.interpreter_params,
             ecal_state: self.ecal_state,
             verifier: self.verifier,
+            preloaded_contract: self.preloaded_contract,
+            storage_observer: self.storage_observer,
+            access_set: self.access_set,
+            ecal_records: self.ecal_records,
+            call_observer: self.call_observer,
+            gas_refund_policy: self.gas_refund_policy,
+            opcode_gas_observer: self.opcode_gas_observer,
+            gas_refund: self.gas_refund,
+            initial_gas: self.initial_gas,
+            gas_by_contract: self.gas_by_contract,
+            #[cfg(feature = "opcode-histogram")]
+            opcode_histogram: self.opcode_histogram,
         }
     }
 
@@ -413,6 +446,7 @@ where
     }
 }
 
+#[cfg(not(feature = "frame-revert"))]
 impl<Type: StorageType, S> StorageWrite<Type> for Record<S>
 where
     S: StorageWrite<Type>,
@@ -435,6 +469,47 @@ where
     }
 }
 
+/// Records the changes made through the byte-level API too, so that
+/// [`InterpreterStorage::revert_frame`] can undo them.
+#[cfg(feature = "frame-revert")]
+impl<Type, S> StorageWrite<Type> for Record<S>
+where
+    Type: StorageType<Value = [u8]>,
+    Type::OwnedValue: From<Vec<u8>>,
+    S: StorageWrite<Type>,
+    S: InterpreterStorage,
+{
+    fn write_bytes(&mut self, key: &Type::Key, buf: &[u8]) -> Result<(), Self::Error> {
+        // Goes through `replace_bytes` to record the overwritten value.
+        <Self as StorageWrite<Type>>::replace_bytes(self, key, buf).map(|_| ())
+    }
+
+    fn replace_bytes(
+        &mut self,
+        key: &Type::Key,
+        buf: &[u8],
+    ) -> Result<Option<Vec<u8>>, Self::Error> {
+        let existing = <S as StorageWrite<Type>>::replace_bytes(&mut self.0, key, buf)?;
+        self.1.push(<Type as StorageType>::record_replace(
+            key,
+            buf,
+            existing.clone().map(Into::into),
+        ));
+        Ok(existing)
+    }
+
+    fn take_bytes(&mut self, key: &Type::Key) -> Result<Option<Vec<u8>>, Self::Error> {
+        let existing = <S as StorageWrite<Type>>::take_bytes(&mut self.0, key)?;
+        if let Some(existing) = &existing {
+            self.1.push(<Type as StorageType>::record_take(
+                key,
+                existing.clone().into(),
+            ));
+        }
+        Ok(existing)
+    }
+}
+
 impl<S: ContractsAssetsStorage + InterpreterStorage> ContractsAssetsStorage
     for Record<S>
 {
@@ -497,6 +572,14 @@ where
         self.0.contract_state_range(id, start_key, range)
     }
 
+    fn merkle_contract_asset_balances(
+        &self,
+        contract: &ContractId,
+    ) -> Result<Vec<(AssetId, Word)>, Self::DataError> {
+        self.0.merkle_contract_asset_balances(contract)
+    }
+
+    #[cfg(not(feature = "frame-revert"))]
     fn contract_state_insert_range<'a, I>(
         &mut self,
         contract: &ContractId,
@@ -510,6 +593,7 @@ where
             .contract_state_insert_range(contract, start_key, values)
     }
 
+    #[cfg(not(feature = "frame-revert"))]
     fn contract_state_remove_range(
         &mut self,
         contract: &ContractId,
@@ -519,6 +603,131 @@ where
         self.0
             .contract_state_remove_range(contract, start_key, range)
     }
+
+    #[cfg(feature = "frame-revert")]
+    fn contract_state_insert_range<'a, I>(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        values: I,
+    ) -> Result<usize, Self::DataError>
+    where
+        I: Iterator<Item = &'a [u8]>,
+    {
+        let values: Vec<&[u8]> = values.collect();
+        let existing: Vec<_> = self
+            .0
+            .contract_state_range(contract, start_key, values.len())?
+            .into_iter()
+            .map(|value| value.map(alloc::borrow::Cow::into_owned))
+            .collect();
+        let unset = self.0.contract_state_insert_range(
+            contract,
+            start_key,
+            values.iter().copied(),
+        )?;
+
+        let keys = state_slot_keys(start_key, values.len());
+        for ((key, value), existing) in keys.zip(values).zip(existing) {
+            let key = (contract, &key).into();
+            self.1.push(<ContractsState as StorageType>::record_replace(
+                &key, value, existing,
+            ));
+        }
+        Ok(unset)
+    }
+
+    #[cfg(feature = "frame-revert")]
+    fn contract_state_remove_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Option<()>, S::DataError> {
+        let existing: Vec<_> = self
+            .0
+            .contract_state_range(contract, start_key, range)?
+            .into_iter()
+            .map(|value| value.map(alloc::borrow::Cow::into_owned))
+            .collect();
+        let removed = self
+            .0
+            .contract_state_remove_range(contract, start_key, range)?;
+
+        let keys = state_slot_keys(start_key, range);
+        for (key, existing) in keys.zip(existing) {
+            if let Some(existing) = existing {
+                let key = (contract, &key).into();
+                self.1
+                    .push(<ContractsState as StorageType>::record_take(&key, existing));
+            }
+        }
+        Ok(removed)
+    }
+
+    #[cfg(feature = "frame-revert")]
+    fn enter_frame(&mut self, depth: usize) {
+        self.2.truncate(depth.saturating_sub(1));
+        self.2.resize(depth, self.1.len());
+    }
+
+    #[cfg(feature = "frame-revert")]
+    fn revert_frame(&mut self, depth: usize) -> Result<bool, Self::DataError> {
+        self.2.truncate(depth);
+        let Some(&checkpoint) = depth.checked_sub(1).and_then(|i| self.2.get(i)) else {
+            return Ok(false);
+        };
+
+        let deltas = self.1.split_off(checkpoint.min(self.1.len()));
+        let storage = &mut self.0;
+        for delta in deltas.into_iter().rev() {
+            match delta {
+                StorageDelta::State(delta) => {
+                    undo_mappable_delta::<ContractsState, _, _>(storage, delta, |v| {
+                        v.as_ref()
+                    })?
+                }
+                StorageDelta::Assets(delta) => {
+                    undo_mappable_delta::<ContractsAssets, _, _>(storage, delta, |v| v)?
+                }
+                StorageDelta::RawCode(delta) => {
+                    undo_mappable_delta::<ContractsRawCode, _, _>(storage, delta, |v| {
+                        v.as_ref()
+                    })?
+                }
+                StorageDelta::UploadedBytecode(delta) => {
+                    undo_mappable_delta::<UploadedBytecodes, _, _>(storage, delta, |v| v)?
+                }
+                StorageDelta::BlobData(delta) => {
+                    undo_mappable_delta::<BlobData, _, _>(storage, delta, |v| v.as_ref())?
+                }
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Restores the value the `delta` replaced or took.
+#[cfg(feature = "frame-revert")]
+fn undo_mappable_delta<Type, S, V>(
+    storage: &mut S,
+    delta: MappableDelta<Type::Key, V>,
+    value: fn(&V) -> &Type::Value,
+) -> Result<(), <S as StorageInspect<Type>>::Error>
+where
+    Type: Mappable,
+    Type::Key: Sized,
+    S: StorageMutate<Type>,
+{
+    match delta {
+        MappableDelta::Replace(key, _, Some(existing))
+        | MappableDelta::Take(key, existing) => {
+            StorageMutate::<Type>::insert(storage, &key, value(&existing))
+        }
+        MappableDelta::Replace(key, _, None) => {
+            StorageMutate::<Type>::remove(storage, &key)
+        }
+    }
 }
 
 impl StorageType for ContractsState {
@@ -599,6 +808,11 @@ where
     S: InterpreterStorage,
 {
     pub fn new(s: S) -> Self {
-        Self(s, Vec::new())
+        Self(
+            s,
+            Vec::new(),
+            #[cfg(feature = "frame-revert")]
+            Vec::new(),
+        )
     }
 }
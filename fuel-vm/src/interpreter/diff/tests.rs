@@ -287,7 +287,7 @@ fn reset_vm_txns() {
     let desired = Interpreter::<_, _, Script>::with_memory_storage();
     let mut latest = Interpreter::<_, _, Script>::with_memory_storage();
     latest
-        .tx
+        .transaction_mut()
         .outputs_mut()
         .push(fuel_tx::Output::ContractCreated {
             contract_id: Default::default(),
@@ -298,3 +298,60 @@ fn reset_vm_txns() {
     latest.reset_vm_state(&diff);
     assert_eq!(desired, latest);
 }
+
+#[cfg(feature = "frame-revert")]
+#[test]
+#[allow(non_snake_case)]
+fn record__revert_frame_restores_the_changes_of_the_frame() {
+    use crate::storage::{
+        ContractsAssetsStorage,
+        InterpreterStorage,
+    };
+    use fuel_types::Bytes32;
+
+    let contract = ContractId::from([1; 32]);
+    let key = Bytes32::from([2; 32]);
+    let asset_key = (&contract, &AssetId::default()).into();
+    let read_slots = |storage: &Record<MemoryStorage>| {
+        storage
+            .contract_state_range(&contract, &key, 2)
+            .unwrap()
+            .into_iter()
+            .map(|value| value.map(|value| value.into_owned()))
+            .collect::<Vec<_>>()
+    };
+
+    // Given
+    let mut storage = Record::new(MemoryStorage::default());
+    storage.enter_frame(1);
+    storage
+        .contract_state_insert_range(&contract, &key, [&[1u8; 32][..]].into_iter())
+        .unwrap();
+    <Record<_> as StorageMutate<ContractsAssets>>::insert(&mut storage, &asset_key, &5)
+        .unwrap();
+    let outer_slots = read_slots(&storage);
+    storage.enter_frame(2);
+    storage
+        .contract_state_insert_range(
+            &contract,
+            &key,
+            [&[3u8; 32][..], &[4u8; 32][..]].into_iter(),
+        )
+        .unwrap();
+    <Record<_> as StorageMutate<ContractsAssets>>::insert(&mut storage, &asset_key, &7)
+        .unwrap();
+
+    // When
+    let reverted = storage.revert_frame(2).unwrap();
+
+    // Then
+    assert!(reverted);
+    assert_eq!(read_slots(&storage), outer_slots);
+    let balance = storage
+        .contract_asset_id_balance(&contract, &AssetId::default())
+        .unwrap();
+    assert_eq!(balance, Some(5));
+    assert!(storage.revert_frame(1).unwrap());
+    assert_eq!(read_slots(&storage), vec![None, None]);
+    assert!(!storage.revert_frame(2).unwrap());
+}
@@ -10,9 +10,18 @@ use crate::{
         SystemRegisters,
         split_registers,
     },
-    error::SimpleResult,
+    consts::VM_REGISTER_COUNT,
+    error::{
+        PanicOrBug,
+        SimpleResult,
+    },
     interpreter::NotSupportedEcal,
 };
+use alloc::{
+    collections::VecDeque,
+    vec::Vec,
+};
+use fuel_types::Word;
 
 use super::{
     Interpreter,
@@ -20,7 +29,19 @@ use super::{
     internal::inc_pc,
 };
 
+#[cfg(feature = "frame-revert")]
+use crate::{
+    error::{
+        IoResult,
+        RuntimeError,
+    },
+    storage::InterpreterStorage,
+};
+
 /// ECAL opcode handler
+///
+/// The handler receives the interpreter, so it can read the registers, the memory and
+/// the gas state, e.g. [`Interpreter::gas_charged_so_far`].
 pub trait EcalHandler: Clone
 where
     Self: Sized,
@@ -71,18 +92,125 @@ impl EcalHandler for PredicateErrorEcal {
     }
 }
 
+/// The effect of an executed ECAL instruction on the registers, recorded when enabled
+/// with [`Interpreter::set_ecal_recording_enabled`].
+///
+/// Changes of the memory made by the handler aren't recorded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EcalRecord {
+    /// The registers changed by the instruction, including `PC`, with their new
+    /// values.
+    pub registers: Vec<(u8, Word)>,
+    /// The reason of the panic, if the handler failed.
+    pub panic: Option<PanicReason>,
+}
+
+impl EcalRecord {
+    /// Records the effect of an ECAL instruction from the registers before and after
+    /// it. Returns `None` if the instruction hit a VM bug.
+    fn new(
+        before: &[Word; VM_REGISTER_COUNT],
+        after: &[Word; VM_REGISTER_COUNT],
+        result: &SimpleResult<()>,
+    ) -> Option<Self> {
+        let panic = match result {
+            Ok(()) => None,
+            Err(PanicOrBug::Panic(reason)) => Some(*reason),
+            Err(PanicOrBug::Bug(_)) => return None,
+        };
+        let registers = (0..=u8::MAX)
+            .zip(before.iter().zip(after.iter()))
+            .filter(|(_, (before, after))| before != after)
+            .map(|(index, (_, after))| (index, *after))
+            .collect();
+
+        Some(Self { registers, panic })
+    }
+}
+
+/// ECAL opcode handler replaying the [`EcalRecord`]s of a previous execution, in
+/// order, instead of calling the original handler.
+///
+/// Panics with [`PanicReason::EcalError`] if there is no record left.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReplayEcal {
+    records: VecDeque<EcalRecord>,
+}
+
+impl ReplayEcal {
+    /// Creates a handler replaying the `records`.
+    pub fn new<I>(records: I) -> Self
+    where
+        I: IntoIterator<Item = EcalRecord>,
+    {
+        Self {
+            records: records.into_iter().collect(),
+        }
+    }
+}
+
+impl EcalHandler for ReplayEcal {
+    /// The recorded registers already include `PC`.
+    const INC_PC: bool = false;
+
+    fn ecal<M, S, Tx, V>(
+        vm: &mut Interpreter<M, S, Tx, Self, V>,
+        _: RegId,
+        _: RegId,
+        _: RegId,
+        _: RegId,
+    ) -> SimpleResult<()> {
+        let record = vm
+            .ecal_state
+            .records
+            .pop_front()
+            .ok_or(PanicReason::EcalError)?;
+        for (index, value) in record.registers {
+            let register = vm
+                .registers
+                .get_mut(usize::from(index))
+                .ok_or(PanicReason::EcalError)?;
+            *register = value;
+        }
+
+        match record.panic {
+            Some(reason) => Err(reason.into()),
+            None => Ok(()),
+        }
+    }
+}
+
 impl<M, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V>
 where
     M: Memory,
     Ecal: EcalHandler,
 {
-    /// Executes ECAL opcode handler function and increments PC
+    /// Executes ECAL opcode handler function and increments PC. Records the effect of
+    /// the instruction if enabled.
     pub(crate) fn external_call(
         &mut self,
         a: RegId,
         b: RegId,
         c: RegId,
         d: RegId,
+    ) -> SimpleResult<()> {
+        let registers_before = self.ecal_records.is_some().then_some(self.registers);
+        let result = self.handle_external_call(a, b, c, d);
+        if let (Some(records), Some(before)) = (&mut self.ecal_records, registers_before)
+        {
+            records.extend(EcalRecord::new(&before, &self.registers, &result));
+        }
+        result
+    }
+
+    fn handle_external_call(
+        &mut self,
+        a: RegId,
+        b: RegId,
+        c: RegId,
+        d: RegId,
     ) -> SimpleResult<()> {
         Ecal::ecal(self, a, b, c, d)?;
         let (SystemRegisters { pc, .. }, _) = split_registers(&mut self.registers);
@@ -94,6 +222,35 @@ where
     }
 }
 
+/// The value of the first register of the `ECAL` reverting the current call frame,
+/// see [`crate::interpreter::InterpreterParams::frame_revert_ecal`].
+#[cfg(feature = "frame-revert")]
+pub const FRAME_REVERT_ECAL_ID: Word = Word::MAX;
+
+#[cfg(feature = "frame-revert")]
+impl<M, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V>
+where
+    S: InterpreterStorage,
+{
+    /// Reverts the storage changes of the current call frame and increments PC, see
+    /// [`crate::interpreter::InterpreterParams::frame_revert_ecal`].
+    pub(crate) fn revert_frame(&mut self) -> IoResult<(), S::DataError> {
+        let depth = self.frames.len();
+        if depth == 0 {
+            return Err(PanicReason::ExpectedInternalContext.into())
+        }
+        let reverted = self
+            .storage
+            .revert_frame(depth)
+            .map_err(RuntimeError::Storage)?;
+        if !reverted {
+            return Err(PanicReason::EcalError.into())
+        }
+        let (SystemRegisters { pc, .. }, _) = split_registers(&mut self.registers);
+        Ok(inc_pc(pc)?)
+    }
+}
+
 impl<M, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V>
 where
     Ecal: EcalHandler,
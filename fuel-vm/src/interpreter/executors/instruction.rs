@@ -1,5 +1,6 @@
 use crate::{
     error::{
+        CryptoFailure,
         InterpreterError,
         IoResult,
         RuntimeError,
@@ -17,6 +18,7 @@ use crate::{
 
 use fuel_asm::{
     Instruction,
+    Opcode,
     PanicInstruction,
     PanicReason,
     RawInstruction,
@@ -60,6 +62,22 @@ where
         Ok(raw_instruction)
     }
 
+    /// Decodes the instruction located in `$m[$pc]` without executing it or advancing
+    /// `$pc`, e.g. to display the next instruction while single-stepping.
+    ///
+    /// Returns an error if `$pc` doesn't point to executable memory or the bytes
+    /// there aren't a valid instruction.
+    pub fn peek_instruction(&self) -> Result<Instruction, RuntimeError<S::DataError>> {
+        let pc = self.registers[RegId::PC];
+        if pc < self.registers[RegId::IS] || pc >= self.registers[RegId::SSP] {
+            return Err(PanicReason::MemoryNotExecutable.into())
+        }
+
+        let raw_instruction: [u8; 4] = self.memory().read_bytes(pc)?;
+        Instruction::try_from(raw_instruction)
+            .map_err(|_| PanicReason::InvalidInstruction.into())
+    }
+
     /// Execute a provided instruction
     pub fn instruction<R, const PREDICATE: bool>(
         &mut self,
@@ -85,9 +103,46 @@ where
             }
         }
 
-        self.instruction_inner::<PREDICATE>(raw).map_err(|e| {
+        if self.interpreter_params.error_on_unknown_opcode
+            && Instruction::try_from(raw).is_err()
+        {
+            return Err(InterpreterError::UnknownOpcode(
+                RawInstruction::from_be_bytes(raw),
+            ))
+        }
+
+        let receipts = self.receipts.len();
+        let state = self.instruction_inner::<PREDICATE>(raw).map_err(|e| {
             InterpreterError::from_runtime(e, RawInstruction::from_be_bytes(raw))
-        })
+        })?;
+
+        if PREDICATE && self.interpreter_params.crypto_failure_to_host {
+            if let Some(failure) = Opcode::try_from(raw[0])
+                .ok()
+                .and_then(CryptoFailure::from_opcode)
+            {
+                if self.registers[RegId::ERR] != 0 {
+                    return Err(InterpreterError::CryptoFailure(failure))
+                }
+            }
+        }
+
+        if self.debugger.has_log_filter()
+            && state == ExecuteState::Proceed
+            && self.receipts.len() > receipts
+        {
+            let debug = self
+                .receipts
+                .as_ref()
+                .last()
+                .map(|receipt| self.debugger.eval_receipt(receipt))
+                .unwrap_or_default();
+            if !debug.should_continue() {
+                return Ok(debug.into())
+            }
+        }
+
+        Ok(state)
     }
 
     fn instruction_inner<const PREDICATE: bool>(
@@ -100,12 +155,53 @@ where
         if PREDICATE {
             // TODO additional branch that might be optimized after
             // https://github.com/FuelLabs/fuel-asm/issues/68
-            if !instruction.opcode().is_predicate_allowed() {
+            let opcode = instruction.opcode();
+            let is_allowed_log = self.interpreter_params.allow_logs_in_predicates
+                && matches!(opcode, Opcode::LOG | Opcode::LOGD);
+            if !opcode.is_predicate_allowed() && !is_allowed_log {
                 return Err(PanicReason::ContractInstructionNotAllowed.into())
             }
         }
 
-        instruction.execute(self)
+        #[cfg(feature = "opcode-histogram")]
+        {
+            let count = self
+                .opcode_histogram
+                .entry(instruction.opcode())
+                .or_default();
+            *count = count.saturating_add(1);
+        }
+
+        let contract = self
+            .frames
+            .last()
+            .filter(|_| self.gas_by_contract.is_some())
+            .map(|frame| *frame.to());
+        let observed = self
+            .opcode_gas_observer
+            .as_ref()
+            .is_some_and(|(_, opcodes)| opcodes.contains(&instruction.opcode()));
+        if contract.is_none() && !observed {
+            return instruction.execute(self)
+        }
+
+        let gas_before = self.registers[RegId::GGAS];
+        let result = instruction.execute(self);
+        // The gas is attributed even if the instruction failed, since it was burned.
+        let charged = gas_before.saturating_sub(self.registers[RegId::GGAS]);
+        if let (Some(gas_by_contract), Some(contract)) =
+            (&mut self.gas_by_contract, contract)
+        {
+            let gas = gas_by_contract.entry(contract).or_default();
+            *gas = gas.saturating_add(charged);
+        }
+        if let Some((observer, _)) =
+            self.opcode_gas_observer.as_ref().filter(|_| observed)
+        {
+            observer.on_gas_charged(instruction.opcode(), charged);
+        }
+
+        result
     }
 }
 
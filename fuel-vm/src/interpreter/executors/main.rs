@@ -23,7 +23,10 @@ use crate::{
         RuntimeBalances,
     },
     pool::VmMemoryPool,
-    predicate::RuntimePredicate,
+    predicate::{
+        RuntimePredicate,
+        predicate_cache_key,
+    },
     prelude::{
         BugVariant,
         RuntimeError,
@@ -41,6 +44,7 @@ use crate::{
     verification::Verifier,
 };
 use alloc::{
+    boxed::Box,
     vec,
     vec::Vec,
 };
@@ -148,6 +152,27 @@ pub mod predicates {
     use super::*;
     use crate::storage::predicate::PredicateStorageProvider;
 
+    /// The failure of the predicates verification along with the memory left behind by
+    /// the first failing predicate.
+    #[derive(Debug, Clone)]
+    pub struct PredicateFailure {
+        /// The reason of the failure.
+        pub error: PredicateVerificationFailed,
+        /// The memory of the first failing predicate at the end of its execution.
+        /// `None` if no predicate failed.
+        pub memory: Option<Box<MemoryInstance>>,
+    }
+
+    /// The result of the predicates verification along with the receipts produced by
+    /// each predicate.
+    #[derive(Debug, Clone)]
+    pub struct PredicatesReceipts {
+        /// The result of the verification.
+        pub result: Result<PredicatesChecked, PredicateVerificationFailed>,
+        /// The index of each executed predicate input with the receipts it produced.
+        pub receipts: Vec<(usize, Vec<Receipt>)>,
+    }
+
     /// Initialize the VM with the provided transaction and check all predicates defined
     /// in the inputs.
     ///
@@ -169,7 +194,89 @@ pub mod predicates {
             params,
             memory.as_mut(),
             storage,
+            None,
+            None,
+        )
+    }
+
+    /// Same as [`check_predicates`], but on failure also returns the memory left behind
+    /// by the first failing predicate, so it can be inspected.
+    ///
+    /// The memory is only copied when a predicate fails.
+    pub fn check_predicates_retaining_memory<Tx>(
+        checked: &Checked<Tx>,
+        params: &CheckPredicateParams,
+        mut memory: impl Memory,
+        storage: &impl PredicateStorageRequirements,
+    ) -> Result<PredicatesChecked, PredicateFailure>
+    where
+        Tx: ExecutableTransaction,
+        <Tx as IntoChecked>::Metadata: CheckedMetadata,
+    {
+        let mut failed_memory = None;
+        run_predicates(
+            PredicateRunKind::Verifying(checked.transaction()),
+            params,
+            memory.as_mut(),
+            storage,
+            Some(&mut failed_memory),
+            None,
         )
+        .map_err(|error| PredicateFailure {
+            error,
+            memory: failed_memory,
+        })
+    }
+
+    /// Initialize the VM with the provided transaction and check all predicates defined
+    /// in the inputs one by one, stopping at the first failure.
+    ///
+    /// Unlike [`check_predicates`], it doesn't require the transaction to be `Checked`
+    /// and doesn't depend on the async executors, so it is available in `no_std`.
+    ///
+    /// The storage provider is not used since contract opcodes are not allowed for
+    /// predicates.
+    pub fn check_predicates_all<Tx>(
+        tx: &Tx,
+        params: &CheckPredicateParams,
+        mut memory: impl Memory,
+        storage: &impl PredicateStorageRequirements,
+    ) -> Result<(), PredicateVerificationFailed>
+    where
+        Tx: ExecutableTransaction,
+    {
+        check_witnesses_size(tx, params)?;
+
+        let max_gas = tx.max_gas(&params.gas_costs, &params.fee_params);
+        if max_gas > params.max_gas_per_tx {
+            return Err(
+                PredicateVerificationFailed::TransactionExceedsTotalGasAllowance(max_gas),
+            );
+        }
+
+        let mut cumulative_gas_used: u64 = 0;
+        for index in 0..tx.inputs().len() {
+            if let Some(predicate) =
+                RuntimePredicate::from_tx(tx, params.tx_offset, index)
+            {
+                let (gas_used, result) = check_predicate(
+                    tx.clone(),
+                    index,
+                    PredicateAction::Verifying,
+                    predicate,
+                    params.clone(),
+                    memory.as_mut(),
+                    storage,
+                    None,
+                );
+                result?;
+                cumulative_gas_used = cumulative_gas_used
+                    .checked_add(gas_used)
+                    .ok_or(PredicateVerificationFailed::OutOfGas { index })?;
+            }
+        }
+
+        Ok(())
     }
 
     /// Initialize the VM with the provided transaction and check all predicates defined
@@ -221,10 +328,63 @@ pub mod predicates {
             params,
             memory.as_mut(),
             storage,
+            None,
+            None,
         )?;
         Ok(predicates_checked)
     }
 
+    /// Same as [`check_predicates`], but allows the `LOG` and `LOGD` instructions in
+    /// the predicates and collects the receipts they produce, to debug them.
+    ///
+    /// The logs consume gas, so the predicates using them must be estimated with
+    /// [`estimate_predicates_verbose`]. Must not be used for the consensus-relevant
+    /// verification, since [`check_predicates`] rejects these instructions.
+    pub fn check_predicates_verbose<Tx>(
+        checked: &Checked<Tx>,
+        params: &CheckPredicateParams,
+        mut memory: impl Memory,
+        storage: &impl PredicateStorageRequirements,
+    ) -> PredicatesReceipts
+    where
+        Tx: ExecutableTransaction,
+        <Tx as IntoChecked>::Metadata: CheckedMetadata,
+    {
+        let mut receipts = vec![];
+        let result = run_predicates(
+            PredicateRunKind::Verifying(checked.transaction()),
+            params,
+            memory.as_mut(),
+            storage,
+            None,
+            Some(&mut receipts),
+        );
+        PredicatesReceipts { result, receipts }
+    }
+
+    /// Same as [`estimate_predicates`], but allows the `LOG` and `LOGD` instructions in
+    /// the predicates and collects the receipts they produce, to debug them.
+    pub fn estimate_predicates_verbose<Tx>(
+        transaction: &mut Tx,
+        params: &CheckPredicateParams,
+        mut memory: impl Memory,
+        storage: &impl PredicateStorageRequirements,
+    ) -> PredicatesReceipts
+    where
+        Tx: ExecutableTransaction,
+    {
+        let mut receipts = vec![];
+        let result = run_predicates(
+            PredicateRunKind::Estimating(transaction),
+            params,
+            memory.as_mut(),
+            storage,
+            None,
+            Some(&mut receipts),
+        );
+        PredicatesReceipts { result, receipts }
+    }
+
     /// Initialize the VM with the provided transaction, check all predicates defined in
     /// the inputs and set the predicate_gas_used to be the actual gas consumed during
     /// execution for each predicate in parallel.
@@ -262,6 +422,8 @@ pub mod predicates {
         Tx: ExecutableTransaction + Send + 'static,
         E: ParallelExecutor,
     {
+        check_witnesses_size(kind.tx(), params)?;
+
         let mut checks = vec![];
         let tx_offset = params.tx_offset;
 
@@ -294,6 +456,7 @@ pub mod predicates {
                         my_params,
                         memory.as_mut(),
                         &storage_instance,
+                        None,
                     );
 
                     (index, result.map(|()| used_gas))
@@ -313,10 +476,14 @@ pub mod predicates {
         params: &CheckPredicateParams,
         mut memory: impl Memory,
         storage: &impl PredicateStorageRequirements,
+        mut failed_memory: Option<&mut Option<Box<MemoryInstance>>>,
+        mut receipts: Option<&mut Vec<(usize, Vec<Receipt>)>>,
     ) -> Result<PredicatesChecked, PredicateVerificationFailed>
     where
         Tx: ExecutableTransaction,
     {
+        check_witnesses_size(kind.tx(), params)?;
+
         let mut checks = vec![];
 
         let max_gas = kind.tx().max_gas(&params.gas_costs, &params.fee_params);
@@ -337,6 +504,7 @@ pub mod predicates {
                         PredicateAction::Estimating { available_gas }
                     }
                 };
+                let mut predicate_receipts = receipts.is_some().then(Vec::new);
                 let (gas_used, result) = check_predicate(
                     tx,
                     index,
@@ -345,8 +513,18 @@ pub mod predicates {
                     params.clone(),
                     memory.as_mut(),
                     storage,
+                    predicate_receipts.as_mut(),
                 );
+                if let (Some(receipts), Some(predicate_receipts)) =
+                    (receipts.as_mut(), predicate_receipts)
+                {
+                    receipts.push((index, predicate_receipts));
+                }
                 global_available_gas = global_available_gas.saturating_sub(gas_used);
+                if let (Err(_), Some(failed_memory)) = (&result, failed_memory.as_mut()) {
+                    failed_memory
+                        .get_or_insert_with(|| Box::new(memory.as_ref().clone()));
+                }
                 checks.push((index, result.map(|()| gas_used)));
             }
         }
@@ -354,6 +532,32 @@ pub mod predicates {
         finalize_check_predicate(kind, checks, params)
     }
 
+    /// Rejects the transaction if its witnesses are larger than
+    /// [`CheckPredicateParams::max_witness_bytes`], if set, before allocating the VM
+    /// memory for the predicates.
+    fn check_witnesses_size<Tx>(
+        tx: &Tx,
+        params: &CheckPredicateParams,
+    ) -> Result<(), PredicateVerificationFailed>
+    where
+        Tx: ExecutableTransaction,
+    {
+        let Some(max) = params.max_witness_bytes else {
+            return Ok(());
+        };
+        let size = tx
+            .witnesses()
+            .iter()
+            .map(|witness| witness.as_ref().len() as u64)
+            .fold(0u64, u64::saturating_add);
+        if size > max {
+            return Err(PredicateVerificationFailed::WitnessesTooLarge { size, max });
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn check_predicate<Tx>(
         tx: Tx,
         index: usize,
@@ -362,6 +566,7 @@ pub mod predicates {
         params: CheckPredicateParams,
         memory: &mut MemoryInstance,
         storage: &impl PredicateStorageRequirements,
+        receipts: Option<&mut Vec<Receipt>>,
     ) -> (Word, Result<(), PredicateVerificationFailed>)
     where
         Tx: ExecutableTransaction,
@@ -394,8 +599,27 @@ pub mod predicates {
             }
         }
 
+        // The cache doesn't hold the receipts, so it is only used without them.
+        let cache = match (predicate_action, &receipts) {
+            (PredicateAction::Verifying, None) => params
+                .predicate_result_cache
+                .clone()
+                .map(|cache| (predicate_cache_key(&tx, index, &params), cache)),
+            _ => None,
+        };
+        if cache
+            .as_ref()
+            .is_some_and(|(key, cache)| cache.contains(key))
+        {
+            let gas_used = tx.inputs()[index]
+                .predicate_gas_used()
+                .expect("We only run predicates at this stage, so it should exist.");
+            return (gas_used, Ok(()));
+        }
+
         let zero_gas_price = 0;
-        let interpreter_params = InterpreterParams::new(zero_gas_price, params);
+        let mut interpreter_params = InterpreterParams::new(zero_gas_price, params);
+        interpreter_params.allow_logs_in_predicates = receipts.is_some();
 
         let mut vm = Interpreter::<_, _, _>::with_storage(
             memory,
@@ -427,6 +651,9 @@ pub mod predicates {
         }
 
         let result = vm.verify_predicate();
+        if let Some(receipts) = receipts {
+            receipts.extend_from_slice(vm.receipts());
+        }
         let is_successful = matches!(result, Ok(ProgramState::Return(0x01)));
 
         let Some(gas_used) = available_gas.checked_sub(vm.remaining_gas()) else {
@@ -451,6 +678,10 @@ pub mod predicates {
                     Err(PredicateVerificationFailed::GasMismatch { index }),
                 );
             }
+
+            if let Some((key, cache)) = cache {
+                cache.insert(key);
+            }
         }
 
         (gas_used, Ok(()))
@@ -1050,6 +1281,11 @@ where
         let gas_used = gas_limit
             .checked_sub(self.remaining_gas())
             .ok_or_else(|| Bug::new(BugVariant::GlobalGasUnderflow))?;
+        let gas_used = if matches!(state, ProgramState::Revert(_)) {
+            gas_used
+        } else {
+            gas_used.saturating_sub(self.capped_gas_refund(gas_used))
+        };
         self.receipts
             .push(Receipt::script_result(result, gas_used))?;
 
@@ -1110,6 +1346,60 @@ where
     }
 }
 
+#[cfg(feature = "test-helpers")]
+impl<M, S, Ecal, V> Interpreter<M, S, fuel_tx::Script, Ecal, V>
+where
+    M: Memory,
+    S: InterpreterStorage,
+    Ecal: EcalHandler,
+    V: Verifier,
+{
+    /// Executes `instructions` as the script of a transaction without inputs and
+    /// outputs, with `gas` as the script gas limit, and returns the receipts.
+    ///
+    /// The validation of the transaction and the fees are skipped, but the gas and the
+    /// memory bounds are enforced like for any other script, so the panics are
+    /// reported by the receipts.
+    pub fn execute_raw(
+        &mut self,
+        instructions: &[fuel_asm::Instruction],
+        gas: Word,
+    ) -> Vec<Receipt> {
+        use fuel_asm::RegId;
+        use fuel_tx::{
+            Finalizable,
+            TransactionBuilder,
+        };
+
+        let script = instructions.iter().copied().collect();
+        // The maximal fee limit keeps the refund of the unused gas from underflowing
+        // for any gas price, since there are no coins to pay for it anyway.
+        let tx = TransactionBuilder::script(script, vec![])
+            .script_gas_limit(gas)
+            .max_fee_limit(Word::MAX)
+            .finalize();
+
+        let block_height = match self.interpreter_params.block_context {
+            Some(block_context) => block_context.height,
+            None => self
+                .storage
+                .block_height()
+                .expect("The storage should provide the block height"),
+        };
+        self.context = Context::Script { block_height };
+
+        self.init_inner(tx, Default::default(), Default::default(), gas)
+            .expect("The script should fit into the memory");
+        let offset = self.tx_offset().saturating_add(self.tx.script_offset()) as Word;
+        self.registers[RegId::PC] = offset;
+        self.registers[RegId::IS] = offset;
+
+        self.run_program()
+            .expect("The script should not fail outside of the VM");
+        self.receipts().to_vec()
+    }
+}
+
 impl<M, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V>
 where
     S: InterpreterStorage,
@@ -2382,12 +2382,7 @@ where
         interpreter: &mut Interpreter<M, S, Tx, Ecal, V>,
     ) -> IoResult<ExecuteState, S::DataError> {
         let (a, b, c, len) = self.unpack();
-        let mut len = interpreter.registers[len];
-
-        // Backwards compatibility with old contracts
-        if len == 0 {
-            len = 32;
-        }
+        let len = crate::crypto::ed25519_message_len(interpreter.registers[len]);
 
         interpreter.dependent_gas_charge(interpreter.gas_costs().ed19(), len)?;
         interpreter.ed25519_verify(
@@ -2557,6 +2552,15 @@ where
         interpreter: &mut Interpreter<M, S, Tx, Ecal, V>,
     ) -> IoResult<ExecuteState, S::DataError> {
         let (a, b, c, d) = self.unpack();
+
+        #[cfg(feature = "frame-revert")]
+        if interpreter.interpreter_params.frame_revert_ecal
+            && interpreter.registers[a] == crate::interpreter::FRAME_REVERT_ECAL_ID
+        {
+            interpreter.revert_frame()?;
+            return Ok(ExecuteState::Proceed)
+        }
+
         interpreter.external_call(a, b, c, d)?;
         Ok(ExecuteState::Proceed)
     }
@@ -62,6 +62,7 @@ use fuel_storage::{
     StorageSize,
 };
 use fuel_tx::{
+    Contract,
     DependentCost,
     PanicReason,
     Receipt,
@@ -95,6 +96,7 @@ where
     pub(crate) fn ret(&mut self, a: Word) -> SimpleResult<()> {
         let current_contract =
             current_contract(&self.context, self.registers.fp(), self.memory.as_ref())?;
+        let in_call = !self.frames.is_empty();
         let input = RetCtx {
             receipts: &mut self.receipts,
             frames: &mut self.frames,
@@ -103,12 +105,17 @@ where
             context: &mut self.context,
             current_contract,
         };
-        input.ret(a)
+        input.ret(a)?;
+        if in_call {
+            self.notify_return();
+        }
+        Ok(())
     }
 
     pub(crate) fn ret_data(&mut self, a: Word, b: Word) -> SimpleResult<Bytes32> {
         let current_contract =
             current_contract(&self.context, self.registers.fp(), self.memory.as_ref())?;
+        let in_call = !self.frames.is_empty();
         let input = RetCtx {
             frames: &mut self.frames,
             registers: &mut self.registers,
@@ -117,7 +124,17 @@ where
             context: &mut self.context,
             current_contract,
         };
-        input.ret_data(a, b)
+        let digest = input.ret_data(a, b)?;
+        if in_call {
+            self.notify_return();
+        }
+        Ok(digest)
+    }
+
+    fn notify_return(&self) {
+        if let Some(call_observer) = &self.call_observer {
+            call_observer.on_return(self.registers[RegId::GGAS]);
+        }
     }
 
     pub(crate) fn revert(&mut self, a: Word) -> SimpleResult<()> {
@@ -374,6 +391,7 @@ where
         asset_id_pointer: Word,
         amount_of_gas_to_forward: Word,
     ) -> IoResult<(), S::DataError> {
+        let gas_before = self.registers[RegId::GGAS];
         let params = PrepareCallParams {
             call_params_pointer,
             asset_id_pointer,
@@ -401,10 +419,23 @@ where
             new_storage_gas_per_byte,
             receipts: &mut self.receipts,
             frames: &mut self.frames,
+            max_call_depth: self.interpreter_params.max_call_depth,
+            preloaded_contract: self.preloaded_contract.as_ref(),
             current_contract,
             verifier: &mut self.verifier,
         }
-        .prepare_call()
+        .prepare_call()?;
+
+        #[cfg(feature = "frame-revert")]
+        if self.interpreter_params.frame_revert_ecal {
+            self.storage.enter_frame(self.frames.len());
+        }
+
+        if let Some(call_observer) = &self.call_observer {
+            let frame = self.frames.last().expect("`CALL` pushed a frame");
+            call_observer.on_call(frame, gas_before);
+        }
+        Ok(())
     }
 }
 
@@ -467,6 +498,8 @@ struct PrepareCallCtx<'vm, S, V> {
     panic_context: &'vm mut PanicContext,
     receipts: &'vm mut ReceiptsCtx,
     frames: &'vm mut Vec<CallFrame>,
+    max_call_depth: Option<usize>,
+    preloaded_contract: Option<&'vm (ContractId, Contract)>,
     current_contract: Option<ContractId>,
     verifier: &'vm mut V,
 }
@@ -477,6 +510,12 @@ impl<S, V> PrepareCallCtx<'_, S, V> {
         S: InterpreterStorage,
         V: Verifier,
     {
+        if let Some(max_call_depth) = self.max_call_depth {
+            if self.frames.len() >= max_call_depth {
+                return Err(PanicReason::MaxCallDepthReached.into())
+            }
+        }
+
         let call_bytes = self
             .memory
             .read(self.params.call_params_pointer, Call::LEN)?;
@@ -484,7 +523,14 @@ impl<S, V> PrepareCallCtx<'_, S, V> {
         let asset_id =
             AssetId::new(self.memory.read_bytes(self.params.asset_id_pointer)?);
 
-        let code_size = contract_size(&self.storage, call.to())? as usize;
+        let preloaded_code = self
+            .preloaded_contract
+            .filter(|(contract_id, _)| contract_id == call.to())
+            .map(|(_, code)| code.as_ref());
+        let code_size = match preloaded_code {
+            Some(code) => code.len(),
+            None => contract_size(&self.storage, call.to())?,
+        };
         let code_size_padded =
             padded_len_usize(code_size).ok_or(PanicReason::MemoryOverflow)?;
 
@@ -587,7 +633,10 @@ impl<S, V> PrepareCallCtx<'_, S, V> {
         let (mem_frame, mem_code) = dst.split_at_mut(CallFrame::serialized_size());
         mem_frame.copy_from_slice(&frame.to_bytes());
         let (mem_code, mem_code_padding) = mem_code.split_at_mut(code_size);
-        read_contract(call.to(), self.storage, mem_code)?;
+        match preloaded_code {
+            Some(code) => mem_code.copy_from_slice(code),
+            None => read_contract(call.to(), self.storage, mem_code)?,
+        }
         mem_code_padding.fill(0);
 
         #[allow(clippy::arithmetic_side_effects)] // Checked above
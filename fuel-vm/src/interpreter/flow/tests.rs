@@ -395,6 +395,8 @@ fn test_prepare_call(input: Input) -> Result<Output, RuntimeError<MemoryStorageE
         new_storage_gas_per_byte: 0,
         receipts: &mut receipts,
         frames: &mut frames,
+        max_call_depth: None,
+        preloaded_contract: None,
         current_contract,
         verifier: &mut Normal,
     };
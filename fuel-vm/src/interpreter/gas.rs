@@ -9,21 +9,73 @@ use crate::{
 };
 
 use fuel_asm::{
+    Opcode,
     PanicReason,
     RegId,
 };
 use fuel_tx::DependentCost;
-use fuel_types::Word;
+use fuel_types::{
+    Bytes32,
+    ContractId,
+    Word,
+};
 
 #[cfg(test)]
 mod tests;
 
+/// Decides the gas refunded when a contract clears one of its storage slots, i.e. when
+/// `SWW` writes zero over a nonzero value or `SCWQ` removes a nonzero value.
+///
+/// The refunds are accumulated during the execution of a script and deducted from the
+/// gas used reported by the `ScriptResult` receipt and charged in the fees, up to
+/// [`GasRefundPolicy::max_refund`]. Nothing is refunded if the script reverts.
+pub trait GasRefundPolicy: core::fmt::Debug + Send + Sync {
+    /// The gas refunded for clearing the `key` slot of the `contract` state.
+    fn refund_on_clear(&self, contract: &ContractId, key: &Bytes32) -> Word;
+
+    /// The maximum gas refunded to a script that used `gas_used` gas.
+    fn max_refund(&self, gas_used: Word) -> Word;
+}
+
+/// Observes the gas charged by the instructions of a chosen set of opcodes, e.g. to
+/// profile the expensive ones without tracing every instruction.
+///
+/// See [`Interpreter::set_opcode_gas_observer`].
+pub trait OpcodeGasObserver: core::fmt::Debug + Send + Sync {
+    /// Called after an instruction with the `opcode` was executed, even if it failed,
+    /// with the gas it charged.
+    fn on_gas_charged(&self, opcode: Opcode, gas_charged: Word);
+}
+
 impl<M, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V> {
     /// Global remaining gas amount
     pub fn remaining_gas(&self) -> Word {
         self.registers[RegId::GGAS]
     }
 
+    /// The gas charged by the current execution so far, i.e. the gas limit it was
+    /// initialized with minus the [`Self::remaining_gas`].
+    ///
+    /// Available to the [`EcalHandler`](super::EcalHandler)s through the interpreter
+    /// they receive, e.g. to charge host-side resources proportionally.
+    pub fn gas_charged_so_far(&self) -> Word {
+        self.initial_gas.saturating_sub(self.remaining_gas())
+    }
+
+    /// The gas refunds accumulated by the current execution, before applying the cap
+    /// of the [`GasRefundPolicy`].
+    pub fn gas_refund(&self) -> Word {
+        self.gas_refund
+    }
+
+    /// The gas refunded to a script that used `gas_used` gas.
+    pub(crate) fn capped_gas_refund(&self, gas_used: Word) -> Word {
+        match &self.gas_refund_policy {
+            Some(policy) => self.gas_refund.min(policy.max_refund(gas_used)),
+            None => 0,
+        }
+    }
+
     /// Sets the amount of gas available for execution to both CGAS and GGAS.
     /// Only useful in contexts where CGAS and GGAS are the same,
     /// i.e. predicates and testing.
@@ -36,7 +36,7 @@ where
     S: InterpreterStorage,
 {
     /// Initialize the VM with a given transaction
-    fn init_inner(
+    pub(crate) fn init_inner(
         &mut self,
         mut tx: Tx,
         initial_balances: InitialBalances,
@@ -77,7 +77,21 @@ where
 
         self.frames.clear();
         self.receipts.clear();
+        self.receipts
+            .set_max_receipts(self.interpreter_params.max_receipts);
+        self.gas_refund = 0;
+        if let Some(gas_by_contract) = &mut self.gas_by_contract {
+            gas_by_contract.clear();
+        }
+        if let Some(access_set) = &mut self.access_set {
+            access_set.clear();
+        }
+        if let Some(ecal_records) = &mut self.ecal_records {
+            ecal_records.clear();
+        }
         self.memory_mut().reset();
+        #[cfg(feature = "opcode-histogram")]
+        self.opcode_histogram.clear();
 
         // Optimized for memset
         self.registers.iter_mut().for_each(|r| *r = 0);
@@ -113,6 +127,7 @@ where
 
         let tx_size = self.transaction().size() as Word;
         self.set_gas(gas_limit);
+        self.initial_gas = gas_limit;
 
         push_stack!(&tx_size.to_be_bytes());
 
@@ -173,7 +188,10 @@ where
         &mut self,
         ready_tx: Ready<Tx>,
     ) -> Result<(), InterpreterError<S::DataError>> {
-        let block_height = self.storage.block_height().map_err(RuntimeError::Storage)?;
+        let block_height = match self.interpreter_params.block_context {
+            Some(block_context) => block_context.height,
+            None => self.storage.block_height().map_err(RuntimeError::Storage)?,
+        };
 
         self.context = Context::Script { block_height };
 
@@ -123,10 +123,6 @@ where
         set_flag(flag, pc, a)
     }
 
-    pub(crate) const fn context(&self) -> &Context {
-        &self.context
-    }
-
     pub(crate) fn internal_contract(&self) -> Result<ContractId, PanicReason> {
         internal_contract(&self.context, self.registers.fp(), self.memory.as_ref())
     }
@@ -1,6 +1,7 @@
 #![cfg(feature = "alloc")]
 
 use super::{
+    ExecutableTransaction,
     Interpreter,
     internal::inc_pc,
 };
@@ -16,6 +17,10 @@ use fuel_asm::{
     PanicReason,
     RegId,
 };
+use fuel_tx::{
+    DependentCost,
+    field::ScriptGasLimit,
+};
 use fuel_types::{
     Word,
     fmt_truncated_hex,
@@ -36,6 +41,7 @@ use core::ops::{
 
 use crate::error::{
     IoResult,
+    PanicOrBug,
     RuntimeError,
 };
 use alloc::vec::Vec;
@@ -44,9 +50,16 @@ use fuel_storage::{
     StorageRead,
 };
 
+#[cfg(feature = "memory-profiling")]
+mod profile;
+
+#[cfg(feature = "memory-profiling")]
+pub use profile::MemoryProfile;
+
 #[cfg(test)]
 mod tests;
 
+#[allow(non_snake_case)]
 #[cfg(test)]
 mod impl_tests;
 
@@ -57,13 +70,17 @@ mod allocation_tests;
 #[cfg(test)]
 mod stack_tests;
 
+#[cfg(all(test, feature = "memory-profiling"))]
+mod profile_tests;
+
 /// The trait for the memory.
 pub trait Memory: AsRef<MemoryInstance> + AsMut<MemoryInstance> {}
 
 impl<M> Memory for M where M: AsRef<MemoryInstance> + AsMut<MemoryInstance> {}
 
 /// The memory of the VM, represented as stack and heap.
-#[derive(Clone, Eq)]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryInstance {
     /// Stack. Grows upwards.
     stack: Vec<u8>,
@@ -72,6 +89,13 @@ pub struct MemoryInstance {
     /// Lowest allowed heap address, i.e. hp register value.
     /// This is needed since we can allocate extra heap for performance reasons.
     hp: usize,
+    /// Memory access counters. Not a part of the memory state.
+    #[cfg(feature = "memory-profiling")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    profiler: profile::MemoryProfiler,
+    /// The latest failed memory growth. Not a part of the memory state.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_growth_error: Option<MemoryGrowthError>,
 }
 
 impl Default for MemoryInstance {
@@ -103,6 +127,8 @@ impl PartialEq for MemoryInstance {
     }
 }
 
+impl Eq for MemoryInstance {}
+
 impl AsRef<MemoryInstance> for MemoryInstance {
     fn as_ref(&self) -> &MemoryInstance {
         self
@@ -121,13 +147,50 @@ impl MemoryInstance {
             stack: Vec::new(),
             heap: Vec::new(),
             hp: MEM_SIZE,
+            #[cfg(feature = "memory-profiling")]
+            profiler: Default::default(),
+            last_growth_error: None,
         }
     }
 
     /// Resets memory to initial state, keeping the original allocations.
+    /// Also resets the memory profile and the latest failed memory growth.
     pub fn reset(&mut self) {
         self.stack.truncate(0);
         self.hp = MEM_SIZE;
+        #[cfg(feature = "memory-profiling")]
+        self.profiler.reset();
+        self.last_growth_error = None;
+    }
+
+    /// The latest failed memory growth since the last reset. When the VM panics with
+    /// [`PanicReason::MemoryGrowthOverlap`], it tells the `$sp` and `$hp` values and
+    /// the requested size of the growth.
+    pub fn last_growth_error(&self) -> Option<MemoryGrowthError> {
+        self.last_growth_error
+    }
+
+    /// Records the failed memory growth, see [`Self::last_growth_error`].
+    fn growth_failed(&mut self, error: MemoryGrowthError) -> MemoryGrowthError {
+        self.last_growth_error = Some(error);
+        error
+    }
+
+    /// Memory access statistics collected since the last reset.
+    #[cfg(feature = "memory-profiling")]
+    pub fn profile(&self) -> MemoryProfile {
+        self.profiler.profile()
+    }
+
+    /// Records the current stack and heap size as an allocation for profiling.
+    #[inline(always)]
+    fn profile_allocated(&self) {
+        #[cfg(feature = "memory-profiling")]
+        self.profiler.record_allocated(
+            self.stack
+                .len()
+                .saturating_add(MEM_SIZE.saturating_sub(self.hp)),
+        );
     }
 
     /// Offset of the heap section
@@ -136,43 +199,71 @@ impl MemoryInstance {
     }
 
     /// Grows the stack to be at least `new_sp` bytes.
-    pub fn grow_stack(&mut self, new_sp: Word) -> Result<(), PanicReason> {
+    pub fn grow_stack(&mut self, new_sp: Word) -> Result<(), MemoryGrowthError> {
+        let (sp, hp) = (self.stack.len() as Word, self.hp as Word);
+        let error = |reason| MemoryGrowthError {
+            reason,
+            sp,
+            hp,
+            requested: new_sp,
+        };
         if new_sp > VM_MAX_RAM {
-            return Err(PanicReason::MemoryOverflow);
+            return Err(self.growth_failed(error(PanicReason::MemoryOverflow)));
         }
         #[allow(clippy::cast_possible_truncation)] // Safety: VM_MAX_RAM is usize
         let new_sp = new_sp as usize;
 
         if new_sp > self.stack.len() {
             if new_sp > self.hp {
-                return Err(PanicReason::MemoryGrowthOverlap)
+                return Err(self.growth_failed(error(PanicReason::MemoryGrowthOverlap)))
             }
 
             self.stack.resize(new_sp, 0);
+            self.profile_allocated();
         }
         Ok(())
     }
 
     /// Grows the heap by `amount` bytes. Updates hp register.
     pub fn grow_heap_by(
+        &mut self,
+        sp_reg: Reg<SP>,
+        hp_reg: RegMut<HP>,
+        amount: Word,
+    ) -> Result<(), MemoryGrowthError> {
+        self.grow_heap(sp_reg, hp_reg, amount, true)
+    }
+
+    /// Grows the heap by `amount` bytes, zeroing the new space only if `zeroed` is
+    /// set. Otherwise the new space may hold data written before a memory reset.
+    /// Updates hp register.
+    pub(crate) fn grow_heap(
         &mut self,
         sp_reg: Reg<SP>,
         mut hp_reg: RegMut<HP>,
         amount: Word,
-    ) -> Result<(), PanicReason> {
+        zeroed: bool,
+    ) -> Result<(), MemoryGrowthError> {
         debug_assert_eq!(
             self.hp as Word, *hp_reg,
             "HP register changed without memory update"
         );
 
-        let amount = usize::try_from(amount).map_err(|_| PanicReason::MemoryOverflow)?;
-        let new_hp = self
-            .hp
-            .checked_sub(amount)
-            .ok_or(PanicReason::MemoryOverflow)?;
+        let error = |reason| MemoryGrowthError {
+            reason,
+            sp: *sp_reg,
+            hp: *hp_reg,
+            requested: amount,
+        };
+        let Some(new_hp) = usize::try_from(amount)
+            .ok()
+            .and_then(|amount| self.hp.checked_sub(amount))
+        else {
+            return Err(self.growth_failed(error(PanicReason::MemoryOverflow)));
+        };
 
         if (new_hp as Word) < *sp_reg {
-            return Err(PanicReason::MemoryGrowthOverlap)
+            return Err(self.growth_failed(error(PanicReason::MemoryGrowthOverlap)))
         }
 
         #[allow(clippy::arithmetic_side_effects)] // Safety: self.hp is in heap
@@ -182,9 +273,11 @@ impl MemoryInstance {
         if self.heap.len() >= new_len {
             // No need to reallocate, but we need to zero the new space
             // in case it was used before a memory reset.
-            let start = new_hp - self.heap_offset();
-            let end = self.hp - self.heap_offset();
-            self.heap[start..end].fill(0);
+            if zeroed {
+                let start = new_hp - self.heap_offset();
+                let end = self.hp - self.heap_offset();
+                self.heap[start..end].fill(0);
+            }
         } else {
             // Need to clear dirty memory before expanding it. An example:
             // Heap vector: [dirty, dirty, dirty, 0, 0, 0]
@@ -202,9 +295,11 @@ impl MemoryInstance {
             // So, either we need to clear the memory before copying,
             // or after we copied dirty parts.
             // Clearing before looks like more readable solution.
-            let end = self.hp.checked_sub(self.heap_offset());
-            if let Some(end) = end {
-                self.heap[..end].fill(0);
+            if zeroed {
+                let end = self.hp.checked_sub(self.heap_offset());
+                if let Some(end) = end {
+                    self.heap[..end].fill(0);
+                }
             }
 
             // Reallocation is needed.
@@ -215,7 +310,9 @@ impl MemoryInstance {
             let prefix_zeroes = cap - old_len;
             self.heap.resize(cap, 0);
             self.heap.copy_within(..old_len, prefix_zeroes);
-            self.heap[..prefix_zeroes].fill(0);
+            if zeroed {
+                self.heap[..prefix_zeroes].fill(0);
+            }
         }
 
         self.hp = new_hp;
@@ -223,11 +320,18 @@ impl MemoryInstance {
 
         // If heap enters region where stack has been, truncate the stack
         self.stack.truncate(new_hp);
+        self.profile_allocated();
 
         Ok(())
     }
 
-    /// Verify that the memory range is accessble and return it as a range.
+    /// Returns the range of `count` bytes at `addr`, validating that it lies entirely
+    /// within the currently allocated stack or heap.
+    ///
+    /// This is the validated constructor of [`MemoryRange`]: unlike
+    /// [`MemoryRange::new`], it fails early, with `MemoryOverflow` if the range exceeds
+    /// the memory, and with `UninitalizedMemoryAccess` if it reaches the unallocated
+    /// memory between the stack and the heap.
     pub fn verify<A: ToAddr, B: ToAddr>(
         &self,
         addr: A,
@@ -247,6 +351,14 @@ impl MemoryInstance {
         }
     }
 
+    /// Checks that the `len` bytes at `addr` can be accessed, without reading them.
+    ///
+    /// Fails with the same reasons as [`MemoryInstance::verify`], so the
+    /// instructions can validate all their operands before doing any work.
+    pub fn require(&self, addr: Word, len: Word) -> Result<(), PanicReason> {
+        self.verify(addr, len).map(|_| ())
+    }
+
     /// Verify a constant-sized memory range.
     pub fn verify_const<A: ToAddr, const C: usize>(
         &self,
@@ -263,6 +375,8 @@ impl MemoryInstance {
         count: C,
     ) -> Result<&[u8], PanicReason> {
         let range = self.verify(addr, count)?;
+        #[cfg(feature = "memory-profiling")]
+        self.profiler.record_read(range.len());
 
         if range.end() <= self.stack.len() {
             Ok(&self.stack[range.usizes()])
@@ -285,6 +399,44 @@ impl MemoryInstance {
         Ok(result)
     }
 
+    /// Reads `count` consecutive 32-byte chunks of memory starting at `addr`, if
+    /// possible.
+    pub fn chunks32<A: ToAddr>(
+        &self,
+        addr: A,
+        count: usize,
+    ) -> Result<impl Iterator<Item = &[u8; 32]>, PanicReason> {
+        let len = count.checked_mul(32).ok_or(PanicReason::MemoryOverflow)?;
+        let bytes = self.read(addr, len)?;
+        Ok(bytes
+            .chunks_exact(32)
+            .map(|chunk| chunk.try_into().expect("Chunks are 32 bytes long")))
+    }
+
+    /// Reads the memory of `range` as a UTF-8 string, e.g. to decode a `LogData`
+    /// payload known to be a string.
+    pub fn read_utf8(&self, range: MemoryRange) -> Result<&str, ReadUtf8Error> {
+        let bytes = self
+            .read(range.start(), range.len())
+            .map_err(ReadUtf8Error::Memory)?;
+        core::str::from_utf8(bytes).map_err(ReadUtf8Error::Utf8)
+    }
+
+    /// Gets write access to `count` consecutive 32-byte chunks of memory starting at
+    /// `addr`, checking for ownership.
+    pub fn chunks32_mut<A: ToAddr>(
+        &mut self,
+        owner: OwnershipRegisters,
+        addr: A,
+        count: usize,
+    ) -> Result<impl Iterator<Item = &mut [u8; 32]>, PanicReason> {
+        let len = count.checked_mul(32).ok_or(PanicReason::MemoryOverflow)?;
+        let bytes = self.write(owner, addr, len)?;
+        Ok(bytes
+            .chunks_exact_mut(32)
+            .map(|chunk| chunk.try_into().expect("Chunks are 32 bytes long")))
+    }
+
     /// Gets write access to memory, if possible.
     /// Doesn't perform any ownership checks.
     #[allow(clippy::arithmetic_side_effects)] // Safety: subtractions are checked
@@ -294,6 +446,8 @@ impl MemoryInstance {
         len: B,
     ) -> Result<&mut [u8], PanicReason> {
         let range = self.verify(addr, len)?;
+        #[cfg(feature = "memory-profiling")]
+        self.profiler.record_write(range.len());
         if range.end() <= self.stack.len() {
             Ok(&mut self.stack[range.usizes()])
         } else if range.start() >= self.heap_offset() {
@@ -363,6 +517,12 @@ impl MemoryInstance {
 
         owner.verify_ownership(&dst_range)?;
 
+        #[cfg(feature = "memory-profiling")]
+        {
+            self.profiler.record_read(src_range.len());
+            self.profiler.record_write(dst_range.len());
+        }
+
         if src_range.end() <= self.stack.len() {
             if dst_range.end() <= self.stack.len() {
                 self.stack
@@ -424,6 +584,50 @@ impl MemoryInstance {
         &self.heap
     }
 
+    /// Returns the contiguous ranges of memory where `self` and `other` differ, along
+    /// with the bytes of both instances in that range, sorted by address.
+    ///
+    /// Only the regions allocated in either instance are compared, i.e. the stack up
+    /// to the larger stack and the heap from the lower `hp`. Bytes that are not
+    /// allocated in one of the instances are treated as zeroes.
+    #[cfg(any(test, feature = "test-helpers"))]
+    pub fn diff(&self, other: &MemoryInstance) -> Vec<(Range<usize>, Vec<u8>, Vec<u8>)> {
+        let stack_end = self.stack.len().max(other.stack.len());
+        let heap_start = self.hp.min(other.hp).max(stack_end);
+
+        let mut diff: Vec<(Range<usize>, Vec<u8>, Vec<u8>)> = Vec::new();
+        for addr in (0..stack_end).chain(heap_start..MEM_SIZE) {
+            let (ours, theirs) = (self.byte_at(addr), other.byte_at(addr));
+            if ours == theirs {
+                continue
+            }
+
+            let next = addr.saturating_add(1);
+            match diff.last_mut() {
+                Some((range, ours_bytes, theirs_bytes)) if range.end == addr => {
+                    range.end = next;
+                    ours_bytes.push(ours);
+                    theirs_bytes.push(theirs);
+                }
+                _ => diff.push((addr..next, alloc::vec![ours], alloc::vec![theirs])),
+            }
+        }
+        diff
+    }
+
+    /// Returns the byte at `addr`, or zero if it isn't allocated.
+    #[cfg(any(test, feature = "test-helpers"))]
+    fn byte_at(&self, addr: usize) -> u8 {
+        if let Some(byte) = self.stack.get(addr) {
+            return *byte
+        }
+        if addr < self.hp {
+            return 0
+        }
+        let index = addr.saturating_sub(self.heap_offset());
+        self.heap.get(index).copied().unwrap_or(0)
+    }
+
     /// Returns a `MemoryRollbackData` that can be used to achieve the state of the
     /// `desired_memory_state` instance.
     pub fn collect_rollback_data(
@@ -622,6 +826,53 @@ impl ToAddr for i32 {
     }
 }
 
+/// The error of the memory growth, see [`MemoryInstance::grow_stack`] and
+/// [`MemoryInstance::grow_heap_by`]. The VM panics with its `reason`, and the error
+/// stays available with [`MemoryInstance::last_growth_error`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+#[display(fmt = "{reason} when growing by {requested} with $sp={sp}, $hp={hp}")]
+pub struct MemoryGrowthError {
+    /// Either `MemoryOverflow` or `MemoryGrowthOverlap`, if the stack and the heap
+    /// would overlap.
+    pub reason: PanicReason,
+    /// The stack pointer before the growth.
+    pub sp: Word,
+    /// The heap pointer before the growth.
+    pub hp: Word,
+    /// The requested stack pointer when growing the stack, or the requested number of
+    /// bytes when growing the heap.
+    pub requested: Word,
+}
+
+impl From<MemoryGrowthError> for PanicReason {
+    fn from(error: MemoryGrowthError) -> Self {
+        error.reason
+    }
+}
+
+impl From<MemoryGrowthError> for PanicOrBug {
+    fn from(error: MemoryGrowthError) -> Self {
+        Self::Panic(error.reason)
+    }
+}
+
+impl<StorageError> From<MemoryGrowthError> for RuntimeError<StorageError> {
+    fn from(error: MemoryGrowthError) -> Self {
+        Self::Recoverable(error.reason)
+    }
+}
+
+/// The error of [`MemoryInstance::read_utf8`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+pub enum ReadUtf8Error {
+    /// The memory of the range can't be read.
+    #[display(fmt = "Can't read the memory: {_0}")]
+    Memory(PanicReason),
+    /// The bytes are not valid UTF-8.
+    #[display(fmt = "Invalid UTF-8: {_0}")]
+    Utf8(core::str::Utf8Error),
+}
+
 /// A range of memory. No guarantees are made about validity of access.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -671,6 +922,59 @@ impl MemoryRange {
     }
 }
 
+impl<M, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V>
+where
+    Tx: ExecutableTransaction,
+{
+    /// Returns an upper bound of the memory, in bytes, used to execute the `tx`,
+    /// without executing it, e.g. for admission control.
+    ///
+    /// The bound is the size of the static layout written to the stack during the
+    /// initialization, i.e. the transaction and its metadata, plus a heuristic for
+    /// the memory the script allocates: the sum of the bytes `ALOC`, `CFE`, `CFEI`,
+    /// `LDC` and `CALL` could each allocate with the whole script gas limit. The
+    /// static layout is never underestimated. The bound is capped by [`MEM_SIZE`].
+    pub fn estimate_memory_bound(&self, tx: &Tx) -> usize {
+        let static_layout = self.tx_offset().saturating_add(tx.size());
+
+        let gas_limit = tx
+            .as_script()
+            .map(|script| *script.script_gas_limit())
+            .unwrap_or_default();
+        let gas_costs = self.gas_costs();
+        let allocated = [
+            gas_costs.aloc(),
+            gas_costs.cfe(),
+            gas_costs.cfei(),
+            gas_costs.ldc(),
+            gas_costs.call(),
+        ]
+        .iter()
+        .map(|cost| max_units_for_gas(cost, gas_limit))
+        .fold(0, Word::saturating_add);
+        let allocated = usize::try_from(allocated).unwrap_or(usize::MAX);
+
+        static_layout.saturating_add(allocated).min(MEM_SIZE)
+    }
+}
+
+/// The maximum number of units an operation with the dependent `cost` can process
+/// with `gas`.
+fn max_units_for_gas(cost: &DependentCost, gas: Word) -> Word {
+    let Some(gas) = gas.checked_sub(cost.base()) else {
+        return 0
+    };
+    match cost {
+        // The units are rounded down when charged, so one more unit of gas is needed
+        DependentCost::LightOperation { units_per_gas, .. } => {
+            gas.saturating_add(1).saturating_mul(*units_per_gas)
+        }
+        DependentCost::HeavyOperation { gas_per_unit, .. } => {
+            gas.checked_div(*gas_per_unit).unwrap_or(Word::MAX)
+        }
+    }
+}
+
 impl<M, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V>
 where
     M: Memory,
@@ -755,9 +1059,13 @@ where
     }
 
     pub(crate) fn malloc(&mut self, a: Word) -> SimpleResult<()> {
+        #[cfg(feature = "skip-alloc-zeroing")]
+        let zeroed = !self.interpreter_params.skip_alloc_zeroing;
+        #[cfg(not(feature = "skip-alloc-zeroing"))]
+        let zeroed = true;
         let (SystemRegisters { hp, sp, pc, .. }, _) =
             split_registers(&mut self.registers);
-        malloc(hp, sp.as_ref(), pc, a, self.memory.as_mut())
+        malloc(hp, sp.as_ref(), pc, a, self.memory.as_mut(), zeroed)
     }
 
     pub(crate) fn memclear(&mut self, a: Word, b: Word) -> SimpleResult<()> {
@@ -801,7 +1109,14 @@ pub(crate) fn try_update_stack_pointer(
     if new_sp < *ssp {
         Err(PanicReason::MemoryOverflow.into())
     } else if new_sp > *hp {
-        Err(PanicReason::MemoryGrowthOverlap.into())
+        Err(memory
+            .growth_failed(MemoryGrowthError {
+                reason: PanicReason::MemoryGrowthOverlap,
+                sp: *sp,
+                hp: *hp,
+                requested: new_sp,
+            })
+            .into())
     } else {
         *sp = new_sp;
         memory.grow_stack(new_sp)?;
@@ -967,8 +1282,9 @@ pub(crate) fn malloc(
     pc: RegMut<PC>,
     amount: Word,
     memory: &mut MemoryInstance,
+    zeroed: bool,
 ) -> SimpleResult<()> {
-    memory.grow_heap_by(sp, hp, amount)?;
+    memory.grow_heap(sp, hp, amount, zeroed)?;
     Ok(inc_pc(pc)?)
 }
 
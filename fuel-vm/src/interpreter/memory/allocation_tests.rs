@@ -26,6 +26,7 @@ fn test_malloc(mut hp: Word, sp: Word, a: Word) -> SimpleResult<Word> {
         RegMut::new(&mut pc),
         a,
         &mut memory,
+        true,
     )?;
     assert_eq!(pc, 8);
 
@@ -202,3 +203,26 @@ macro_rules! generate_tests__memory_instance__grow_heap_by_after_reset__does_not
 generate_tests__memory_instance__grow_heap_by_after_reset__does_not_retain_dirty_memory_size!(
     0, 1, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384, 32768, 65536, 131072
 );
+
+#[test_case(true => vec![0u8; 64]; "Zeroed")]
+#[test_case(false => vec![1u8; 64]; "Not zeroed")]
+fn memory_instance__grow_heap_after_reset__zeroes_only_if_requested(
+    zeroed: bool,
+) -> Vec<u8> {
+    // given: a memory instance with a dirty heap
+    let mut memory = MemoryInstance::new();
+    let sp = Reg::new(&10);
+    let mut hp = VM_MAX_RAM;
+    memory.grow_heap_by(sp, RegMut::new(&mut hp), 64).unwrap();
+    memory.write_bytes_noownerchecks(hp, [1u8; 64]).unwrap();
+
+    // when: we reset and grow the heap again over the dirty memory
+    memory.reset();
+    let mut hp = VM_MAX_RAM;
+    memory
+        .grow_heap(sp, RegMut::new(&mut hp), 64, zeroed)
+        .unwrap();
+
+    // then: the new space holds the old data unless it was zeroed
+    memory.read(hp, 64).unwrap().to_vec()
+}
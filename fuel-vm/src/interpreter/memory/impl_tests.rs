@@ -6,12 +6,20 @@ use crate::{
 };
 
 use super::{
+    MemoryGrowthError,
     MemoryInstance,
+    MemoryRange,
+    OwnershipRegisters,
+    ReadUtf8Error,
     Reg,
     VM_MAX_RAM,
 };
+use fuel_asm::PanicReason;
 
-use alloc::vec;
+use alloc::{
+    vec,
+    vec::Vec,
+};
 
 #[test]
 fn grow_heap_over_old_stack() {
@@ -56,6 +64,48 @@ fn grow_heap_over_old_stack() {
     assert_eq!(memory.read(VM_MAX_RAM - 100, 100).unwrap(), vec![2u8; 100]);
 }
 
+#[test]
+fn growth_errors_report_the_pointers_and_the_requested_size() {
+    let mut memory = MemoryInstance::new();
+    let sp = 100;
+    let mut hp = VM_MAX_RAM;
+    memory.grow_stack(sp).expect("Can grow stack");
+    memory
+        .grow_heap_by(Reg::<SP>::new(&sp), RegMut::<HP>::new(&mut hp), 50)
+        .expect("Can grow heap");
+    let too_large = hp - 99;
+
+    let heap_error = memory
+        .grow_heap_by(Reg::<SP>::new(&sp), RegMut::<HP>::new(&mut hp), too_large)
+        .expect_err("Cannot grow heap over stack");
+    let stack_error = memory
+        .grow_stack(hp + 1)
+        .expect_err("Cannot grow stack over heap");
+
+    assert_eq!(
+        heap_error,
+        MemoryGrowthError {
+            reason: PanicReason::MemoryGrowthOverlap,
+            sp,
+            hp,
+            requested: too_large,
+        }
+    );
+    assert_eq!(
+        stack_error,
+        MemoryGrowthError {
+            reason: PanicReason::MemoryGrowthOverlap,
+            sp,
+            hp,
+            requested: hp + 1,
+        }
+    );
+    assert_eq!(memory.last_growth_error(), Some(stack_error));
+
+    memory.reset();
+    assert_eq!(memory.last_growth_error(), None);
+}
+
 #[test]
 fn reads_cannot_cross_from_stack_to_heap() {
     let mut memory = MemoryInstance::new();
@@ -117,3 +167,208 @@ fn memory_reset() {
         .expect_err("Heap should be empty");
     assert_eq!(memory.hp, MEM_SIZE);
 }
+
+#[test]
+fn chunks32_reads_consecutive_chunks() {
+    let mut memory = MemoryInstance::new();
+    memory.grow_stack(96).expect("Can grow stack");
+    for (i, byte) in memory
+        .write_noownerchecks(0, 96)
+        .unwrap()
+        .iter_mut()
+        .enumerate()
+    {
+        *byte = (i / 32) as u8;
+    }
+
+    let chunks: Vec<_> = memory.chunks32(32, 2).expect("Range is readable").collect();
+
+    assert_eq!(chunks, vec![&[1u8; 32], &[2u8; 32]]);
+}
+
+#[test]
+fn chunks32_rejects_overflowing_counts() {
+    let mut memory = MemoryInstance::new();
+    memory.grow_stack(96).expect("Can grow stack");
+
+    let result = memory.chunks32(0, usize::MAX).map(|_| ());
+
+    assert_eq!(result, Err(PanicReason::MemoryOverflow));
+}
+
+#[test]
+fn chunks32_rejects_uninitialized_memory() {
+    let mut memory = MemoryInstance::new();
+    memory.grow_stack(32).expect("Can grow stack");
+
+    let result = memory.chunks32(0, 2).map(|_| ());
+
+    assert_eq!(result, Err(PanicReason::UninitalizedMemoryAccess));
+}
+
+#[test]
+fn read_utf8_decodes_valid_strings() {
+    let mut memory = MemoryInstance::new();
+    memory.grow_stack(16).expect("Can grow stack");
+    memory
+        .write_noownerchecks(4, 5)
+        .unwrap()
+        .copy_from_slice(b"hello");
+
+    let result = memory.read_utf8(MemoryRange::new(4, 5));
+
+    assert_eq!(result, Ok("hello"));
+}
+
+#[test]
+fn read_utf8_rejects_invalid_sequences() {
+    let mut memory = MemoryInstance::new();
+    memory.grow_stack(16).expect("Can grow stack");
+    memory
+        .write_noownerchecks(0, 2)
+        .unwrap()
+        .copy_from_slice(&[0xc3, 0x28]);
+
+    let result = memory.read_utf8(MemoryRange::new(0, 2));
+
+    let Err(ReadUtf8Error::Utf8(error)) = result else {
+        panic!("Expected a UTF-8 error, got {result:?}");
+    };
+    assert_eq!(error.valid_up_to(), 0);
+}
+
+#[test]
+fn read_utf8_rejects_uninitialized_memory() {
+    let mut memory = MemoryInstance::new();
+    memory.grow_stack(16).expect("Can grow stack");
+
+    let result = memory.read_utf8(MemoryRange::new(0, 32));
+
+    assert_eq!(
+        result,
+        Err(ReadUtf8Error::Memory(PanicReason::UninitalizedMemoryAccess))
+    );
+}
+
+#[test]
+fn chunks32_mut_checks_ownership() {
+    let mut memory = MemoryInstance::new();
+    memory.grow_stack(64).expect("Can grow stack");
+    let owner = OwnershipRegisters {
+        sp: 64,
+        ssp: 32,
+        hp: VM_MAX_RAM,
+        prev_hp: VM_MAX_RAM,
+    };
+
+    memory
+        .chunks32_mut(owner, 0, 2)
+        .map(|_| ())
+        .expect_err("The first chunk isn't owned");
+    for chunk in memory
+        .chunks32_mut(owner, 32, 1)
+        .expect("The second chunk is owned")
+    {
+        chunk.fill(7);
+    }
+
+    assert_eq!(memory.read(0, 64).unwrap(), [[0u8; 32], [7u8; 32]].concat());
+}
+
+#[test]
+fn diff__returns_contiguous_differing_ranges() {
+    // Given
+    let mut ours = MemoryInstance::new();
+    ours.grow_stack(64).expect("Can grow stack");
+    let mut hp = VM_MAX_RAM;
+    ours.grow_heap_by(Reg::<SP>::new(&64), RegMut::<HP>::new(&mut hp), 16)
+        .expect("Can grow heap");
+    let mut theirs = ours.clone();
+    theirs.write_noownerchecks(8, 4).unwrap().fill(1);
+    theirs.write_noownerchecks(13, 1).unwrap().fill(2);
+    theirs
+        .write_noownerchecks(VM_MAX_RAM - 2, 2)
+        .unwrap()
+        .fill(3);
+
+    // When
+    let diff = ours.diff(&theirs);
+
+    // Then
+    assert_eq!(
+        diff,
+        vec![
+            (8..12, vec![0; 4], vec![1; 4]),
+            (13..14, vec![0], vec![2]),
+            (MEM_SIZE - 2..MEM_SIZE, vec![0; 2], vec![3; 2]),
+        ]
+    );
+    assert!(ours.diff(&ours).is_empty());
+}
+
+#[test]
+fn diff__treats_unallocated_memory_as_zeroes() {
+    // Given
+    let mut ours = MemoryInstance::new();
+    ours.grow_stack(8).expect("Can grow stack");
+    let mut theirs = ours.clone();
+    theirs.grow_stack(16).expect("Can grow stack");
+    theirs.write_noownerchecks(4, 8).unwrap().fill(5);
+
+    // When
+    let diff = ours.diff(&theirs);
+
+    // Then
+    assert_eq!(diff, vec![(4..12, vec![0; 8], vec![5; 8])]);
+}
+
+#[test]
+fn verify__accepts_allocated_stack_and_heap() {
+    // Given
+    let mut memory = MemoryInstance::new();
+    let sp = 64;
+    let mut hp = VM_MAX_RAM;
+    memory.grow_stack(sp).expect("Can grow stack");
+    memory
+        .grow_heap_by(Reg::<SP>::new(&sp), RegMut::<HP>::new(&mut hp), 32)
+        .expect("Can grow heap");
+
+    // When
+    let stack = memory.verify(8, 56);
+    let heap = memory.verify(VM_MAX_RAM - 32, 32);
+
+    // Then
+    assert_eq!(stack, Ok(MemoryRange::new(8, 56)));
+    assert_eq!(heap, Ok(MemoryRange::new(MEM_SIZE - 32, 32)));
+}
+
+#[test]
+fn verify__rejects_unallocated_and_out_of_bounds_memory() {
+    // Given
+    let mut memory = MemoryInstance::new();
+    memory.grow_stack(64).expect("Can grow stack");
+
+    // When
+    let gap = memory.verify(60, 8);
+    let overflow = memory.verify(VM_MAX_RAM - 4, 8);
+
+    // Then
+    assert_eq!(gap, Err(PanicReason::UninitalizedMemoryAccess));
+    assert_eq!(overflow, Err(PanicReason::MemoryOverflow));
+}
+
+#[test]
+fn require__matches_verify() {
+    // Given
+    let mut memory = MemoryInstance::new();
+    memory.grow_stack(64).expect("Can grow stack");
+    let cases = [(0, 64), (60, 8), (VM_MAX_RAM - 4, 8), (u64::MAX, 1)];
+
+    for (addr, len) in cases {
+        // When
+        let result = memory.require(addr, len);
+
+        // Then
+        assert_eq!(result, memory.verify(addr, len).map(|_| ()));
+    }
+}
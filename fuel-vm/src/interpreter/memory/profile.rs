@@ -0,0 +1,67 @@
+//! Memory access profiling, available with the `memory-profiling` feature.
+
+use core::sync::atomic::{
+    AtomicU64,
+    Ordering,
+};
+
+/// Memory access statistics collected by the [`MemoryInstance`](super::MemoryInstance)
+/// since the last reset.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MemoryProfile {
+    /// Total number of bytes read from the memory.
+    pub bytes_read: u64,
+    /// Total number of bytes written to the memory.
+    pub bytes_written: u64,
+    /// The largest amount of allocated memory, i.e. the stack plus the heap, in bytes.
+    pub peak_allocated: u64,
+}
+
+/// Counters updated by the memory access paths.
+///
+/// Atomics are used because reads only borrow the memory immutably,
+/// while the memory must stay `Sync`.
+#[derive(Debug, Default)]
+pub(super) struct MemoryProfiler {
+    bytes_read: AtomicU64,
+    bytes_written: AtomicU64,
+    peak_allocated: AtomicU64,
+}
+
+impl MemoryProfiler {
+    pub(super) fn record_read(&self, len: usize) {
+        self.bytes_read.fetch_add(len as u64, Ordering::Relaxed);
+    }
+
+    pub(super) fn record_write(&self, len: usize) {
+        self.bytes_written.fetch_add(len as u64, Ordering::Relaxed);
+    }
+
+    pub(super) fn record_allocated(&self, allocated: usize) {
+        self.peak_allocated
+            .fetch_max(allocated as u64, Ordering::Relaxed);
+    }
+
+    pub(super) fn profile(&self) -> MemoryProfile {
+        MemoryProfile {
+            bytes_read: self.bytes_read.load(Ordering::Relaxed),
+            bytes_written: self.bytes_written.load(Ordering::Relaxed),
+            peak_allocated: self.peak_allocated.load(Ordering::Relaxed),
+        }
+    }
+
+    pub(super) fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl Clone for MemoryProfiler {
+    fn clone(&self) -> Self {
+        let profile = self.profile();
+        Self {
+            bytes_read: AtomicU64::new(profile.bytes_read),
+            bytes_written: AtomicU64::new(profile.bytes_written),
+            peak_allocated: AtomicU64::new(profile.peak_allocated),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::constraints::reg_key::*;
+
+use super::{
+    MemoryInstance,
+    MemoryProfile,
+    OwnershipRegisters,
+    Reg,
+};
+
+#[test]
+fn profile_counts_reads_writes_and_peak_allocation() {
+    let mut memory = MemoryInstance::new();
+    assert_eq!(memory.profile(), MemoryProfile::default());
+
+    let sp = 64;
+    let mut hp = crate::consts::VM_MAX_RAM;
+    memory.grow_stack(sp).expect("Can grow stack");
+    memory.write_noownerchecks(0, 32).unwrap().fill(1);
+    memory.read(8, 16).unwrap();
+
+    memory
+        .grow_heap_by(Reg::<SP>::new(&sp), RegMut::<HP>::new(&mut hp), 100)
+        .expect("Can grow heap");
+    memory
+        .memcopy(32, 0, 8, OwnershipRegisters::test_full_stack())
+        .unwrap();
+
+    assert_eq!(
+        memory.profile(),
+        MemoryProfile {
+            bytes_read: 16 + 8,
+            bytes_written: 32 + 8,
+            peak_allocated: 64 + 100,
+        }
+    );
+}
+
+#[test]
+fn profile_keeps_peak_allocation_after_heap_is_reused() {
+    let mut memory = MemoryInstance::new();
+    let sp = 0;
+    let mut hp = crate::consts::VM_MAX_RAM;
+
+    memory
+        .grow_heap_by(Reg::<SP>::new(&sp), RegMut::<HP>::new(&mut hp), 1024)
+        .expect("Can grow heap");
+    memory.grow_stack(16).expect("Can grow stack");
+
+    assert_eq!(memory.profile().peak_allocated, 1024 + 16);
+}
+
+#[test]
+fn reset_clears_profile() {
+    let mut memory = MemoryInstance::new();
+    memory.grow_stack(64).expect("Can grow stack");
+    memory.write_noownerchecks(0, 64).unwrap();
+    memory.read(0, 64).unwrap();
+
+    memory.reset();
+
+    assert_eq!(memory.profile(), MemoryProfile::default());
+}
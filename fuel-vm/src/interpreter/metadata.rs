@@ -3,6 +3,7 @@ use super::{
     ExecutableTxType,
     Interpreter,
     Memory,
+    MemoryRange,
     internal::inc_pc,
 };
 use crate::{
@@ -81,13 +82,7 @@ where
         imm: Immediate12,
     ) -> SimpleResult<()> {
         let tx_offset = self.tx_offset();
-        // Tx size is stored just below the tx bytes
-        let tx_size_ptr = tx_offset.checked_sub(8).expect("Tx offset is not valid");
-        let tx_size = Word::from_be_bytes(
-            self.memory()
-                .read_bytes(tx_size_ptr)
-                .expect("Tx length not in memory"),
-        );
+        let tx_size = self.tx_size();
         let (SystemRegisters { pc, .. }, mut w) = split_registers(&mut self.registers);
         let result = &mut w[WriteRegKey::try_from(ra)?];
         let input = GTFInput {
@@ -96,9 +91,47 @@ where
                 .input_contracts_index_to_output_index,
             tx_offset,
             tx_size,
-            pc,
         };
-        input.get_transaction_field(result, b, imm)
+        input.get_transaction_field(pc, result, b, imm)
+    }
+
+    /// Returns the memory region of the transaction field that `GTF` with the
+    /// index `b` and the `args` returns a pointer to. The length of the region is
+    /// the value of the matching length field, e.g. `GTFArgs::ScriptDataLength` for
+    /// `GTFArgs::ScriptData`.
+    ///
+    /// Pointer fields without a length field, e.g. `GTFArgs::InputCoinOwner`, return
+    /// a zero-length range. Fields that are not pointers fail with
+    /// `PanicReason::InvalidMetadataIdentifier`, and regions outside of the allocated
+    /// memory with the error of `MemoryInstance::verify`.
+    pub fn gtf_field_range(
+        &self,
+        b: Word,
+        args: GTFArgs,
+    ) -> Result<MemoryRange, PanicReason> {
+        let b = convert::to_usize(b).ok_or(PanicReason::InvalidMetadataIdentifier)?;
+        let input = GTFInput {
+            tx: &self.tx,
+            input_contracts_index_to_output_index: &self
+                .input_contracts_index_to_output_index,
+            tx_offset: self.tx_offset(),
+            tx_size: self.tx_size(),
+        };
+        let range = input.field_range(b, args)?;
+        self.memory().verify(range.start(), range.len())
+    }
+
+    fn tx_size(&self) -> Word {
+        // Tx size is stored just below the tx bytes
+        let tx_size_ptr = self
+            .tx_offset()
+            .checked_sub(8)
+            .expect("Tx offset is not valid");
+        Word::from_be_bytes(
+            self.memory()
+                .read_bytes(tx_size_ptr)
+                .expect("Tx length not in memory"),
+        )
     }
 }
 
@@ -153,24 +186,88 @@ struct GTFInput<'vm, Tx> {
     input_contracts_index_to_output_index: &'vm alloc::collections::BTreeMap<u16, u16>,
     tx_offset: usize,
     tx_size: Word,
-    pc: RegMut<'vm, PC>,
 }
 
-impl<Tx> GTFInput<'_, Tx> {
-    #[allow(deprecated)]
+impl<Tx> GTFInput<'_, Tx>
+where
+    Tx: ExecutableTransaction,
+{
     pub(crate) fn get_transaction_field(
         self,
+        pc: RegMut<PC>,
         result: &mut Word,
         b: Word,
         imm: Immediate12,
-    ) -> SimpleResult<()>
-    where
-        Tx: ExecutableTransaction,
-    {
+    ) -> SimpleResult<()> {
         let b = convert::to_usize(b).ok_or(PanicReason::InvalidMetadataIdentifier)?;
         let args = GTFArgs::try_from(imm)?;
+
+        *result = self.field(b, args)?;
+
+        inc_pc(pc)?;
+        Ok(())
+    }
+
+    #[allow(deprecated)]
+    fn field_range(&self, b: usize, args: GTFArgs) -> Result<MemoryRange, PanicReason> {
+        let len_args = match args {
+            GTFArgs::Script => Some(GTFArgs::ScriptLength),
+            GTFArgs::ScriptData => Some(GTFArgs::ScriptDataLength),
+            GTFArgs::InputCoinPredicate => Some(GTFArgs::InputCoinPredicateLength),
+            GTFArgs::InputCoinPredicateData => {
+                Some(GTFArgs::InputCoinPredicateDataLength)
+            }
+            GTFArgs::InputMessageData => Some(GTFArgs::InputMessageDataLength),
+            GTFArgs::InputMessagePredicate => Some(GTFArgs::InputMessagePredicateLength),
+            GTFArgs::InputMessagePredicateData => {
+                Some(GTFArgs::InputMessagePredicateDataLength)
+            }
+            GTFArgs::WitnessData => Some(GTFArgs::WitnessDataLength),
+            GTFArgs::ScriptInputAtIndex
+            | GTFArgs::CreateInputAtIndex
+            | GTFArgs::TxInputAtIndex
+            | GTFArgs::ScriptOutputAtIndex
+            | GTFArgs::CreateOutputAtIndex
+            | GTFArgs::TxOutputAtIndex
+            | GTFArgs::ScriptWitnessAtIndex
+            | GTFArgs::CreateWitnessAtIndex
+            | GTFArgs::TxWitnessAtIndex
+            | GTFArgs::InputCoinTxId
+            | GTFArgs::InputCoinOwner
+            | GTFArgs::InputCoinAssetId
+            | GTFArgs::InputCoinTxPointer
+            | GTFArgs::InputContractTxId
+            | GTFArgs::InputContractId
+            | GTFArgs::InputMessageSender
+            | GTFArgs::InputMessageRecipient
+            | GTFArgs::InputMessageNonce
+            | GTFArgs::OutputCoinTo
+            | GTFArgs::OutputCoinAssetId
+            | GTFArgs::OutputContractCreatedContractId
+            | GTFArgs::OutputContractCreatedStateRoot
+            | GTFArgs::CreateSalt
+            | GTFArgs::CreateStorageSlotAtIndex
+            | GTFArgs::BlobId
+            | GTFArgs::UploadRoot
+            | GTFArgs::UploadProofSetAtIndex
+            | GTFArgs::UpgradePurpose => None,
+            _ => return Err(PanicReason::InvalidMetadataIdentifier),
+        };
+
+        let start = self.field(b, args)?;
+        let len = match len_args {
+            Some(len_args) => self.field(b, len_args)?,
+            None => 0,
+        };
+
+        let start = convert::to_usize(start).ok_or(PanicReason::MemoryOverflow)?;
+        let len = convert::to_usize(len).ok_or(PanicReason::MemoryOverflow)?;
+        Ok(MemoryRange::new(start, len))
+    }
+
+    #[allow(deprecated)]
+    fn field(&self, b: usize, args: GTFArgs) -> Result<Word, PanicReason> {
         let tx = self.tx;
-        let input_contract_to_output_index = self.input_contracts_index_to_output_index;
         let ofs = self.tx_offset;
 
         // We use saturating_add with tx offset below.
@@ -178,43 +275,7 @@ impl<Tx> GTFInput<'_, Tx> {
         // for the field that's above VM_MAX_RAM.
 
         let a = match args {
-            GTFArgs::Type => Tx::transaction_type() as Word,
-
             // General
-            GTFArgs::ScriptGasLimit => tx
-                .as_script()
-                .map(|script| *script.script_gas_limit())
-                .unwrap_or_default(),
-            GTFArgs::PolicyTypes => tx.policies().bits() as Word,
-            GTFArgs::PolicyTip => tx
-                .policies()
-                .get(PolicyType::Tip)
-                .ok_or(PanicReason::PolicyIsNotSet)?,
-            GTFArgs::PolicyWitnessLimit => tx
-                .policies()
-                .get(PolicyType::WitnessLimit)
-                .ok_or(PanicReason::PolicyIsNotSet)?,
-            GTFArgs::PolicyMaturity => tx
-                .policies()
-                .get(PolicyType::Maturity)
-                .ok_or(PanicReason::PolicyIsNotSet)?,
-            GTFArgs::PolicyExpiration => tx
-                .policies()
-                .get(PolicyType::Expiration)
-                .ok_or(PanicReason::PolicyIsNotSet)?,
-            GTFArgs::PolicyMaxFee => tx
-                .policies()
-                .get(PolicyType::MaxFee)
-                .ok_or(PanicReason::PolicyIsNotSet)?,
-            GTFArgs::ScriptInputsCount
-            | GTFArgs::CreateInputsCount
-            | GTFArgs::TxInputsCount => tx.inputs().len() as Word,
-            GTFArgs::ScriptOutputsCount
-            | GTFArgs::CreateOutputsCount
-            | GTFArgs::TxOutputsCount => tx.outputs().len() as Word,
-            GTFArgs::ScriptWitnessesCount
-            | GTFArgs::CreateWitnessesCount
-            | GTFArgs::TxWitnessesCount => tx.witnesses().len() as Word,
             GTFArgs::ScriptInputAtIndex
             | GTFArgs::CreateInputAtIndex
             | GTFArgs::TxInputAtIndex => ofs
@@ -231,15 +292,8 @@ impl<Tx> GTFInput<'_, Tx> {
                 tx.witnesses_offset_at(b)
                     .ok_or(PanicReason::WitnessNotFound)?,
             ) as Word,
-            GTFArgs::TxLength => self.tx_size,
 
             // Input
-            GTFArgs::InputType => {
-                tx.inputs()
-                    .get(b)
-                    .map(InputRepr::from)
-                    .ok_or(PanicReason::InputNotFound)? as Word
-            }
             GTFArgs::InputCoinTxId => ofs.saturating_add(
                 tx.inputs()
                     .get(b)
@@ -249,14 +303,6 @@ impl<Tx> GTFInput<'_, Tx> {
                     .and_then(|ofs| tx.inputs_offset_at(b).map(|o| o.saturating_add(ofs)))
                     .ok_or(PanicReason::InputNotFound)?,
             ) as Word,
-            GTFArgs::InputCoinOutputIndex => {
-                tx.inputs()
-                    .get(b)
-                    .filter(|i| i.is_coin())
-                    .and_then(Input::utxo_id)
-                    .map(UtxoId::output_index)
-                    .ok_or(PanicReason::InputNotFound)? as Word
-            }
             GTFArgs::InputCoinOwner => ofs.saturating_add(
                 tx.inputs()
                     .get(b)
@@ -266,12 +312,6 @@ impl<Tx> GTFInput<'_, Tx> {
                     .and_then(|ofs| tx.inputs_offset_at(b).map(|o| o.saturating_add(ofs)))
                     .ok_or(PanicReason::InputNotFound)?,
             ) as Word,
-            GTFArgs::InputCoinAmount => tx
-                .inputs()
-                .get(b)
-                .filter(|i| i.is_coin())
-                .and_then(Input::amount)
-                .ok_or(PanicReason::InputNotFound)?,
             GTFArgs::InputCoinAssetId => ofs.saturating_add(
                 tx.inputs()
                     .get(b)
@@ -290,34 +330,6 @@ impl<Tx> GTFInput<'_, Tx> {
                     .and_then(|ofs| tx.inputs_offset_at(b).map(|o| o.saturating_add(ofs)))
                     .ok_or(PanicReason::InputNotFound)?,
             ) as Word,
-            GTFArgs::InputCoinWitnessIndex => {
-                tx.inputs()
-                    .get(b)
-                    .filter(|i| i.is_coin())
-                    .and_then(Input::witness_index)
-                    .ok_or(PanicReason::InputNotFound)? as Word
-            }
-            GTFArgs::InputCoinPredicateLength => {
-                tx.inputs()
-                    .get(b)
-                    .filter(|i| i.is_coin())
-                    .and_then(Input::predicate_len)
-                    .ok_or(PanicReason::InputNotFound)? as Word
-            }
-            GTFArgs::InputCoinPredicateDataLength => {
-                tx.inputs()
-                    .get(b)
-                    .filter(|i| i.is_coin())
-                    .and_then(Input::predicate_data_len)
-                    .ok_or(PanicReason::InputNotFound)? as Word
-            }
-            GTFArgs::InputCoinPredicateGasUsed => {
-                tx.inputs()
-                    .get(b)
-                    .filter(|i| i.is_coin())
-                    .and_then(Input::predicate_gas_used)
-                    .ok_or(PanicReason::InputNotFound)? as Word
-            }
             GTFArgs::InputCoinPredicate => ofs.saturating_add(
                 tx.inputs()
                     .get(b)
@@ -343,14 +355,6 @@ impl<Tx> GTFInput<'_, Tx> {
                     .and_then(|ofs| tx.inputs_offset_at(b).map(|o| o.saturating_add(ofs)))
                     .ok_or(PanicReason::InputNotFound)?,
             ) as Word,
-            GTFArgs::InputContractOutputIndex => {
-                let b = u16::try_from(b)
-                    .map_err(|_| PanicReason::InvalidMetadataIdentifier)?;
-                input_contract_to_output_index
-                    .get(&b)
-                    .copied()
-                    .ok_or(PanicReason::InputNotFound)? as Word
-            }
             GTFArgs::InputContractId => ofs.saturating_add(
                 tx.inputs()
                     .get(b)
@@ -378,12 +382,6 @@ impl<Tx> GTFInput<'_, Tx> {
                     .and_then(|ofs| tx.inputs_offset_at(b).map(|o| o.saturating_add(ofs)))
                     .ok_or(PanicReason::InputNotFound)?,
             ) as Word,
-            GTFArgs::InputMessageAmount => tx
-                .inputs()
-                .get(b)
-                .filter(|i| i.is_message())
-                .and_then(Input::amount)
-                .ok_or(PanicReason::InputNotFound)?,
             GTFArgs::InputMessageNonce => ofs.saturating_add(
                 tx.inputs()
                     .get(b)
@@ -393,41 +391,6 @@ impl<Tx> GTFInput<'_, Tx> {
                     .and_then(|ofs| tx.inputs_offset_at(b).map(|o| o.saturating_add(ofs)))
                     .ok_or(PanicReason::InputNotFound)?,
             ) as Word,
-            GTFArgs::InputMessageWitnessIndex => {
-                tx.inputs()
-                    .get(b)
-                    .filter(|i| i.is_message())
-                    .and_then(Input::witness_index)
-                    .ok_or(PanicReason::InputNotFound)? as Word
-            }
-            GTFArgs::InputMessageDataLength => {
-                tx.inputs()
-                    .get(b)
-                    .filter(|i| i.is_message())
-                    .and_then(Input::input_data_len)
-                    .ok_or(PanicReason::InputNotFound)? as Word
-            }
-            GTFArgs::InputMessagePredicateLength => {
-                tx.inputs()
-                    .get(b)
-                    .filter(|i| i.is_message())
-                    .and_then(Input::predicate_len)
-                    .ok_or(PanicReason::InputNotFound)? as Word
-            }
-            GTFArgs::InputMessagePredicateDataLength => {
-                tx.inputs()
-                    .get(b)
-                    .filter(|i| i.is_message())
-                    .and_then(Input::predicate_data_len)
-                    .ok_or(PanicReason::InputNotFound)? as Word
-            }
-            GTFArgs::InputMessagePredicateGasUsed => {
-                tx.inputs()
-                    .get(b)
-                    .filter(|i| i.is_message())
-                    .and_then(Input::predicate_gas_used)
-                    .ok_or(PanicReason::InputNotFound)? as Word
-            }
             GTFArgs::InputMessageData => ofs.saturating_add(
                 tx.inputs()
                     .get(b)
@@ -455,12 +418,6 @@ impl<Tx> GTFInput<'_, Tx> {
             ) as Word,
 
             // Output
-            GTFArgs::OutputType => {
-                tx.outputs()
-                    .get(b)
-                    .map(OutputRepr::from)
-                    .ok_or(PanicReason::OutputNotFound)? as Word
-            }
             GTFArgs::OutputCoinTo => ofs.saturating_add(
                 tx.outputs()
                     .get(b)
@@ -472,12 +429,6 @@ impl<Tx> GTFInput<'_, Tx> {
                     })
                     .ok_or(PanicReason::OutputNotFound)?,
             ) as Word,
-            GTFArgs::OutputCoinAmount => tx
-                .outputs()
-                .get(b)
-                .filter(|o| o.is_coin())
-                .and_then(Output::amount)
-                .ok_or(PanicReason::OutputNotFound)?,
             GTFArgs::OutputCoinAssetId => ofs.saturating_add(
                 tx.outputs()
                     .get(b)
@@ -489,13 +440,6 @@ impl<Tx> GTFInput<'_, Tx> {
                     })
                     .ok_or(PanicReason::OutputNotFound)?,
             ) as Word,
-            GTFArgs::OutputContractInputIndex => {
-                tx.outputs()
-                    .get(b)
-                    .filter(|o| o.is_contract())
-                    .and_then(Output::input_index)
-                    .ok_or(PanicReason::InputNotFound)? as Word
-            }
             GTFArgs::OutputContractCreatedContractId => ofs.saturating_add(
                 tx.outputs()
                     .get(b)
@@ -519,6 +463,241 @@ impl<Tx> GTFInput<'_, Tx> {
                     .ok_or(PanicReason::OutputNotFound)?,
             ) as Word,
 
+            // Witness
+            GTFArgs::WitnessData => {
+                tx.witnesses_offset_at(b)
+                    .map(|w| ofs.saturating_add(w).saturating_add(WORD_SIZE))
+                    .ok_or(PanicReason::WitnessNotFound)? as Word
+            }
+
+            // If it is not any above commands, it is something specific to the
+            // transaction type or an integer field.
+            specific_args => {
+                let offset = match (tx.executable_type(), specific_args) {
+                    // Script
+                    (ExecutableTxType::Script(script), GTFArgs::Script) => {
+                        script.script_offset()
+                    }
+                    (ExecutableTxType::Script(script), GTFArgs::ScriptData) => {
+                        script.script_data_offset()
+                    }
+
+                    // Create
+                    (ExecutableTxType::Create(create), GTFArgs::CreateSalt) => {
+                        create.salt_offset()
+                    }
+                    (
+                        ExecutableTxType::Create(create),
+                        GTFArgs::CreateStorageSlotAtIndex,
+                    ) => create
+                        .storage_slots_offset_at(b)
+                        .ok_or(PanicReason::StorageSlotsNotFound)?,
+
+                    // Blob
+                    (ExecutableTxType::Blob(blob), GTFArgs::BlobId) => {
+                        blob.blob_id_offset()
+                    }
+
+                    // Upload
+                    (ExecutableTxType::Upload(upload), GTFArgs::UploadRoot) => {
+                        upload.bytecode_root_offset()
+                    }
+                    (
+                        ExecutableTxType::Upload(upload),
+                        GTFArgs::UploadProofSetAtIndex,
+                    ) => upload
+                        .proof_set_offset_at(b)
+                        .ok_or(PanicReason::ProofInUploadNotFound)?,
+
+                    // Upgrade
+                    (ExecutableTxType::Upgrade(upgrade), GTFArgs::UpgradePurpose) => {
+                        upgrade.upgrade_purpose_offset()
+                    }
+
+                    _ => return self.get_transaction_int_field(b, specific_args),
+                };
+
+                convert::checked_offset(ofs, offset).unwrap_or(Word::MAX)
+            }
+        };
+
+        Ok(a)
+    }
+
+    /// Returns the value of the `GTF` field that is an integer, e.g. a count, an
+    /// amount, a length or a witness index. Pointers into the transaction are handled
+    /// by `GTFInput::field`.
+    ///
+    /// The values are read from the transaction as is, without any arithmetic.
+    #[allow(deprecated)]
+    pub(crate) fn get_transaction_int_field(
+        &self,
+        b: usize,
+        args: GTFArgs,
+    ) -> Result<Word, PanicReason> {
+        let tx = self.tx;
+        let input_contract_to_output_index = self.input_contracts_index_to_output_index;
+
+        let a = match args {
+            GTFArgs::Type => Tx::transaction_type() as Word,
+
+            // General
+            GTFArgs::ScriptGasLimit => tx
+                .as_script()
+                .map(|script| *script.script_gas_limit())
+                .unwrap_or_default(),
+            GTFArgs::PolicyTypes => tx.policies().bits() as Word,
+            GTFArgs::PolicyTip => tx
+                .policies()
+                .get(PolicyType::Tip)
+                .ok_or(PanicReason::PolicyIsNotSet)?,
+            GTFArgs::PolicyWitnessLimit => tx
+                .policies()
+                .get(PolicyType::WitnessLimit)
+                .ok_or(PanicReason::PolicyIsNotSet)?,
+            GTFArgs::PolicyMaturity => tx
+                .policies()
+                .get(PolicyType::Maturity)
+                .ok_or(PanicReason::PolicyIsNotSet)?,
+            GTFArgs::PolicyExpiration => tx
+                .policies()
+                .get(PolicyType::Expiration)
+                .ok_or(PanicReason::PolicyIsNotSet)?,
+            GTFArgs::PolicyMaxFee => tx
+                .policies()
+                .get(PolicyType::MaxFee)
+                .ok_or(PanicReason::PolicyIsNotSet)?,
+            GTFArgs::ScriptInputsCount
+            | GTFArgs::CreateInputsCount
+            | GTFArgs::TxInputsCount => tx.inputs().len() as Word,
+            GTFArgs::ScriptOutputsCount
+            | GTFArgs::CreateOutputsCount
+            | GTFArgs::TxOutputsCount => tx.outputs().len() as Word,
+            GTFArgs::ScriptWitnessesCount
+            | GTFArgs::CreateWitnessesCount
+            | GTFArgs::TxWitnessesCount => tx.witnesses().len() as Word,
+            GTFArgs::TxLength => self.tx_size,
+
+            // Input
+            GTFArgs::InputType => {
+                tx.inputs()
+                    .get(b)
+                    .map(InputRepr::from)
+                    .ok_or(PanicReason::InputNotFound)? as Word
+            }
+            GTFArgs::InputCoinOutputIndex => {
+                tx.inputs()
+                    .get(b)
+                    .filter(|i| i.is_coin())
+                    .and_then(Input::utxo_id)
+                    .map(UtxoId::output_index)
+                    .ok_or(PanicReason::InputNotFound)? as Word
+            }
+            GTFArgs::InputCoinAmount => tx
+                .inputs()
+                .get(b)
+                .filter(|i| i.is_coin())
+                .and_then(Input::amount)
+                .ok_or(PanicReason::InputNotFound)?,
+            GTFArgs::InputCoinWitnessIndex => {
+                tx.inputs()
+                    .get(b)
+                    .filter(|i| i.is_coin())
+                    .and_then(Input::witness_index)
+                    .ok_or(PanicReason::InputNotFound)? as Word
+            }
+            GTFArgs::InputCoinPredicateLength => {
+                tx.inputs()
+                    .get(b)
+                    .filter(|i| i.is_coin())
+                    .and_then(Input::predicate_len)
+                    .ok_or(PanicReason::InputNotFound)? as Word
+            }
+            GTFArgs::InputCoinPredicateDataLength => {
+                tx.inputs()
+                    .get(b)
+                    .filter(|i| i.is_coin())
+                    .and_then(Input::predicate_data_len)
+                    .ok_or(PanicReason::InputNotFound)? as Word
+            }
+            GTFArgs::InputCoinPredicateGasUsed => {
+                tx.inputs()
+                    .get(b)
+                    .filter(|i| i.is_coin())
+                    .and_then(Input::predicate_gas_used)
+                    .ok_or(PanicReason::InputNotFound)? as Word
+            }
+            GTFArgs::InputContractOutputIndex => {
+                let b = convert::word_to_u16(b as Word)
+                    .ok_or(PanicReason::InvalidMetadataIdentifier)?;
+                input_contract_to_output_index
+                    .get(&b)
+                    .copied()
+                    .ok_or(PanicReason::InputNotFound)? as Word
+            }
+            GTFArgs::InputMessageAmount => tx
+                .inputs()
+                .get(b)
+                .filter(|i| i.is_message())
+                .and_then(Input::amount)
+                .ok_or(PanicReason::InputNotFound)?,
+            GTFArgs::InputMessageWitnessIndex => {
+                tx.inputs()
+                    .get(b)
+                    .filter(|i| i.is_message())
+                    .and_then(Input::witness_index)
+                    .ok_or(PanicReason::InputNotFound)? as Word
+            }
+            GTFArgs::InputMessageDataLength => {
+                tx.inputs()
+                    .get(b)
+                    .filter(|i| i.is_message())
+                    .and_then(Input::input_data_len)
+                    .ok_or(PanicReason::InputNotFound)? as Word
+            }
+            GTFArgs::InputMessagePredicateLength => {
+                tx.inputs()
+                    .get(b)
+                    .filter(|i| i.is_message())
+                    .and_then(Input::predicate_len)
+                    .ok_or(PanicReason::InputNotFound)? as Word
+            }
+            GTFArgs::InputMessagePredicateDataLength => {
+                tx.inputs()
+                    .get(b)
+                    .filter(|i| i.is_message())
+                    .and_then(Input::predicate_data_len)
+                    .ok_or(PanicReason::InputNotFound)? as Word
+            }
+            GTFArgs::InputMessagePredicateGasUsed => {
+                tx.inputs()
+                    .get(b)
+                    .filter(|i| i.is_message())
+                    .and_then(Input::predicate_gas_used)
+                    .ok_or(PanicReason::InputNotFound)? as Word
+            }
+
+            // Output
+            GTFArgs::OutputType => {
+                tx.outputs()
+                    .get(b)
+                    .map(OutputRepr::from)
+                    .ok_or(PanicReason::OutputNotFound)? as Word
+            }
+            GTFArgs::OutputCoinAmount => tx
+                .outputs()
+                .get(b)
+                .filter(|o| o.is_coin())
+                .and_then(Output::amount)
+                .ok_or(PanicReason::OutputNotFound)?,
+            GTFArgs::OutputContractInputIndex => {
+                tx.outputs()
+                    .get(b)
+                    .filter(|o| o.is_contract())
+                    .and_then(Output::input_index)
+                    .ok_or(PanicReason::InputNotFound)? as Word
+            }
+
             // Witness
             GTFArgs::WitnessDataLength => {
                 tx.witnesses()
@@ -526,11 +705,6 @@ impl<Tx> GTFInput<'_, Tx> {
                     .map(|w| w.as_ref().len())
                     .ok_or(PanicReason::WitnessNotFound)? as Word
             }
-            GTFArgs::WitnessData => {
-                tx.witnesses_offset_at(b)
-                    .map(|w| ofs.saturating_add(w).saturating_add(WORD_SIZE))
-                    .ok_or(PanicReason::WitnessNotFound)? as Word
-            }
 
             // If it is not any above commands, it is something specific to the
             // transaction type.
@@ -543,12 +717,6 @@ impl<Tx> GTFInput<'_, Tx> {
                     (ExecutableTxType::Script(script), GTFArgs::ScriptDataLength) => {
                         script.script_data().len() as Word
                     }
-                    (ExecutableTxType::Script(script), GTFArgs::Script) => {
-                        ofs.saturating_add(script.script_offset()) as Word
-                    }
-                    (ExecutableTxType::Script(script), GTFArgs::ScriptData) => {
-                        ofs.saturating_add(script.script_data_offset()) as Word
-                    }
 
                     // Create
                     (
@@ -559,32 +727,13 @@ impl<Tx> GTFInput<'_, Tx> {
                         ExecutableTxType::Create(create),
                         GTFArgs::CreateStorageSlotsCount,
                     ) => create.storage_slots().len() as Word,
-                    (ExecutableTxType::Create(create), GTFArgs::CreateSalt) => {
-                        ofs.saturating_add(create.salt_offset()) as Word
-                    }
-                    (
-                        ExecutableTxType::Create(create),
-                        GTFArgs::CreateStorageSlotAtIndex,
-                    ) => {
-                        (ofs.saturating_add(
-                            create
-                                .storage_slots_offset_at(b)
-                                .ok_or(PanicReason::StorageSlotsNotFound)?,
-                        )) as Word
-                    }
 
                     // Blob
-                    (ExecutableTxType::Blob(blob), GTFArgs::BlobId) => {
-                        ofs.saturating_add(blob.blob_id_offset()) as Word
-                    }
                     (ExecutableTxType::Blob(blob), GTFArgs::BlobWitnessIndex) => {
                         *blob.bytecode_witness_index() as Word
                     }
 
                     // Upload
-                    (ExecutableTxType::Upload(upload), GTFArgs::UploadRoot) => {
-                        ofs.saturating_add(upload.bytecode_root_offset()) as Word
-                    }
                     (ExecutableTxType::Upload(upload), GTFArgs::UploadWitnessIndex) => {
                         *upload.bytecode_witness_index() as Word
                     }
@@ -599,28 +748,12 @@ impl<Tx> GTFInput<'_, Tx> {
                     (ExecutableTxType::Upload(upload), GTFArgs::UploadProofSetCount) => {
                         upload.proof_set().len() as Word
                     }
-                    (
-                        ExecutableTxType::Upload(upload),
-                        GTFArgs::UploadProofSetAtIndex,
-                    ) => ofs.saturating_add(
-                        upload
-                            .proof_set_offset_at(b)
-                            .ok_or(PanicReason::ProofInUploadNotFound)?,
-                    ) as Word,
 
-                    // Upgrade
-                    (ExecutableTxType::Upgrade(upgrade), GTFArgs::UpgradePurpose) => {
-                        ofs.saturating_add(upgrade.upgrade_purpose_offset()) as Word
-                    }
-
-                    _ => return Err(PanicReason::InvalidMetadataIdentifier.into()),
+                    _ => return Err(PanicReason::InvalidMetadataIdentifier),
                 }
             }
         };
 
-        *result = a;
-
-        inc_pc(self.pc)?;
-        Ok(())
+        Ok(a)
     }
 }
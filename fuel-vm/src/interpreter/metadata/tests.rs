@@ -3,6 +3,7 @@ use alloc::vec;
 use fuel_tx::{
     Script,
     TxParameters,
+    field::Inputs,
 };
 use fuel_types::BlockHeight;
 use test_case::test_case;
@@ -45,17 +46,117 @@ fn test_get_transaction_field() {
         input_contracts_index_to_output_index: &input_contracts_index_to_output_index,
         tx_offset: 0,
         tx_size: fuel_tx::TxParameters::DEFAULT.tx_offset() as Word,
-        pc: RegMut::new(&mut pc),
     };
     let mut result = 1;
     let b = 0;
     input
-        .get_transaction_field(&mut result, b, GTFArgs::ScriptGasLimit as Immediate12)
+        .get_transaction_field(
+            RegMut::new(&mut pc),
+            &mut result,
+            b,
+            GTFArgs::ScriptGasLimit as Immediate12,
+        )
         .unwrap();
     assert_eq!(pc, 8);
     assert_eq!(result, *tx.script_gas_limit());
 }
 
+#[test]
+fn get_transaction_int_field_returns_integer_fields_only() {
+    let mut tx = Script::default();
+    *tx.script_data_mut() = vec![1, 2, 3];
+    let input_contracts_index_to_output_index = Default::default();
+    let input = GTFInput {
+        tx: &tx,
+        input_contracts_index_to_output_index: &input_contracts_index_to_output_index,
+        tx_offset: 0,
+        tx_size: 0,
+    };
+
+    assert_eq!(
+        input.get_transaction_int_field(0, GTFArgs::ScriptDataLength),
+        Ok(3)
+    );
+    assert_eq!(
+        input.get_transaction_int_field(0, GTFArgs::ScriptData),
+        Err(PanicReason::InvalidMetadataIdentifier)
+    );
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[test]
+fn field_range_returns_pointer_and_length() {
+    // Given
+    let tx_offset = 100;
+    let mut tx = Script::default();
+    *tx.script_data_mut() = vec![1, 2, 3];
+    let input_contracts_index_to_output_index = Default::default();
+    let input = GTFInput {
+        tx: &tx,
+        input_contracts_index_to_output_index: &input_contracts_index_to_output_index,
+        tx_offset,
+        tx_size: 0,
+    };
+
+    // When
+    let range = input.field_range(0, GTFArgs::ScriptData).unwrap();
+
+    // Then
+    assert_eq!(range.start(), tx_offset + tx.script_data_offset());
+    assert_eq!(range.len(), 3);
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[test]
+fn field_range_returns_empty_range_for_fields_without_length() {
+    // Given
+    let mut tx = Script::default();
+    *tx.inputs_mut() = vec![Input::coin_signed(
+        Default::default(),
+        Default::default(),
+        0,
+        Default::default(),
+        Default::default(),
+        0,
+    )];
+    let b = 0;
+    let input_contracts_index_to_output_index = Default::default();
+    let input = GTFInput {
+        tx: &tx,
+        input_contracts_index_to_output_index: &input_contracts_index_to_output_index,
+        tx_offset: 0,
+        tx_size: 0,
+    };
+
+    // When
+    let range = input.field_range(b, GTFArgs::InputCoinOwner).unwrap();
+
+    // Then
+    let owner_offset =
+        tx.inputs_offset_at(b).unwrap() + tx.inputs()[b].repr().owner_offset().unwrap();
+    assert_eq!(range.start(), owner_offset);
+    assert!(range.is_empty());
+}
+
+#[test]
+fn field_range_fails_for_value_fields() {
+    // Given
+    let tx = Script::default();
+    let input_contracts_index_to_output_index = Default::default();
+    let input = GTFInput {
+        tx: &tx,
+        input_contracts_index_to_output_index: &input_contracts_index_to_output_index,
+        tx_offset: 0,
+        tx_size: 0,
+    };
+
+    // When
+    let result = input.field_range(0, GTFArgs::ScriptGasLimit);
+
+    // Then
+    assert_eq!(result, Err(PanicReason::InvalidMetadataIdentifier));
+}
+
 #[test_case(Context::PredicateEstimation { program: RuntimePredicate::empty() }, 2 => (); "can fetch inside predicate estimation")]
 #[test_case(Context::PredicateVerification { program: RuntimePredicate::empty() }, 2 => (); "can fetch inside predicate verification")]
 #[test_case(Context::Script { block_height: BlockHeight::default() }, 3 => (); "can fetch inside script")]
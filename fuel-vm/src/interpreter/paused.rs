@@ -0,0 +1,180 @@
+//! Pausing the [`Interpreter`] execution and resuming it later.
+
+use super::{
+    EcalRecord,
+    InitialBalances,
+    Interpreter,
+    InterpreterParams,
+    Memory,
+    MemoryInstance,
+    NotSupportedEcal,
+    PanicContext,
+    ReceiptsCtx,
+    RuntimeBalances,
+};
+use crate::{
+    call::CallFrame,
+    consts::*,
+    context::Context,
+    state::Debugger,
+    storage::AccessSet,
+    verification,
+};
+
+use alloc::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+    vec::Vec,
+};
+use fuel_tx::Contract;
+use fuel_types::{
+    ContractId,
+    Word,
+};
+
+/// The state of a paused execution: everything the [`Interpreter`] holds except
+/// the storage, the observers and the gas refund policy, which must be set again on
+/// the restored interpreter.
+///
+/// It is created by [`Interpreter::pause`], usually after the execution stopped at a
+/// breakpoint. With the `serde` feature it can be persisted and turned back into an
+/// interpreter with [`Interpreter::from_paused`], possibly in a different process.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PausedExecution<Tx = (), Ecal = NotSupportedEcal, V = verification::Normal> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; VM_REGISTER_COUNT]>")
+    )]
+    registers: [Word; VM_REGISTER_COUNT],
+    memory: MemoryInstance,
+    frames: Vec<CallFrame>,
+    receipts: ReceiptsCtx,
+    tx: Tx,
+    initial_balances: InitialBalances,
+    input_contracts: BTreeSet<ContractId>,
+    input_contracts_index_to_output_index: BTreeMap<u16, u16>,
+    debugger: Debugger,
+    context: Context,
+    balances: RuntimeBalances,
+    interpreter_params: InterpreterParams,
+    panic_context: PanicContext,
+    ecal_state: Ecal,
+    verifier: V,
+    preloaded_contract: Option<(ContractId, Contract)>,
+    gas_refund: Word,
+    initial_gas: Word,
+    gas_by_contract: Option<hashbrown::HashMap<ContractId, Word>>,
+    access_set: Option<AccessSet>,
+    ecal_records: Option<Vec<EcalRecord>>,
+    #[cfg(feature = "opcode-histogram")]
+    opcode_histogram: hashbrown::HashMap<fuel_asm::Opcode, u64>,
+}
+
+impl<M, S, Tx, Ecal, V> Interpreter<M, S, Tx, Ecal, V>
+where
+    M: Memory,
+    Tx: Clone,
+    Ecal: Clone,
+    V: Clone,
+{
+    /// Captures the current execution state, so it can be restored later with
+    /// [`Interpreter::from_paused`].
+    pub fn pause(&self) -> PausedExecution<Tx, Ecal, V> {
+        PausedExecution {
+            registers: self.registers,
+            memory: self.memory.as_ref().clone(),
+            frames: self.frames.clone(),
+            receipts: self.receipts.clone(),
+            tx: self.tx.clone(),
+            initial_balances: self.initial_balances.clone(),
+            input_contracts: self.input_contracts.clone(),
+            input_contracts_index_to_output_index: self
+                .input_contracts_index_to_output_index
+                .clone(),
+            debugger: self.debugger.clone(),
+            context: self.context.clone(),
+            balances: self.balances.clone(),
+            interpreter_params: self.interpreter_params.clone(),
+            panic_context: self.panic_context.clone(),
+            ecal_state: self.ecal_state.clone(),
+            verifier: self.verifier.clone(),
+            preloaded_contract: self.preloaded_contract.clone(),
+            gas_refund: self.gas_refund,
+            initial_gas: self.initial_gas,
+            gas_by_contract: self.gas_by_contract.clone(),
+            access_set: self.access_set.clone(),
+            ecal_records: self.ecal_records.clone(),
+            #[cfg(feature = "opcode-histogram")]
+            opcode_histogram: self.opcode_histogram.clone(),
+        }
+    }
+}
+
+impl<S, Tx, Ecal, V> Interpreter<MemoryInstance, S, Tx, Ecal, V> {
+    /// Restores the interpreter from a paused execution on top of the provided
+    /// storage. Use [`Interpreter::resume`] to continue the execution.
+    ///
+    /// The storage must contain the changes made by the execution before it was
+    /// paused.
+    pub fn from_paused(paused: PausedExecution<Tx, Ecal, V>, storage: S) -> Self {
+        let PausedExecution {
+            registers,
+            memory,
+            frames,
+            receipts,
+            tx,
+            initial_balances,
+            input_contracts,
+            input_contracts_index_to_output_index,
+            debugger,
+            context,
+            balances,
+            interpreter_params,
+            panic_context,
+            ecal_state,
+            verifier,
+            preloaded_contract,
+            gas_refund,
+            initial_gas,
+            gas_by_contract,
+            access_set,
+            ecal_records,
+            #[cfg(feature = "opcode-histogram")]
+            opcode_histogram,
+        } = paused;
+
+        Self {
+            registers,
+            memory,
+            frames,
+            receipts,
+            tx,
+            initial_balances,
+            input_contracts,
+            input_contracts_index_to_output_index,
+            storage,
+            debugger,
+            context,
+            balances,
+            interpreter_params,
+            panic_context,
+            ecal_state,
+            verifier,
+            preloaded_contract,
+            storage_observer: None,
+            access_set,
+            ecal_records,
+            call_observer: None,
+            gas_refund_policy: None,
+            opcode_gas_observer: None,
+            gas_refund,
+            initial_gas,
+            gas_by_contract,
+            #[cfg(feature = "opcode-histogram")]
+            opcode_histogram,
+        }
+    }
+}
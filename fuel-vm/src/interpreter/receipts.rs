@@ -21,10 +21,22 @@ use crate::{
 };
 
 /// Receipts and the associated Merkle tree
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReceiptsCtx {
     receipts: Vec<Receipt>,
     receipts_tree: MerkleTree,
+    max_receipts: usize,
+}
+
+impl Default for ReceiptsCtx {
+    fn default() -> Self {
+        Self {
+            receipts: Default::default(),
+            receipts_tree: Default::default(),
+            max_receipts: Self::MAX_RECEIPTS,
+        }
+    }
 }
 
 impl ReceiptsCtx {
@@ -32,18 +44,31 @@ impl ReceiptsCtx {
     /// https://github.com/FuelLabs/fuel-specs/blob/master/src/fuel-vm/instruction-set.md#Receipts
     pub const MAX_RECEIPTS: usize = u16::MAX as usize;
 
+    /// Sets the maximum number of receipts of the context, clamped to
+    /// [`Self::MAX_RECEIPTS`]. At least two slots are always kept for the panic and
+    /// script result receipts ending the script.
+    pub fn set_max_receipts(&mut self, max_receipts: usize) {
+        self.max_receipts = max_receipts.clamp(2, Self::MAX_RECEIPTS);
+    }
+
+    /// Returns the maximum number of receipts of the context.
+    pub fn max_receipts(&self) -> usize {
+        self.max_receipts
+    }
+
     /// Add a new receipt, updating the Merkle tree as well.
     /// Returns a panic if the context is full.
+    #[allow(clippy::arithmetic_side_effects)] // Safety: max_receipts is at least 2
     pub fn push(&mut self, receipt: Receipt) -> SimpleResult<()> {
-        if self.receipts.len() == Self::MAX_RECEIPTS {
+        if self.receipts.len() >= self.max_receipts {
             return Err(Bug::new(BugVariant::ReceiptsCtxFull).into())
         }
 
         // Last two slots can be only used for ending the script,
         // with a script result optinally preceded by a panic
-        if (self.receipts.len() == Self::MAX_RECEIPTS - 1
+        if (self.receipts.len() == self.max_receipts - 1
             && !matches!(receipt, Receipt::ScriptResult { .. }))
-            || (self.receipts.len() == Self::MAX_RECEIPTS - 2
+            || (self.receipts.len() == self.max_receipts - 2
                 && !matches!(
                     receipt,
                     Receipt::ScriptResult { .. } | Receipt::Panic { .. }
@@ -117,6 +117,7 @@ pub mod prelude {
         call::{
             Call,
             CallFrame,
+            CallObserver,
         },
         context::Context,
         error::{
@@ -139,6 +140,7 @@ pub mod prelude {
             Debugger,
             ProgramState,
             StateTransition,
+            StateTransitionDiff,
             StateTransitionRef,
         },
         storage::{
@@ -2,6 +2,15 @@
 
 use crate::interpreter::Memory;
 
+#[cfg(feature = "spin-pool")]
+mod spin_pool;
+
+#[cfg(feature = "spin-pool")]
+pub use spin_pool::{
+    SpinPooledMemory,
+    SpinVmMemoryPool,
+};
+
 #[cfg(any(test, feature = "test-helpers"))]
 use crate::interpreter::MemoryInstance;
 
@@ -12,6 +21,17 @@ pub trait VmMemoryPool: Sync {
 
     /// Gets a new VM memory instance from the pool.
     fn get_new(&self) -> impl core::future::Future<Output = Self::Memory> + Send;
+
+    /// Eagerly allocates and retains up to `n` instances, so the first calls to
+    /// [`Self::get_new`] don't pay for the allocation. Pools with a capacity bound
+    /// must not retain more instances than it allows.
+    ///
+    /// Returns the number of retained instances. The default implementation doesn't
+    /// retain anything.
+    fn warm(&self, n: usize) -> usize {
+        let _ = n;
+        0
+    }
 }
 
 /// Dummy pool that just returns new instance every time.
@@ -0,0 +1,145 @@
+use super::VmMemoryPool;
+use crate::interpreter::MemoryInstance;
+
+use alloc::sync::Arc;
+use spin::mutex::SpinMutex;
+
+type Slots<const N: usize> = SpinMutex<[Option<MemoryInstance>; N]>;
+
+/// Pool of VM memory instances usable without `std`, e.g. to verify predicates on
+/// constrained targets.
+///
+/// Retains up to `N` instances in a fixed-size array guarded by a spinlock.
+/// [`VmMemoryPool::get_new`] takes a retained instance if any, otherwise it allocates
+/// a new one. The instance goes back to the pool when the returned
+/// [`SpinPooledMemory`] is dropped, unless the pool is full. Cloning the pool is cheap
+/// and the clones share the retained instances.
+#[derive(Debug, Clone)]
+pub struct SpinVmMemoryPool<const N: usize> {
+    slots: Arc<Slots<N>>,
+}
+
+impl<const N: usize> SpinVmMemoryPool<N> {
+    /// Creates an empty pool retaining up to `N` instances.
+    pub fn new() -> Self {
+        Self {
+            slots: Arc::new(SpinMutex::new(core::array::from_fn(|_| None))),
+        }
+    }
+
+    /// The maximal number of retained instances.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// The number of retained instances, ready to be taken.
+    pub fn available(&self) -> usize {
+        self.slots
+            .lock()
+            .iter()
+            .filter(|slot| slot.is_some())
+            .count()
+    }
+}
+
+impl<const N: usize> Default for SpinVmMemoryPool<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> VmMemoryPool for SpinVmMemoryPool<N> {
+    type Memory = SpinPooledMemory<N>;
+
+    fn get_new(&self) -> impl core::future::Future<Output = Self::Memory> + Send {
+        let retained = self.slots.lock().iter_mut().find_map(Option::take);
+        core::future::ready(SpinPooledMemory {
+            memory: retained.unwrap_or_default(),
+            slots: self.slots.clone(),
+        })
+    }
+
+    fn warm(&self, n: usize) -> usize {
+        let mut slots = self.slots.lock();
+        let retained = slots.iter().filter(|slot| slot.is_some()).count();
+        slots
+            .iter_mut()
+            .filter(|slot| slot.is_none())
+            .take(n.saturating_sub(retained))
+            .for_each(|slot| *slot = Some(MemoryInstance::new()));
+        slots.iter().filter(|slot| slot.is_some()).count()
+    }
+}
+
+/// A VM memory instance taken from a [`SpinVmMemoryPool`]. It is reset and returned to
+/// the pool on drop.
+#[derive(Debug)]
+pub struct SpinPooledMemory<const N: usize> {
+    memory: MemoryInstance,
+    slots: Arc<Slots<N>>,
+}
+
+impl<const N: usize> AsRef<MemoryInstance> for SpinPooledMemory<N> {
+    fn as_ref(&self) -> &MemoryInstance {
+        &self.memory
+    }
+}
+
+impl<const N: usize> AsMut<MemoryInstance> for SpinPooledMemory<N> {
+    fn as_mut(&mut self) -> &mut MemoryInstance {
+        &mut self.memory
+    }
+}
+
+impl<const N: usize> Drop for SpinPooledMemory<N> {
+    fn drop(&mut self) {
+        let mut memory = core::mem::take(&mut self.memory);
+        memory.reset();
+        let mut slots = self.slots.lock();
+        if let Some(slot) = slots.iter_mut().find(|slot| slot.is_none()) {
+            *slot = Some(memory);
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[test]
+    fn get_new__reuses_the_dropped_instances() {
+        // Given
+        let pool = SpinVmMemoryPool::<1>::new();
+        let mut memory = block_on(pool.get_new());
+        memory
+            .as_mut()
+            .grow_stack(1024)
+            .expect("The stack can grow");
+        drop(memory);
+        assert_eq!(pool.available(), 1);
+
+        // When
+        let memory = block_on(pool.get_new());
+
+        // Then
+        assert_eq!(pool.available(), 0);
+        assert_eq!(memory.as_ref(), &MemoryInstance::new());
+    }
+
+    #[test]
+    fn drop__does_not_retain_more_than_the_capacity() {
+        // Given
+        let pool = SpinVmMemoryPool::<2>::new();
+        let memories: alloc::vec::Vec<_> =
+            (0..3).map(|_| block_on(pool.get_new())).collect();
+
+        // When
+        drop(memories);
+
+        // Then
+        assert_eq!(pool.available(), pool.capacity());
+        assert_eq!(pool.warm(5), 2);
+    }
+}
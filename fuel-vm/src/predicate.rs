@@ -1,8 +1,20 @@
 //! Predicate representations with required data to be executed during VM runtime
 
-use fuel_tx::field;
-
-use crate::interpreter::MemoryRange;
+use fuel_crypto::Hasher;
+use fuel_tx::{
+    DependentCost,
+    GasCosts,
+    field,
+};
+use fuel_types::{
+    Bytes32,
+    canonical::Serialize,
+};
+
+use crate::{
+    checked_transaction::CheckPredicateParams,
+    interpreter::MemoryRange,
+};
 
 /// Runtime representation of a predicate
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -48,6 +60,220 @@ impl RuntimePredicate {
     }
 }
 
+/// Remembers the predicates verified successfully, to skip the execution of the
+/// identical verifications, e.g. of the same transaction seen twice by a mempool.
+///
+/// The entries are identified by [`predicate_cache_key`]. Only the successful
+/// verifications are recorded, the failing ones are always executed again.
+pub trait PredicateResultCache: core::fmt::Debug + Send + Sync {
+    /// Returns `true` if the verification identified by `key` succeeded before.
+    fn contains(&self, key: &Bytes32) -> bool;
+
+    /// Records that the verification identified by `key` succeeded.
+    fn insert(&self, key: Bytes32);
+}
+
+/// Computes the key identifying the verification of the predicate of the input
+/// `index` of `tx` in a [`PredicateResultCache`].
+///
+/// The key is the SHA-256 hash of everything the predicate can observe:
+/// - the canonical encoding of `tx`, which holds the predicate bytecode, the predicate
+///   data and the gas used declared by every input;
+/// - `index`, which selects the verified predicate;
+/// - the chain ID, which is part of the transaction ID, and the base asset ID, both
+///   readable with `GM`;
+/// - the transaction offset, the maximum number of inputs, the maximum contract size and
+///   the maximum message data length, which shape the VM memory and the limits of the
+///   instructions;
+/// - the gas costs, which decide whether the predicate consumes exactly the declared gas.
+///
+/// Every value is fed in a fixed encoding independent of the target and of the
+/// derived implementations, so the keys can be shared and persisted across versions
+/// as long as the encoded values don't change.
+///
+/// The fee parameters, the maximum gas per transaction and the maximum witness
+/// size are not part of the key, because they are checked for the whole transaction
+/// on every verification. The blobs loaded by the predicate are identified by the
+/// hash of their bytecode, so they can't change under the same key.
+pub fn predicate_cache_key<Tx>(
+    tx: &Tx,
+    index: usize,
+    params: &CheckPredicateParams,
+) -> Bytes32
+where
+    Tx: Serialize,
+{
+    let mut hasher = Hasher::default();
+    hasher.input(tx.to_bytes());
+    hasher.input((index as u64).to_be_bytes());
+    hasher.input(params.chain_id.to_bytes());
+    hasher.input(params.base_asset_id);
+    hasher.input((params.tx_offset as u64).to_be_bytes());
+    hasher.input(params.max_inputs.to_be_bytes());
+    hasher.input(params.contract_max_size.to_be_bytes());
+    hasher.input(params.max_message_data_length.to_be_bytes());
+    hash_gas_costs(&mut hasher, &params.gas_costs);
+    hasher.finalize()
+}
+
+/// Feeds every gas cost into the `hasher` in a fixed order: the costs as big-endian
+/// words, the dependent costs as a variant byte followed by their two words, and the
+/// costs undefined by older versions of the gas costs prefixed with a presence byte.
+fn hash_gas_costs(hasher: &mut Hasher, gas_costs: &GasCosts) {
+    let words = [
+        gas_costs.add(),
+        gas_costs.addi(),
+        gas_costs.and(),
+        gas_costs.andi(),
+        gas_costs.bal(),
+        gas_costs.bhei(),
+        gas_costs.bhsh(),
+        gas_costs.burn(),
+        gas_costs.cb(),
+        gas_costs.cfsi(),
+        gas_costs.div(),
+        gas_costs.divi(),
+        gas_costs.eck1(),
+        gas_costs.ecr1(),
+        gas_costs.eq_(),
+        gas_costs.exp(),
+        gas_costs.expi(),
+        gas_costs.flag(),
+        gas_costs.gm(),
+        gas_costs.gt(),
+        gas_costs.gtf(),
+        gas_costs.ji(),
+        gas_costs.jmp(),
+        gas_costs.jne(),
+        gas_costs.jnei(),
+        gas_costs.jnzi(),
+        gas_costs.jmpf(),
+        gas_costs.jmpb(),
+        gas_costs.jnzf(),
+        gas_costs.jnzb(),
+        gas_costs.jnef(),
+        gas_costs.jneb(),
+        gas_costs.lb(),
+        gas_costs.log(),
+        gas_costs.lt(),
+        gas_costs.lw(),
+        gas_costs.mint(),
+        gas_costs.mlog(),
+        gas_costs.mod_op(),
+        gas_costs.modi(),
+        gas_costs.move_op(),
+        gas_costs.movi(),
+        gas_costs.mroo(),
+        gas_costs.mul(),
+        gas_costs.muli(),
+        gas_costs.mldv(),
+        gas_costs.noop(),
+        gas_costs.not(),
+        gas_costs.or(),
+        gas_costs.ori(),
+        gas_costs.poph(),
+        gas_costs.popl(),
+        gas_costs.pshh(),
+        gas_costs.pshl(),
+        gas_costs.ret(),
+        gas_costs.rvrt(),
+        gas_costs.sb(),
+        gas_costs.sll(),
+        gas_costs.slli(),
+        gas_costs.srl(),
+        gas_costs.srli(),
+        gas_costs.srw(),
+        gas_costs.sub(),
+        gas_costs.subi(),
+        gas_costs.sw(),
+        gas_costs.sww(),
+        gas_costs.time(),
+        gas_costs.tr(),
+        gas_costs.tro(),
+        gas_costs.wdcm(),
+        gas_costs.wqcm(),
+        gas_costs.wdop(),
+        gas_costs.wqop(),
+        gas_costs.wdml(),
+        gas_costs.wqml(),
+        gas_costs.wddv(),
+        gas_costs.wqdv(),
+        gas_costs.wdmd(),
+        gas_costs.wqmd(),
+        gas_costs.wdam(),
+        gas_costs.wqam(),
+        gas_costs.wdmm(),
+        gas_costs.wqmm(),
+        gas_costs.xor(),
+        gas_costs.xori(),
+        gas_costs.new_storage_per_byte(),
+    ];
+    for word in words {
+        hasher.input(word.to_be_bytes());
+    }
+
+    let dependent_costs = [
+        gas_costs.aloc(),
+        gas_costs.cfe(),
+        gas_costs.cfei(),
+        gas_costs.call(),
+        gas_costs.ccp(),
+        gas_costs.croo(),
+        gas_costs.csiz(),
+        gas_costs.ed19(),
+        gas_costs.k256(),
+        gas_costs.ldc(),
+        gas_costs.logd(),
+        gas_costs.mcl(),
+        gas_costs.mcli(),
+        gas_costs.mcp(),
+        gas_costs.mcpi(),
+        gas_costs.meq(),
+        gas_costs.retd(),
+        gas_costs.s256(),
+        gas_costs.scwq(),
+        gas_costs.smo(),
+        gas_costs.srwq(),
+        gas_costs.swwq(),
+        gas_costs.contract_root(),
+        gas_costs.state_root(),
+        gas_costs.vm_initialization(),
+    ];
+    for cost in dependent_costs {
+        hash_dependent_cost(hasher, cost);
+    }
+
+    match gas_costs.ecop() {
+        Ok(word) => {
+            hasher.input([1]);
+            hasher.input(word.to_be_bytes());
+        }
+        Err(_) => hasher.input([0]),
+    }
+    for cost in [gas_costs.bsiz(), gas_costs.bldd(), gas_costs.epar()] {
+        match cost {
+            Ok(cost) => {
+                hasher.input([1]);
+                hash_dependent_cost(hasher, cost);
+            }
+            Err(_) => hasher.input([0]),
+        }
+    }
+}
+
+fn hash_dependent_cost(hasher: &mut Hasher, cost: DependentCost) {
+    let (variant, base, units) = match cost {
+        DependentCost::LightOperation {
+            base,
+            units_per_gas,
+        } => (0u8, base, units_per_gas),
+        DependentCost::HeavyOperation { base, gas_per_unit } => (1u8, base, gas_per_unit),
+    };
+    hasher.input([variant]);
+    hasher.input(base.to_be_bytes());
+    hasher.input(units.to_be_bytes());
+}
+
 #[allow(clippy::cast_possible_truncation)]
 #[cfg(test)]
 mod tests {
@@ -2,10 +2,20 @@
 
 use alloc::vec::Vec;
 
-use fuel_tx::Receipt;
+use fuel_tx::{
+    Output,
+    Receipt,
+    field,
+};
 use fuel_types::{
     Bytes32,
+    ContractId,
     Word,
+    canonical::{
+        self,
+        Deserialize,
+        Serialize,
+    },
 };
 
 mod debug;
@@ -56,6 +66,7 @@ impl From<DebugEval> for ExecuteState {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Deserialize, Serialize)]
 /// Resulting state of a transaction/program execution.
 pub enum ProgramState {
     /// The transaction returned a [`Word`].
@@ -97,6 +108,44 @@ impl ProgramState {
     pub const fn is_debug(&self) -> bool {
         self.debug_ref().is_some()
     }
+
+    /// Return `true` if the execution finished, either by returning or reverting,
+    /// and `false` if the VM is suspended by a debug event.
+    ///
+    /// ```
+    /// use fuel_vm::state::{
+    ///     Breakpoint,
+    ///     DebugEval,
+    ///     ProgramState,
+    /// };
+    ///
+    /// assert!(ProgramState::Return(1).is_complete());
+    /// assert!(ProgramState::Revert(0).is_complete());
+    ///
+    /// let paused = DebugEval::Breakpoint(Breakpoint::script(0));
+    /// assert!(!ProgramState::RunProgram(paused).is_complete());
+    /// ```
+    pub const fn is_complete(&self) -> bool {
+        !self.is_debug()
+    }
+
+    /// The value passed to the `RVRT` instruction, if the execution reverted.
+    ///
+    /// A panic also reverts the execution with the value `0`. The panic reason is
+    /// reported by the [`Receipt::Panic`] receipt.
+    ///
+    /// ```
+    /// use fuel_vm::state::ProgramState;
+    ///
+    /// assert_eq!(ProgramState::Revert(42).as_revert(), Some(42));
+    /// assert_eq!(ProgramState::Return(42).as_revert(), None);
+    /// ```
+    pub const fn as_revert(&self) -> Option<Word> {
+        match self {
+            Self::Revert(r) => Some(*r),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -139,12 +188,88 @@ impl<Tx> StateTransition<Tx> {
         self.receipts.as_slice()
     }
 
+    /// The binary Merkle root of the serialized receipts, in the order they were
+    /// produced by the VM.
+    pub fn receipts_root(&self) -> Bytes32 {
+        receipts_root(&self.receipts)
+    }
+
     /// Convert this instance into its internal attributes.
     pub fn into_inner(self) -> (ProgramState, Tx, Vec<Receipt>) {
         (self.state, self.tx, self.receipts)
     }
 }
 
+impl<Tx> StateTransition<Tx> {
+    /// Version of the binary format produced by [`StateTransition::encode`].
+    pub const ENCODING_VERSION: u8 = 1;
+
+    /// Serializes the state transition into bytes.
+    ///
+    /// The bytes start with the [`Self::ENCODING_VERSION`] header byte, followed by
+    /// the canonical encoding of the program state, the transaction and the
+    /// receipts. The receipt fields skipped by the canonical encoding, like the
+    /// data of `LogData`, are not part of the output.
+    pub fn encode(&self) -> Vec<u8>
+    where
+        Tx: Serialize,
+    {
+        let mut bytes = Vec::with_capacity(
+            1usize
+                .saturating_add(self.state.size())
+                .saturating_add(self.tx.size())
+                .saturating_add(self.receipts.size()),
+        );
+        bytes.push(Self::ENCODING_VERSION);
+        bytes.extend(self.state.to_bytes());
+        bytes.extend(self.tx.to_bytes());
+        bytes.extend(self.receipts.to_bytes());
+        bytes
+    }
+
+    /// Deserializes a state transition produced by [`StateTransition::encode`].
+    ///
+    /// Fails if the header byte doesn't match [`Self::ENCODING_VERSION`] or if the
+    /// `bytes` are not fully consumed.
+    pub fn decode(mut bytes: &[u8]) -> Result<Self, canonical::Error>
+    where
+        Tx: Deserialize,
+    {
+        use canonical::Input;
+
+        if bytes.read_byte()? != Self::ENCODING_VERSION {
+            return Err(canonical::Error::Unknown(
+                "Unsupported state transition encoding version",
+            ));
+        }
+
+        let state = ProgramState::decode(&mut bytes)?;
+        let tx = Tx::decode(&mut bytes)?;
+        let receipts = Vec::<Receipt>::decode(&mut bytes)?;
+
+        if !bytes.is_empty() {
+            return Err(canonical::Error::Unknown(
+                "Trailing bytes after the state transition",
+            ));
+        }
+
+        Ok(Self::new(state, tx, receipts))
+    }
+}
+
+impl<Tx: field::Outputs> StateTransition<Tx> {
+    /// The contract id and state root of each `Output::ContractCreated` of the
+    /// resulting transaction.
+    pub fn created_contracts(&self) -> Vec<(ContractId, Bytes32)> {
+        created_contracts(self.tx.outputs())
+    }
+
+    /// Compares the result of this transaction execution with `other`.
+    pub fn diff(&self, other: &Self) -> StateTransitionDiff {
+        StateTransitionRef::from(self).diff(&StateTransitionRef::from(other))
+    }
+}
+
 impl<Tx> From<StateTransition<Tx>> for ProgramState {
     fn from(t: StateTransition<Tx>) -> ProgramState {
         t.state
@@ -185,6 +310,12 @@ impl<'a, Tx> StateTransitionRef<'a, Tx> {
         self.receipts
     }
 
+    /// The binary Merkle root of the serialized receipts, in the order they were
+    /// produced by the VM.
+    pub fn receipts_root(&self) -> Bytes32 {
+        receipts_root(self.receipts)
+    }
+
     /// Flag whether the client should revert after execution.
     pub fn should_revert(&self) -> bool {
         self.receipts
@@ -193,6 +324,82 @@ impl<'a, Tx> StateTransitionRef<'a, Tx> {
     }
 }
 
+impl<Tx: field::Outputs> StateTransitionRef<'_, Tx> {
+    /// The contract id and state root of each `Output::ContractCreated` of the
+    /// resulting transaction.
+    pub fn created_contracts(&self) -> Vec<(ContractId, Bytes32)> {
+        created_contracts(self.tx.outputs())
+    }
+
+    /// Compares the result of this transaction execution with `other`.
+    pub fn diff(&self, other: &Self) -> StateTransitionDiff {
+        StateTransitionDiff {
+            changed_outputs: changed_indices(self.tx.outputs(), other.tx.outputs()),
+            changed_receipts: changed_indices(self.receipts, other.receipts),
+            gas_delta: i128::from(script_gas_used(other.receipts))
+                .saturating_sub(i128::from(script_gas_used(self.receipts))),
+        }
+    }
+}
+
+/// The difference between the results of two transaction executions, produced by
+/// [`StateTransition::diff`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct StateTransitionDiff {
+    /// Indexes of the outputs that differ, including the ones present only in one of
+    /// the transactions.
+    pub changed_outputs: Vec<usize>,
+    /// Indexes of the receipts that differ, including the ones present only in one of
+    /// the executions.
+    pub changed_receipts: Vec<usize>,
+    /// The gas used by the script of the other execution minus the gas used by this
+    /// one. The gas used is zero for transactions without a script.
+    pub gas_delta: i128,
+}
+
+impl StateTransitionDiff {
+    /// Returns `true` if both executions have the same outputs, receipts and gas used.
+    pub fn is_empty(&self) -> bool {
+        self.changed_outputs.is_empty()
+            && self.changed_receipts.is_empty()
+            && self.gas_delta == 0
+    }
+}
+
+fn changed_indices<T: PartialEq>(left: &[T], right: &[T]) -> Vec<usize> {
+    let len = core::cmp::max(left.len(), right.len());
+    (0..len)
+        .filter(|&index| left.get(index) != right.get(index))
+        .collect()
+}
+
+fn script_gas_used(receipts: &[Receipt]) -> Word {
+    receipts
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+fn receipts_root(receipts: &[Receipt]) -> Bytes32 {
+    crate::crypto::ephemeral_merkle_root(receipts.iter().map(|r| r.to_bytes()))
+}
+
+fn created_contracts(outputs: &[Output]) -> Vec<(ContractId, Bytes32)> {
+    outputs
+        .iter()
+        .filter_map(|output| match output {
+            Output::ContractCreated {
+                contract_id,
+                state_root,
+            } => Some((*contract_id, *state_root)),
+            _ => None,
+        })
+        .collect()
+}
+
 impl<'a, Tx> From<&'a StateTransition<Tx>> for StateTransitionRef<'a, Tx> {
     fn from(t: &'a StateTransition<Tx>) -> StateTransitionRef<'a, Tx> {
         Self {
@@ -2,12 +2,17 @@ use fuel_asm::Instruction;
 use fuel_types::{
     ContractId,
     Word,
+    canonical::{
+        Deserialize,
+        Serialize,
+    },
 };
 
 use crate::consts::VM_MAX_RAM;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Deserialize, Serialize)]
 /// Breakpoint description that binds a tuple `(contract, $pc)` to a debugger
 /// implementation.
 ///
@@ -60,6 +65,7 @@ impl Breakpoint {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Deserialize, Serialize)]
 /// State evaluation of the interpreter that will describe if a program should
 /// break or continue.
 pub enum DebugEval {
@@ -68,6 +74,10 @@ pub enum DebugEval {
     Breakpoint(Breakpoint),
     /// This evaluation should not break the program.
     Continue,
+    /// The program was paused after a `LOG` or `LOGD` instruction, located by the
+    /// `Breakpoint`, produced a receipt matching the log filter of the debugger. The
+    /// receipt is the last one of the VM.
+    LogMatch(Breakpoint),
 }
 
 impl Default for DebugEval {
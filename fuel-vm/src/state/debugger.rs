@@ -4,24 +4,41 @@ use crate::state::{
     ProgramState,
 };
 
+use fuel_tx::Receipt;
 use fuel_types::{
     ContractId,
     Word,
 };
 
+use alloc::sync::Arc;
 use hashbrown::{
     HashMap,
     HashSet,
 };
 
+/// The filter of the `Log` and `LogData` receipts set with
+/// [`Debugger::set_log_filter`].
+#[derive(Clone)]
+struct LogFilter(Arc<dyn Fn(&Receipt) -> bool + Send + Sync>);
+
+impl core::fmt::Debug for LogFilter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("LogFilter")
+    }
+}
+
 /// Debugger implementation for the VM.
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Debugger {
     /// Debugger is active and used.
     is_active: bool,
     /// Single-stepping mode triggers a breakpoint after each instruction
     single_stepping: bool,
     breakpoints: HashMap<ContractId, HashSet<Word>>,
+    /// Pauses the execution after a `LOG` or `LOGD` producing a matching receipt.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    log_filter: Option<LogFilter>,
     last_state: Option<ProgramState>,
 }
 
@@ -74,6 +91,46 @@ impl Debugger {
             .map(|set| set.remove(&breakpoint.pc()));
     }
 
+    /// Pause the execution after each `LOG` or `LOGD` instruction whose receipt
+    /// matches the `filter`, replacing the previous filter.
+    pub fn set_log_filter<F>(&mut self, filter: F)
+    where
+        F: Fn(&Receipt) -> bool + Send + Sync + 'static,
+    {
+        self.is_active = true;
+        self.log_filter = Some(LogFilter(Arc::new(filter)));
+    }
+
+    /// Remove the log filter, if existent.
+    pub fn clear_log_filter(&mut self) {
+        self.log_filter = None;
+    }
+
+    /// Returns `true` if a log filter is set.
+    pub const fn has_log_filter(&self) -> bool {
+        self.log_filter.is_some()
+    }
+
+    /// Evaluate whether the `receipt` produced by the last executed instruction
+    /// matches the log filter.
+    ///
+    /// The returned [`DebugEval::LogMatch`] points at the `LOG` or `LOGD` instruction
+    /// that produced the receipt.
+    pub fn eval_receipt(&self, receipt: &Receipt) -> DebugEval {
+        let Some(LogFilter(filter)) = &self.log_filter else {
+            return DebugEval::Continue
+        };
+
+        match receipt {
+            Receipt::Log { id, pc, is, .. } | Receipt::LogData { id, pc, is, .. }
+                if filter(receipt) =>
+            {
+                DebugEval::LogMatch(Breakpoint::raw(*id, pc.saturating_sub(*is)))
+            }
+            _ => DebugEval::Continue,
+        }
+    }
+
     /// Evaluate the current state of the interpreter whether or not a
     /// breakpoint was reached.
     pub fn eval_state(&mut self, contract: Option<&ContractId>, pc: Word) -> DebugEval {
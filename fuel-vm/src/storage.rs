@@ -7,18 +7,137 @@ use fuel_types::{
     ContractId,
 };
 
+/// Delegates the storage traits of the `$table`s of the `$wrapper` to its `storage`
+/// field.
+macro_rules! delegate_to_storage {
+    ($wrapper:ident; $($table:ty),* $(,)?) => {$(
+        impl<S> StorageInspect<$table> for $wrapper<S>
+        where
+            S: StorageInspect<$table>,
+        {
+            type Error = <S as StorageInspect<$table>>::Error;
+
+            fn get(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<Cow<'_, <$table as Mappable>::OwnedValue>>, Self::Error>
+            {
+                <S as StorageInspect<$table>>::get(&self.storage, key)
+            }
+
+            fn contains_key(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<bool, Self::Error> {
+                <S as StorageInspect<$table>>::contains_key(&self.storage, key)
+            }
+        }
+
+        impl<S> StorageMutate<$table> for $wrapper<S>
+        where
+            S: StorageMutate<$table>,
+        {
+            fn replace(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+                value: &<$table as Mappable>::Value,
+            ) -> Result<Option<<$table as Mappable>::OwnedValue>, Self::Error> {
+                <S as StorageMutate<$table>>::replace(&mut self.storage, key, value)
+            }
+
+            fn take(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<<$table as Mappable>::OwnedValue>, Self::Error> {
+                <S as StorageMutate<$table>>::take(&mut self.storage, key)
+            }
+        }
+
+        impl<S> StorageSize<$table> for $wrapper<S>
+        where
+            S: StorageSize<$table>,
+        {
+            fn size_of_value(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<usize>, Self::Error> {
+                <S as StorageSize<$table>>::size_of_value(&self.storage, key)
+            }
+        }
+
+        impl<S> StorageRead<$table> for $wrapper<S>
+        where
+            S: StorageRead<$table>,
+        {
+            fn read(
+                &self,
+                key: &<$table as Mappable>::Key,
+                offset: usize,
+                buf: &mut [u8],
+            ) -> Result<bool, Self::Error> {
+                <S as StorageRead<$table>>::read(&self.storage, key, offset, buf)
+            }
+
+            fn read_alloc(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<Vec<u8>>, Self::Error> {
+                <S as StorageRead<$table>>::read_alloc(&self.storage, key)
+            }
+        }
+
+        impl<S> StorageWrite<$table> for $wrapper<S>
+        where
+            S: StorageWrite<$table>,
+        {
+            fn write_bytes(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+                buf: &[u8],
+            ) -> Result<(), Self::Error> {
+                <S as StorageWrite<$table>>::write_bytes(&mut self.storage, key, buf)
+            }
+
+            fn replace_bytes(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+                buf: &[u8],
+            ) -> Result<Option<Vec<u8>>, Self::Error> {
+                <S as StorageWrite<$table>>::replace_bytes(&mut self.storage, key, buf)
+            }
+
+            fn take_bytes(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<Vec<u8>>, Self::Error> {
+                <S as StorageWrite<$table>>::take_bytes(&mut self.storage, key)
+            }
+        }
+    )*};
+}
+
 mod blob_data;
+#[cfg(feature = "std")]
+mod contract_cache;
 mod contracts_assets;
 mod contracts_state;
 mod interpreter;
 #[cfg(feature = "test-helpers")]
 mod memory;
+mod observer;
+mod overlay;
 pub mod predicate;
+mod replay;
 
 pub use blob_data::{
     BlobBytes,
     BlobData,
 };
+#[cfg(feature = "std")]
+pub use contract_cache::{
+    CachedStorage,
+    SharedContractCache,
+};
 pub use contracts_assets::{
     ContractsAssetKey,
     ContractsAssets,
@@ -34,9 +153,20 @@ pub use interpreter::{
 };
 #[cfg(feature = "test-helpers")]
 pub use memory::{
+    BlockHashProvider,
     MemoryStorage,
     MemoryStorageError,
 };
+pub(crate) use observer::state_slot_keys;
+pub use observer::{
+    AccessSet,
+    StorageObserver,
+};
+pub use overlay::OverlayStorage;
+pub use replay::{
+    ReplayLog,
+    ReplayStorage,
+};
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
@@ -0,0 +1,518 @@
+use crate::storage::{
+    BlobData,
+    ContractsAssets,
+    ContractsRawCode,
+    ContractsState,
+    ContractsStateData,
+    InterpreterStorage,
+    UploadedBytecodes,
+    interpreter::ContractsAssetsStorage,
+};
+
+use fuel_storage::{
+    Mappable,
+    StorageInspect,
+    StorageMutate,
+    StorageRead,
+    StorageSize,
+    StorageWrite,
+};
+use fuel_tx::{
+    ConsensusParameters,
+    Contract,
+};
+use fuel_types::{
+    AssetId,
+    BlockHeight,
+    Bytes32,
+    ContractId,
+    Word,
+};
+
+use alloc::{
+    borrow::Cow,
+    sync::Arc,
+    vec::Vec,
+};
+use std::{
+    collections::HashMap,
+    sync::{
+        PoisonError,
+        RwLock,
+    },
+};
+
+/// The bytecode of contracts shared between storages, e.g. of `Transactor`s executing
+/// transactions in parallel, to load hot contracts from the backend only once.
+///
+/// Cloning the cache is cheap and the clones share the same entries. The cache is
+/// filled by [`CachedStorage`] on reads and an entry is invalidated whenever the
+/// bytecode of its contract is written through a [`CachedStorage`]. Changes to the
+/// backends that bypass the wrapper, e.g. reverting them, must be followed by
+/// [`SharedContractCache::invalidate`] or [`SharedContractCache::clear`].
+#[derive(Debug, Default, Clone)]
+pub struct SharedContractCache {
+    contracts: Arc<RwLock<HashMap<ContractId, Contract>>>,
+}
+
+impl SharedContractCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached bytecode of the `contract`, if any.
+    pub fn get(&self, contract: &ContractId) -> Option<Contract> {
+        self.contracts
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(contract)
+            .cloned()
+    }
+
+    /// Caches the `bytecode` of the `contract`.
+    pub fn insert(&self, contract: ContractId, bytecode: Contract) {
+        self.contracts
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(contract, bytecode);
+    }
+
+    /// Removes the cached bytecode of the `contract`, if any.
+    pub fn invalidate(&self, contract: &ContractId) {
+        self.contracts
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(contract);
+    }
+
+    /// Removes all the cached bytecode.
+    pub fn clear(&self) {
+        self.contracts
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Returns the number of cached contracts.
+    pub fn len(&self) -> usize {
+        self.contracts
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Returns `true` if no contract is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Loads the bytecode of a contract missing from the wrapped storage.
+type LoadContract = dyn Fn(&ContractId) -> Option<Contract> + Send + Sync;
+
+/// The fallback of the contracts bytecode set with
+/// [`CachedStorage::set_contract_loader`].
+#[derive(Clone)]
+struct ContractLoader(Arc<LoadContract>);
+
+impl core::fmt::Debug for ContractLoader {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("ContractLoader")
+    }
+}
+
+/// An [`InterpreterStorage`] wrapper consulting a [`SharedContractCache`] before
+/// reading the bytecode of contracts from the wrapped storage.
+///
+/// All the other tables are delegated to the wrapped storage as is.
+#[derive(Debug, Clone)]
+pub struct CachedStorage<S> {
+    storage: S,
+    cache: SharedContractCache,
+    loader: Option<ContractLoader>,
+}
+
+impl<S> CachedStorage<S> {
+    /// Wraps the `storage`, using the `cache` for the bytecode of contracts.
+    pub fn new(storage: S, cache: SharedContractCache) -> Self {
+        Self {
+            storage,
+            cache,
+            loader: None,
+        }
+    }
+
+    /// Loads the bytecode of the contracts missing from both the cache and the wrapped
+    /// storage with the `loader`, e.g. from the disk or the network, replacing the
+    /// previous loader.
+    ///
+    /// The loaded bytecode is added to the cache and is served as if it was deployed
+    /// in the wrapped storage. The `loader` is consulted again if the contract is
+    /// removed through the wrapper afterwards.
+    pub fn set_contract_loader<F>(&mut self, loader: F)
+    where
+        F: Fn(&ContractId) -> Option<Contract> + Send + Sync + 'static,
+    {
+        self.loader = Some(ContractLoader(Arc::new(loader)));
+    }
+
+    /// Removes the contract loader, if any.
+    pub fn remove_contract_loader(&mut self) {
+        self.loader = None;
+    }
+
+    /// Returns the bytecode of the `contract` from the loader, caching it.
+    fn load_contract(&self, contract: &ContractId) -> Option<Contract> {
+        let ContractLoader(loader) = self.loader.as_ref()?;
+        let bytecode = loader(contract)?;
+        self.cache.insert(*contract, bytecode.clone());
+        Some(bytecode)
+    }
+
+    /// Returns the cache of the contracts bytecode.
+    pub fn cache(&self) -> &SharedContractCache {
+        &self.cache
+    }
+
+    /// Returns the wrapped storage.
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Returns the wrapped storage mutably. The cache isn't invalidated by the
+    /// changes made through it.
+    pub fn storage_mut(&mut self) -> &mut S {
+        &mut self.storage
+    }
+
+    /// Returns the wrapped storage.
+    pub fn into_inner(self) -> S {
+        self.storage
+    }
+}
+
+impl<S> CachedStorage<S>
+where
+    S: StorageInspect<ContractsRawCode>,
+{
+    /// Returns the bytecode of the `contract`, loading it from the wrapped storage or
+    /// the contract loader and caching it on a miss.
+    fn cached_contract(
+        &self,
+        contract: &ContractId,
+    ) -> Result<Option<Contract>, S::Error> {
+        if let Some(bytecode) = self.cache.get(contract) {
+            return Ok(Some(bytecode))
+        }
+
+        let Some(bytecode) = self.storage.get(contract)?.map(Cow::into_owned) else {
+            return Ok(self.load_contract(contract))
+        };
+        self.cache.insert(*contract, bytecode.clone());
+        Ok(Some(bytecode))
+    }
+}
+
+impl<S> StorageInspect<ContractsRawCode> for CachedStorage<S>
+where
+    S: StorageInspect<ContractsRawCode>,
+{
+    type Error = S::Error;
+
+    fn get(&self, key: &ContractId) -> Result<Option<Cow<'_, Contract>>, Self::Error> {
+        Ok(self.cached_contract(key)?.map(Cow::Owned))
+    }
+
+    fn contains_key(&self, key: &ContractId) -> Result<bool, Self::Error> {
+        if self.cache.get(key).is_some() || self.storage.contains_key(key)? {
+            return Ok(true)
+        }
+        Ok(self.load_contract(key).is_some())
+    }
+}
+
+impl<S> StorageMutate<ContractsRawCode> for CachedStorage<S>
+where
+    S: StorageMutate<ContractsRawCode>,
+{
+    fn replace(
+        &mut self,
+        key: &ContractId,
+        value: &[u8],
+    ) -> Result<Option<Contract>, Self::Error> {
+        self.cache.invalidate(key);
+        self.storage.replace(key, value)
+    }
+
+    fn take(&mut self, key: &ContractId) -> Result<Option<Contract>, Self::Error> {
+        self.cache.invalidate(key);
+        self.storage.take(key)
+    }
+}
+
+impl<S> StorageSize<ContractsRawCode> for CachedStorage<S>
+where
+    S: StorageSize<ContractsRawCode>,
+{
+    fn size_of_value(&self, key: &ContractId) -> Result<Option<usize>, Self::Error> {
+        if let Some(bytecode) = self.cache.get(key) {
+            return Ok(Some(bytecode.as_ref().len()))
+        }
+        if let Some(size) = self.storage.size_of_value(key)? {
+            return Ok(Some(size))
+        }
+        Ok(self
+            .load_contract(key)
+            .map(|bytecode| bytecode.as_ref().len()))
+    }
+}
+
+impl<S> StorageRead<ContractsRawCode> for CachedStorage<S>
+where
+    S: StorageRead<ContractsRawCode>,
+{
+    fn read(
+        &self,
+        key: &ContractId,
+        offset: usize,
+        buf: &mut [u8],
+    ) -> Result<bool, Self::Error> {
+        let Some(bytecode) = self.cached_contract(key)? else {
+            return Ok(false)
+        };
+
+        let end = offset.saturating_add(buf.len());
+        match bytecode.as_ref().get(offset..end) {
+            Some(bytes) => {
+                buf.copy_from_slice(bytes);
+                Ok(true)
+            }
+            // Let the wrapped storage report reading past the end of the bytecode
+            None => self.storage.read(key, offset, buf),
+        }
+    }
+
+    fn read_alloc(&self, key: &ContractId) -> Result<Option<Vec<u8>>, Self::Error> {
+        Ok(self.cached_contract(key)?.map(Into::into))
+    }
+}
+
+impl<S> StorageWrite<ContractsRawCode> for CachedStorage<S>
+where
+    S: StorageWrite<ContractsRawCode>,
+{
+    fn write_bytes(&mut self, key: &ContractId, buf: &[u8]) -> Result<(), Self::Error> {
+        self.cache.invalidate(key);
+        self.storage.write_bytes(key, buf)
+    }
+
+    fn replace_bytes(
+        &mut self,
+        key: &ContractId,
+        buf: &[u8],
+    ) -> Result<Option<Vec<u8>>, Self::Error> {
+        self.cache.invalidate(key);
+        self.storage.replace_bytes(key, buf)
+    }
+
+    fn take_bytes(&mut self, key: &ContractId) -> Result<Option<Vec<u8>>, Self::Error> {
+        self.cache.invalidate(key);
+        self.storage.take_bytes(key)
+    }
+}
+
+delegate_to_storage!(
+    CachedStorage;
+    ContractsState,
+    ContractsAssets,
+    UploadedBytecodes,
+    BlobData,
+);
+
+impl<S: ContractsAssetsStorage> ContractsAssetsStorage for CachedStorage<S> {}
+
+impl<S> InterpreterStorage for CachedStorage<S>
+where
+    S: InterpreterStorage,
+{
+    type DataError = S::DataError;
+
+    fn block_height(&self) -> Result<BlockHeight, Self::DataError> {
+        self.storage.block_height()
+    }
+
+    fn consensus_parameters_version(&self) -> Result<u32, Self::DataError> {
+        self.storage.consensus_parameters_version()
+    }
+
+    fn state_transition_version(&self) -> Result<u32, Self::DataError> {
+        self.storage.state_transition_version()
+    }
+
+    fn timestamp(&self, height: BlockHeight) -> Result<Word, Self::DataError> {
+        self.storage.timestamp(height)
+    }
+
+    fn block_hash(&self, block_height: BlockHeight) -> Result<Bytes32, Self::DataError> {
+        self.storage.block_hash(block_height)
+    }
+
+    fn coinbase(&self) -> Result<ContractId, Self::DataError> {
+        self.storage.coinbase()
+    }
+
+    fn set_consensus_parameters(
+        &mut self,
+        version: u32,
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<Option<ConsensusParameters>, Self::DataError> {
+        self.storage
+            .set_consensus_parameters(version, consensus_parameters)
+    }
+
+    fn set_state_transition_bytecode(
+        &mut self,
+        version: u32,
+        hash: &Bytes32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        self.storage.set_state_transition_bytecode(version, hash)
+    }
+
+    fn contract_state_range(
+        &self,
+        id: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Vec<Option<Cow<'_, ContractsStateData>>>, Self::DataError> {
+        self.storage.contract_state_range(id, start_key, range)
+    }
+
+    fn merkle_contract_asset_balances(
+        &self,
+        contract: &ContractId,
+    ) -> Result<Vec<(AssetId, Word)>, Self::DataError> {
+        self.storage.merkle_contract_asset_balances(contract)
+    }
+
+    fn contract_state_insert_range<'a, I>(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        values: I,
+    ) -> Result<usize, Self::DataError>
+    where
+        I: Iterator<Item = &'a [u8]>,
+    {
+        self.storage
+            .contract_state_insert_range(contract, start_key, values)
+    }
+
+    fn contract_state_remove_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Option<()>, Self::DataError> {
+        self.storage
+            .contract_state_remove_range(contract, start_key, range)
+    }
+
+    #[cfg(feature = "frame-revert")]
+    fn enter_frame(&mut self, depth: usize) {
+        self.storage.enter_frame(depth)
+    }
+
+    #[cfg(feature = "frame-revert")]
+    fn revert_frame(&mut self, depth: usize) -> Result<bool, Self::DataError> {
+        self.storage.revert_frame(depth)
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(all(test, feature = "test-helpers"))]
+mod tests {
+    use super::*;
+    use crate::storage::MemoryStorage;
+
+    #[test]
+    fn cached_storage__reads_cached_bytecode_without_the_backend() {
+        // Given
+        let id = ContractId::from([1; 32]);
+        let bytecode = Contract::from(vec![1u8, 2, 3, 4]);
+        let mut backend = MemoryStorage::default();
+        backend.storage_contract_insert(&id, &bytecode).unwrap();
+        let cache = SharedContractCache::new();
+        let warm = CachedStorage::new(backend, cache.clone());
+        warm.storage_contract(&id).unwrap();
+
+        // When
+        let cold = CachedStorage::new(MemoryStorage::default(), cache.clone());
+        let mut buf = [0u8; 2];
+        let found = StorageRead::<ContractsRawCode>::read(&cold, &id, 2, &mut buf);
+
+        // Then
+        assert_eq!(found, Ok(true));
+        assert_eq!(buf, [3, 4]);
+        assert_eq!(cold.storage_contract_size(&id).unwrap(), Some(4));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn cached_storage__invalidates_the_cache_on_deploy() {
+        // Given
+        let contract_id = ContractId::from([1; 32]);
+        let cache = SharedContractCache::new();
+        let mut storage = CachedStorage::new(MemoryStorage::default(), cache.clone());
+        storage
+            .storage_contract_insert(&contract_id, &Contract::from(vec![1u8]))
+            .unwrap();
+        storage.storage_contract(&contract_id).unwrap();
+        assert!(!cache.is_empty());
+
+        // When
+        storage
+            .storage_contract_insert(&contract_id, &Contract::from(vec![2u8]))
+            .unwrap();
+
+        // Then
+        assert!(cache.is_empty());
+        let bytecode = storage.storage_contract(&contract_id).unwrap().unwrap();
+        assert_eq!(bytecode.into_owned(), Contract::from(vec![2u8]));
+    }
+
+    #[test]
+    fn cached_storage__loads_missing_contracts_once_with_the_loader() {
+        // Given
+        let contract_id = ContractId::from([1; 32]);
+        let loads = Arc::new(core::sync::atomic::AtomicUsize::new(0));
+        let cache = SharedContractCache::new();
+        let mut storage = CachedStorage::new(MemoryStorage::default(), cache.clone());
+        storage.set_contract_loader({
+            let loads = loads.clone();
+            move |id| {
+                loads.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+                (*id == contract_id).then(|| Contract::from(vec![1u8, 2, 3]))
+            }
+        });
+
+        // When
+        let exists = storage.storage_contract_exists(&contract_id).unwrap();
+        let size = storage.storage_contract_size(&contract_id).unwrap();
+        let bytecode = storage.storage_contract(&contract_id).unwrap().unwrap();
+        let missing = storage
+            .storage_contract_exists(&ContractId::from([2; 32]))
+            .unwrap();
+
+        // Then
+        assert!(exists);
+        assert_eq!(size, Some(3));
+        assert_eq!(bytecode.into_owned(), Contract::from(vec![1u8, 2, 3]));
+        assert!(!missing);
+        assert_eq!(loads.load(core::sync::atomic::Ordering::Relaxed), 2);
+        assert_eq!(
+            cache.get(&contract_id),
+            Some(Contract::from(vec![1u8, 2, 3]))
+        );
+    }
+}
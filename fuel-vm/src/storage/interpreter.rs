@@ -156,6 +156,11 @@ pub trait InterpreterStorage:
 
     /// Fetch the size of a previously inserted contract code from the chain state for a
     /// given contract.
+    ///
+    /// This is used by the VM whenever only the length of the bytecode is required,
+    /// e.g. for `CSIZ` and for gas charging of `CALL`, `LDC`, `CCP` and `CROO`.
+    /// Backends should override it if they can compute the size without
+    /// materializing the whole bytecode.
     fn storage_contract_size(
         &self,
         id: &ContractId,
@@ -188,6 +193,15 @@ pub trait InterpreterStorage:
         StorageInspect::<ContractsState>::get(self, &(id, key).into())
     }
 
+    /// Check if a key is set in a contract storage, without fetching its value.
+    fn contract_state_contains(
+        &self,
+        id: &ContractId,
+        key: &Bytes32,
+    ) -> Result<bool, Self::DataError> {
+        StorageInspect::<ContractsState>::contains_key(self, &(id, key).into())
+    }
+
     /// Insert a key-value mapping in a contract storage.
     fn contract_state_insert(
         &mut self,
@@ -227,6 +241,13 @@ pub trait InterpreterStorage:
         range: usize,
     ) -> Result<Vec<Option<Cow<ContractsStateData>>>, Self::DataError>;
 
+    /// Fetch all the assets held by the `contract` with a nonzero balance, sorted by
+    /// asset id.
+    fn merkle_contract_asset_balances(
+        &self,
+        contract: &ContractId,
+    ) -> Result<Vec<(AssetId, Word)>, Self::DataError>;
+
     /// Insert a range of key-value mappings into contract storage.
     /// Returns the number of keys that were previously unset but are now set.
     fn contract_state_insert_range<'a, I>(
@@ -246,6 +267,23 @@ pub trait InterpreterStorage:
         start_key: &Bytes32,
         range: usize,
     ) -> Result<Option<()>, Self::DataError>;
+
+    /// Called when the call frame at `depth`, i.e. the number of frames on the call
+    /// stack, is entered. The changes made from now on belong to this frame and can be
+    /// undone with [`InterpreterStorage::revert_frame`].
+    ///
+    /// Does nothing by default.
+    #[cfg(feature = "frame-revert")]
+    fn enter_frame(&mut self, _depth: usize) {}
+
+    /// Undoes the changes made since the call frame at `depth` was entered.
+    ///
+    /// Returns `false` if the storage doesn't track the changes per frame, which is the
+    /// default.
+    #[cfg(feature = "frame-revert")]
+    fn revert_frame(&mut self, _depth: usize) -> Result<bool, Self::DataError> {
+        Ok(false)
+    }
 }
 
 /// Storage operations for contract assets.
@@ -371,6 +409,13 @@ where
         )
     }
 
+    fn merkle_contract_asset_balances(
+        &self,
+        contract: &ContractId,
+    ) -> Result<Vec<(AssetId, Word)>, Self::DataError> {
+        <S as InterpreterStorage>::merkle_contract_asset_balances(self.deref(), contract)
+    }
+
     fn contract_state_insert_range<'a, I>(
         &mut self,
         contract: &ContractId,
@@ -401,4 +446,14 @@ where
             range,
         )
     }
+
+    #[cfg(feature = "frame-revert")]
+    fn enter_frame(&mut self, depth: usize) {
+        <S as InterpreterStorage>::enter_frame(self.deref_mut(), depth)
+    }
+
+    #[cfg(feature = "frame-revert")]
+    fn revert_frame(&mut self, depth: usize) -> Result<bool, Self::DataError> {
+        <S as InterpreterStorage>::revert_frame(self.deref_mut(), depth)
+    }
 }
@@ -34,13 +34,13 @@ use fuel_tx::{
     Contract,
 };
 use fuel_types::{
+    AssetId,
     BlobId,
     BlockHeight,
     Bytes32,
     ContractId,
     Word,
 };
-use tai64::Tai64;
 
 use super::{
     BlobBytes,
@@ -51,9 +51,17 @@ use crate::storage::predicate::PredicateStorageRequirements;
 use alloc::{
     borrow::Cow,
     collections::BTreeMap,
+    sync::Arc,
     vec::Vec,
 };
 
+/// Provides the hash of the block at a given height, e.g. to replay historical
+/// transactions with the authentic values returned by `BHSH`.
+pub trait BlockHashProvider: core::fmt::Debug + Send + Sync {
+    /// Returns the hash of the block at `block_height`.
+    fn block_hash(&self, block_height: BlockHeight) -> Bytes32;
+}
+
 /// Errors arising from accessing the memory storage.
 #[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
 pub enum MemoryStorageError {
@@ -101,6 +109,7 @@ pub struct MemoryStorage {
     coinbase: ContractId,
     consensus_parameters_version: u32,
     state_transition_version: u32,
+    block_hash_provider: Option<Arc<dyn BlockHashProvider>>,
     memory: MemoryStorageInner,
     transacted: MemoryStorageInner,
     persisted: MemoryStorageInner,
@@ -124,19 +133,28 @@ impl MemoryStorage {
             coinbase,
             consensus_parameters_version,
             state_transition_version,
+            block_hash_provider: None,
             memory: Default::default(),
             transacted: Default::default(),
             persisted: Default::default(),
         }
     }
 
-    /// Iterate over all contract state in storage
+    /// Iterate over all contract state in storage, in ascending order of the keys.
+    ///
+    /// The order only depends on the stored entries, not on the order they were
+    /// inserted in, so any root computed from this iterator is deterministic.
     pub fn all_contract_state(
         &self,
     ) -> impl Iterator<Item = (&ContractsStateKey, &ContractsStateData)> {
         self.memory.contract_state.iter()
     }
 
+    /// The ids of all deployed contracts, in ascending order.
+    pub fn contract_ids(&self) -> Vec<ContractId> {
+        self.memory.contracts.keys().copied().collect()
+    }
+
     /// Fetch a mapping from the contract state.
     pub fn contract_state(
         &self,
@@ -149,6 +167,16 @@ impl MemoryStorage {
             .unwrap_or(Cow::Owned(ContractsStateData::default()))
     }
 
+    /// Sets the provider of the block hashes returned by
+    /// [`InterpreterStorage::block_hash`]. `None` restores the default, where the hash
+    /// is derived from the block height.
+    pub fn set_block_hash_provider(
+        &mut self,
+        block_hash_provider: Option<Arc<dyn BlockHashProvider>>,
+    ) {
+        self.block_hash_provider = block_hash_provider;
+    }
+
     /// Set the transacted state to the memory state.
     pub fn commit(&mut self) {
         self.transacted = self.memory.clone();
@@ -171,6 +199,24 @@ impl MemoryStorage {
         self.persisted = self.transacted.clone();
     }
 
+    #[cfg(feature = "test-helpers")]
+    /// The merkle root of the state of the `contract`.
+    ///
+    /// The leaves are the state keys followed by their values, in ascending order of
+    /// the keys. Same as the [`fuel_storage::MerkleRootStorage`] implementation, but
+    /// doesn't require the trait to be in scope.
+    pub fn contract_state_root(&self, contract: &ContractId) -> Bytes32 {
+        let start: ContractsStateKey = (contract, &Bytes32::zeroed()).into();
+        let leaves = self
+            .memory
+            .contract_state
+            .range(start..)
+            .take_while(|(key, _)| key.contract_id() == contract)
+            .map(|(key, value)| [key.state_key().as_ref(), value.as_ref()].concat())
+            .collect::<Vec<_>>();
+        crate::crypto::ephemeral_merkle_root(leaves.iter())
+    }
+
     #[cfg(feature = "test-helpers")]
     /// Set the block height of the chain
     pub fn set_block_height(&mut self, block_height: BlockHeight) {
@@ -217,6 +263,16 @@ impl MemoryStorage {
     }
 }
 
+#[cfg(feature = "test-helpers")]
+impl fuel_storage::MerkleRootStorage<ContractId, ContractsState> for MemoryStorage {
+    fn root(
+        &self,
+        contract: &ContractId,
+    ) -> Result<fuel_storage::MerkleRoot, Self::Error> {
+        Ok(*self.contract_state_root(contract))
+    }
+}
+
 impl Default for MemoryStorage {
     fn default() -> Self {
         let block_height = 1.into();
@@ -649,14 +705,18 @@ impl InterpreterStorage for MemoryStorage {
 
     #[allow(clippy::arithmetic_side_effects)] // Safety: not enough bits to overflow
     fn timestamp(&self, height: BlockHeight) -> Result<Word, Self::DataError> {
-        const GENESIS: Tai64 = Tai64::UNIX_EPOCH;
+        const GENESIS: Word = (1 << 62) + 10;
         const INTERVAL: Word = 10;
 
-        Ok((GENESIS + (*height as Word * INTERVAL)).0)
+        Ok(GENESIS + (*height as Word * INTERVAL))
     }
 
     fn block_hash(&self, block_height: BlockHeight) -> Result<Bytes32, Self::DataError> {
-        Ok(Hasher::hash(block_height.to_be_bytes()))
+        let block_hash = match &self.block_hash_provider {
+            Some(provider) => provider.block_hash(block_height),
+            None => Hasher::hash(block_height.to_be_bytes()),
+        };
+        Ok(block_hash)
     }
 
     fn coinbase(&self) -> Result<ContractId, Self::DataError> {
@@ -718,6 +778,21 @@ impl InterpreterStorage for MemoryStorage {
         .collect())
     }
 
+    fn merkle_contract_asset_balances(
+        &self,
+        contract: &ContractId,
+    ) -> Result<Vec<(AssetId, Word)>, Self::DataError> {
+        let start: ContractsAssetKey = (contract, &AssetId::zeroed()).into();
+        Ok(self
+            .memory
+            .balances
+            .range(start..)
+            .take_while(|(key, _)| key.contract_id() == contract)
+            .filter(|(_, balance)| **balance != 0)
+            .map(|(key, balance)| (*key.asset_id(), *balance))
+            .collect())
+    }
+
     fn contract_state_insert_range<'a, I>(
         &mut self,
         contract: &ContractId,
@@ -793,6 +868,7 @@ fn add_one(a: &mut [u8; 32]) -> bool {
 }
 
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod tests {
     use super::*;
     use alloc::vec;
@@ -877,4 +953,126 @@ mod tests {
 
         r
     }
+
+    #[test]
+    fn all_contract_state__root_is_independent_of_insertion_order() {
+        let contract = ContractId::new(key(7));
+        let entries = [
+            (key(3), vec![3u8; 32]),
+            (key(1), vec![1; 8]),
+            (key(2), vec![]),
+        ];
+        let root_of = |order: &[usize]| {
+            let mut mem = MemoryStorage::default();
+            for &i in order {
+                let (slot, value) = &entries[i];
+                mem.contract_state_insert(&contract, &(*slot).into(), value)
+                    .unwrap();
+            }
+            let leaves = mem
+                .all_contract_state()
+                .map(|(key, value)| [key.as_ref(), value.as_ref()].concat())
+                .collect::<Vec<_>>();
+            crate::crypto::ephemeral_merkle_root(leaves.iter())
+        };
+
+        // Given
+        let orders: [&[usize]; 3] = [&[0, 1, 2], &[2, 1, 0], &[1, 0, 2]];
+
+        // When
+        let roots = orders.map(root_of);
+
+        // Then
+        assert_eq!(roots[0], roots[1]);
+        assert_eq!(roots[0], roots[2]);
+    }
+
+    #[test]
+    fn merkle_contract_asset_balances__lists_the_nonzero_balances_of_the_contract() {
+        // Given
+        let mut mem = MemoryStorage::default();
+        let contract = ContractId::new(key(1));
+        let other_contract = ContractId::new(key(2));
+        for (asset, balance) in [(key(5), 50), (key(3), 30), (key(4), 0)] {
+            mem.contract_asset_id_balance_insert(&contract, &asset.into(), balance)
+                .unwrap();
+        }
+        mem.contract_asset_id_balance_insert(&other_contract, &key(6).into(), 60)
+            .unwrap();
+
+        // When
+        let balances = mem.merkle_contract_asset_balances(&contract);
+
+        // Then
+        assert_eq!(balances, Ok(vec![(key(3).into(), 30), (key(5).into(), 50)]));
+    }
+
+    #[cfg(feature = "test-helpers")]
+    #[test]
+    fn contract_state_root__matches_the_merkle_root_storage() {
+        use fuel_storage::MerkleRootStorage;
+
+        // Given
+        let mut mem = MemoryStorage::default();
+        let contract = ContractId::new(key(1));
+        let other_contract = ContractId::new(key(2));
+        mem.contract_state_insert(&contract, &key(3).into(), &[3; 32])
+            .unwrap();
+        mem.contract_state_insert(&contract, &key(4).into(), &[4; 8])
+            .unwrap();
+        mem.contract_state_insert(&other_contract, &key(5).into(), &[5; 32])
+            .unwrap();
+
+        // When
+        let root = mem.contract_state_root(&contract);
+
+        // Then
+        let leaves = [
+            [key(3).to_vec(), vec![3; 32]].concat(),
+            [key(4).to_vec(), vec![4; 8]].concat(),
+        ];
+        assert_eq!(root, crate::crypto::ephemeral_merkle_root(leaves.iter()));
+        assert_eq!(
+            MerkleRootStorage::<ContractId, ContractsState>::root(&mem, &contract),
+            Ok(*root)
+        );
+        assert_ne!(mem.contract_state_root(&other_contract), root);
+    }
+
+    #[test]
+    fn contract_state_contains__reports_only_the_set_keys() {
+        // Given
+        let mut mem = MemoryStorage::default();
+        let contract = ContractId::new(key(1));
+        mem.contract_state_insert(&contract, &key(2).into(), &[])
+            .unwrap();
+
+        // When
+        let set = mem.contract_state_contains(&contract, &key(2).into());
+        let unset = mem.contract_state_contains(&contract, &key(3).into());
+        let other_contract =
+            mem.contract_state_contains(&ContractId::new(key(4)), &key(2).into());
+
+        // Then
+        assert_eq!(set, Ok(true));
+        assert_eq!(unset, Ok(false));
+        assert_eq!(other_contract, Ok(false));
+    }
+
+    #[test]
+    fn contract_ids_lists_all_deployed_contracts() {
+        // Given
+        let mut mem = MemoryStorage::default();
+        let contract = Contract::from([1u8; 32].as_ref());
+        let ids = [ContractId::new(key(2)), ContractId::new(key(1))];
+        for id in ids {
+            mem.storage_contract_insert(&id, &contract).unwrap();
+        }
+
+        // When
+        let contract_ids = mem.contract_ids();
+
+        // Then
+        assert_eq!(contract_ids, vec![ids[1], ids[0]]);
+    }
 }
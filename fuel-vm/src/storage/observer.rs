@@ -0,0 +1,54 @@
+use alloc::collections::BTreeSet;
+use fuel_types::{
+    Bytes32,
+    ContractId,
+};
+
+/// Observes the contract state slots read and written by the VM.
+///
+/// Useful to build a trace of the storage accesses of a transaction, e.g. to warm
+/// caches or to detect conflicts between transactions executed in parallel. The
+/// methods are called once per slot, after the instruction accessing the storage
+/// succeeded.
+pub trait StorageObserver: core::fmt::Debug + Send + Sync {
+    /// Called when the `key` slot of the `contract` state is read.
+    fn on_read(&self, contract: &ContractId, key: &Bytes32);
+
+    /// Called when the `key` slot of the `contract` state is written. The `value` is
+    /// `None` if the slot was cleared.
+    fn on_write(&self, contract: &ContractId, key: &Bytes32, value: Option<&[u8]>);
+}
+
+/// The contract state slots read and written by a transaction.
+///
+/// Aggregated version of the [`StorageObserver`] notifications, collected by the VM
+/// when enabled with `set_access_set_enabled`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccessSet {
+    /// The slots read by `SRW` and `SRWQ`.
+    pub reads: BTreeSet<(ContractId, Bytes32)>,
+    /// The slots written or cleared by `SWW`, `SWWQ` and `SCWQ`.
+    pub writes: BTreeSet<(ContractId, Bytes32)>,
+}
+
+impl AccessSet {
+    /// Removes all the recorded slots.
+    pub fn clear(&mut self) {
+        self.reads.clear();
+        self.writes.clear();
+    }
+}
+
+/// Returns the keys of `num_slots` consecutive slots starting at `start_key`, in the
+/// same order as the range operations of the storage.
+pub(crate) fn state_slot_keys(
+    start_key: &Bytes32,
+    num_slots: usize,
+) -> impl Iterator<Item = Bytes32> {
+    let start_key = ethnum::U256::from_be_bytes(**start_key);
+    (0..num_slots).map(move |i| {
+        let offset = ethnum::U256::from(i as u128);
+        Bytes32::new(start_key.wrapping_add(offset).to_be_bytes())
+    })
+}
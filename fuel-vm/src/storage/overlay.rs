@@ -0,0 +1,454 @@
+use crate::storage::{
+    BlobData,
+    ContractsAssetKey,
+    ContractsAssets,
+    ContractsRawCode,
+    ContractsState,
+    ContractsStateData,
+    InterpreterStorage,
+    UploadedBytecodes,
+    interpreter::ContractsAssetsStorage,
+    state_slot_keys,
+};
+
+use fuel_storage::{
+    Mappable,
+    StorageInspect,
+    StorageMutate,
+    StorageRead,
+    StorageSize,
+    StorageWrite,
+};
+use fuel_tx::ConsensusParameters;
+use fuel_types::{
+    AssetId,
+    BlockHeight,
+    Bytes32,
+    ContractId,
+    Word,
+};
+
+use alloc::{
+    borrow::{
+        Cow,
+        ToOwned,
+    },
+    collections::BTreeMap,
+    vec::Vec,
+};
+
+/// The values written to a table through an [`OverlayStorage`]. `None` marks a removed
+/// value.
+type Changes<Type> =
+    BTreeMap<<Type as Mappable>::OwnedKey, Option<<Type as Mappable>::OwnedValue>>;
+
+/// Storage keeping the changes made by the VM in memory, on top of a read-only
+/// reference to another storage.
+///
+/// The reads see the changes made through the overlay, like with the wrapped storage,
+/// but the wrapped storage is never written to. The changes are discarded when the
+/// overlay is dropped, which makes it suitable to simulate transactions, see
+/// [`crate::transactor::Transactor::simulate`].
+///
+/// New consensus parameters and state transition bytecodes are only compared to the
+/// ones set through the overlay, since the wrapped storage can't be queried for them.
+#[derive(Debug)]
+pub struct OverlayStorage<'a, S> {
+    storage: &'a S,
+    contracts_raw_code: Changes<ContractsRawCode>,
+    contracts_state: Changes<ContractsState>,
+    contracts_assets: Changes<ContractsAssets>,
+    uploaded_bytecodes: Changes<UploadedBytecodes>,
+    blob_data: Changes<BlobData>,
+    consensus_parameters: BTreeMap<u32, ConsensusParameters>,
+    state_transition_bytecodes: BTreeMap<u32, Bytes32>,
+}
+
+impl<'a, S> OverlayStorage<'a, S> {
+    /// Creates an overlay without changes on top of the `storage`.
+    pub fn new(storage: &'a S) -> Self {
+        Self {
+            storage,
+            contracts_raw_code: BTreeMap::new(),
+            contracts_state: BTreeMap::new(),
+            contracts_assets: BTreeMap::new(),
+            uploaded_bytecodes: BTreeMap::new(),
+            blob_data: BTreeMap::new(),
+            consensus_parameters: BTreeMap::new(),
+            state_transition_bytecodes: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the wrapped storage.
+    pub fn storage(&self) -> &'a S {
+        self.storage
+    }
+
+    /// Returns `true` if no change was made through the overlay.
+    pub fn is_empty(&self) -> bool {
+        self.contracts_raw_code.is_empty()
+            && self.contracts_state.is_empty()
+            && self.contracts_assets.is_empty()
+            && self.uploaded_bytecodes.is_empty()
+            && self.blob_data.is_empty()
+            && self.consensus_parameters.is_empty()
+            && self.state_transition_bytecodes.is_empty()
+    }
+}
+
+/// A table whose changes are kept by the [`OverlayStorage`].
+trait OverlayTable: Mappable {
+    fn changes<'o, S>(overlay: &'o OverlayStorage<'_, S>) -> &'o Changes<Self>;
+
+    fn changes_mut<'o, S>(
+        overlay: &'o mut OverlayStorage<'_, S>,
+    ) -> &'o mut Changes<Self>;
+}
+
+macro_rules! overlay_table {
+    ($($table:ty => $field:ident),* $(,)?) => {$(
+        impl OverlayTable for $table {
+            fn changes<'o, S>(overlay: &'o OverlayStorage<'_, S>) -> &'o Changes<Self> {
+                &overlay.$field
+            }
+
+            fn changes_mut<'o, S>(
+                overlay: &'o mut OverlayStorage<'_, S>,
+            ) -> &'o mut Changes<Self> {
+                &mut overlay.$field
+            }
+        }
+    )*};
+}
+
+overlay_table!(
+    ContractsRawCode => contracts_raw_code,
+    ContractsState => contracts_state,
+    ContractsAssets => contracts_assets,
+    UploadedBytecodes => uploaded_bytecodes,
+    BlobData => blob_data,
+);
+
+fn owned_key<Type: Mappable>(key: &Type::Key) -> Type::OwnedKey {
+    Type::OwnedKey::from(key.to_owned())
+}
+
+impl<Type, S> StorageInspect<Type> for OverlayStorage<'_, S>
+where
+    Type: OverlayTable,
+    Type::OwnedKey: Ord + 'static,
+    Type::OwnedValue: 'static,
+    S: StorageInspect<Type>,
+{
+    type Error = S::Error;
+
+    fn get(
+        &self,
+        key: &Type::Key,
+    ) -> Result<Option<Cow<'_, Type::OwnedValue>>, S::Error> {
+        match Type::changes(self).get(&owned_key::<Type>(key)) {
+            Some(value) => Ok(value.as_ref().map(Cow::Borrowed)),
+            None => self.storage.get(key),
+        }
+    }
+
+    fn contains_key(&self, key: &Type::Key) -> Result<bool, S::Error> {
+        match Type::changes(self).get(&owned_key::<Type>(key)) {
+            Some(value) => Ok(value.is_some()),
+            None => self.storage.contains_key(key),
+        }
+    }
+}
+
+impl<Type, S> StorageMutate<Type> for OverlayStorage<'_, S>
+where
+    Type: OverlayTable,
+    Type::OwnedKey: Ord + 'static,
+    Type::OwnedValue: 'static,
+    S: StorageInspect<Type>,
+{
+    fn replace(
+        &mut self,
+        key: &Type::Key,
+        value: &Type::Value,
+    ) -> Result<Option<Type::OwnedValue>, S::Error> {
+        let existing = self.get(key)?.map(Cow::into_owned);
+        let value = Type::OwnedValue::from(value.to_owned());
+        Type::changes_mut(self).insert(owned_key::<Type>(key), Some(value));
+        Ok(existing)
+    }
+
+    fn take(&mut self, key: &Type::Key) -> Result<Option<Type::OwnedValue>, S::Error> {
+        let existing = self.get(key)?.map(Cow::into_owned);
+        Type::changes_mut(self).insert(owned_key::<Type>(key), None);
+        Ok(existing)
+    }
+}
+
+impl<Type, S> StorageSize<Type> for OverlayStorage<'_, S>
+where
+    Type: OverlayTable,
+    Type::OwnedKey: Ord + 'static,
+    Type::OwnedValue: AsRef<[u8]> + 'static,
+    S: StorageSize<Type>,
+{
+    fn size_of_value(&self, key: &Type::Key) -> Result<Option<usize>, S::Error> {
+        match Type::changes(self).get(&owned_key::<Type>(key)) {
+            Some(value) => Ok(value.as_ref().map(|value| value.as_ref().len())),
+            None => self.storage.size_of_value(key),
+        }
+    }
+}
+
+impl<Type, S> StorageRead<Type> for OverlayStorage<'_, S>
+where
+    Type: OverlayTable,
+    Type::OwnedKey: Ord + 'static,
+    Type::OwnedValue: AsRef<[u8]> + 'static,
+    S: StorageRead<Type>,
+{
+    fn read(
+        &self,
+        key: &Type::Key,
+        offset: usize,
+        buf: &mut [u8],
+    ) -> Result<bool, S::Error> {
+        let Some(value) = Type::changes(self).get(&owned_key::<Type>(key)) else {
+            return self.storage.read(key, offset, buf)
+        };
+        let Some(value) = value else { return Ok(false) };
+
+        let end = offset.saturating_add(buf.len());
+        match value.as_ref().get(offset..end) {
+            Some(bytes) => {
+                buf.copy_from_slice(bytes);
+                Ok(true)
+            }
+            // Let the wrapped storage report reading past the end of the value
+            None => self.storage.read(key, offset, buf),
+        }
+    }
+
+    fn read_alloc(&self, key: &Type::Key) -> Result<Option<Vec<u8>>, S::Error> {
+        match Type::changes(self).get(&owned_key::<Type>(key)) {
+            Some(value) => Ok(value.as_ref().map(|value| value.as_ref().to_vec())),
+            None => self.storage.read_alloc(key),
+        }
+    }
+}
+
+impl<Type, S> StorageWrite<Type> for OverlayStorage<'_, S>
+where
+    Type: OverlayTable<Value = [u8]>,
+    Type::OwnedKey: Ord + 'static,
+    Type::OwnedValue: AsRef<[u8]> + 'static,
+    S: StorageInspect<Type>,
+{
+    fn write_bytes(&mut self, key: &Type::Key, buf: &[u8]) -> Result<(), S::Error> {
+        Type::changes_mut(self).insert(owned_key::<Type>(key), Some(buf.to_vec().into()));
+        Ok(())
+    }
+
+    fn replace_bytes(
+        &mut self,
+        key: &Type::Key,
+        buf: &[u8],
+    ) -> Result<Option<Vec<u8>>, S::Error> {
+        let existing = self.get(key)?.map(|value| value.as_ref().as_ref().to_vec());
+        self.write_bytes(key, buf)?;
+        Ok(existing)
+    }
+
+    fn take_bytes(&mut self, key: &Type::Key) -> Result<Option<Vec<u8>>, S::Error> {
+        let existing = self.take(key)?;
+        Ok(existing.map(|value| value.as_ref().to_vec()))
+    }
+}
+
+impl<S> ContractsAssetsStorage for OverlayStorage<'_, S> where
+    S: StorageInspect<ContractsAssets>
+{
+}
+
+impl<S> InterpreterStorage for OverlayStorage<'_, S>
+where
+    S: InterpreterStorage,
+{
+    type DataError = S::DataError;
+
+    fn block_height(&self) -> Result<BlockHeight, Self::DataError> {
+        self.storage.block_height()
+    }
+
+    fn consensus_parameters_version(&self) -> Result<u32, Self::DataError> {
+        self.storage.consensus_parameters_version()
+    }
+
+    fn state_transition_version(&self) -> Result<u32, Self::DataError> {
+        self.storage.state_transition_version()
+    }
+
+    fn timestamp(&self, height: BlockHeight) -> Result<Word, Self::DataError> {
+        self.storage.timestamp(height)
+    }
+
+    fn block_hash(&self, block_height: BlockHeight) -> Result<Bytes32, Self::DataError> {
+        self.storage.block_hash(block_height)
+    }
+
+    fn coinbase(&self) -> Result<ContractId, Self::DataError> {
+        self.storage.coinbase()
+    }
+
+    fn set_consensus_parameters(
+        &mut self,
+        version: u32,
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<Option<ConsensusParameters>, Self::DataError> {
+        Ok(self
+            .consensus_parameters
+            .insert(version, consensus_parameters.clone()))
+    }
+
+    fn set_state_transition_bytecode(
+        &mut self,
+        version: u32,
+        hash: &Bytes32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        Ok(self.state_transition_bytecodes.insert(version, *hash))
+    }
+
+    fn contract_state_range(
+        &self,
+        id: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Vec<Option<Cow<'_, ContractsStateData>>>, Self::DataError> {
+        let values = self.storage.contract_state_range(id, start_key, range)?;
+        Ok(state_slot_keys(start_key, range)
+            .zip(values)
+            .map(
+                |(key, value)| match self.contracts_state.get(&(id, &key).into()) {
+                    Some(changed) => changed.as_ref().map(Cow::Borrowed),
+                    None => value,
+                },
+            )
+            .collect())
+    }
+
+    fn merkle_contract_asset_balances(
+        &self,
+        contract: &ContractId,
+    ) -> Result<Vec<(AssetId, Word)>, Self::DataError> {
+        let mut balances: BTreeMap<AssetId, Word> = self
+            .storage
+            .merkle_contract_asset_balances(contract)?
+            .into_iter()
+            .collect();
+        let start: ContractsAssetKey = (contract, &AssetId::zeroed()).into();
+        for (key, balance) in self
+            .contracts_assets
+            .range(start..)
+            .take_while(|(key, _)| key.contract_id() == contract)
+        {
+            balances.insert(*key.asset_id(), balance.unwrap_or_default());
+        }
+        Ok(balances
+            .into_iter()
+            .filter(|(_, balance)| *balance != 0)
+            .collect())
+    }
+
+    fn contract_state_insert_range<'b, I>(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        values: I,
+    ) -> Result<usize, Self::DataError>
+    where
+        I: Iterator<Item = &'b [u8]>,
+    {
+        let mut unset_count: usize = 0;
+        for (key, value) in state_slot_keys(start_key, usize::MAX).zip(values) {
+            let key = (contract, &key).into();
+            if !StorageInspect::<ContractsState>::contains_key(self, &key)? {
+                unset_count = unset_count.saturating_add(1);
+            }
+            StorageWrite::<ContractsState>::write_bytes(self, &key, value)?;
+        }
+        Ok(unset_count)
+    }
+
+    fn contract_state_remove_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Option<()>, Self::DataError> {
+        let mut all_set = true;
+        for key in state_slot_keys(start_key, range) {
+            let key = (contract, &key).into();
+            let removed = StorageMutate::<ContractsState>::take(self, &key)?;
+            all_set &= removed.is_some();
+        }
+        Ok(all_set.then_some(()))
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(all(test, feature = "test-helpers"))]
+mod tests {
+    use super::*;
+    use crate::storage::MemoryStorage;
+    use alloc::vec;
+    use fuel_tx::Contract;
+
+    #[test]
+    fn overlay_storage__reads_its_changes_without_writing_the_storage() {
+        // Given
+        let contract = ContractId::from([1; 32]);
+        let key = Bytes32::from([2; 32]);
+        let mut storage = MemoryStorage::default();
+        storage
+            .contract_state_insert(&contract, &key, &[1; 32])
+            .unwrap();
+        storage
+            .storage_contract_insert(&contract, &Contract::from(vec![1u8]))
+            .unwrap();
+        let mut overlay = OverlayStorage::new(&storage);
+
+        // When
+        overlay
+            .contract_state_insert_range(&contract, &key, [&[2u8; 32][..]].into_iter())
+            .unwrap();
+        overlay
+            .contract_asset_id_balance_insert(&contract, &AssetId::zeroed(), 5)
+            .unwrap();
+        StorageMutate::<ContractsRawCode>::remove(&mut overlay, &contract).unwrap();
+
+        // Then
+        let slots = overlay.contract_state_range(&contract, &key, 1).unwrap();
+        assert_eq!(
+            slots[0].as_deref(),
+            Some(&ContractsStateData::from(&[2u8; 32][..]))
+        );
+        assert_eq!(
+            overlay.merkle_contract_asset_balances(&contract).unwrap(),
+            vec![(AssetId::zeroed(), 5)]
+        );
+        assert!(!overlay.storage_contract_exists(&contract).unwrap());
+
+        let slots = storage.contract_state_range(&contract, &key, 1).unwrap();
+        assert_eq!(
+            slots[0].as_deref(),
+            Some(&ContractsStateData::from(&[1u8; 32][..]))
+        );
+        assert!(
+            storage
+                .merkle_contract_asset_balances(&contract)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(storage.storage_contract_exists(&contract).unwrap());
+    }
+}
@@ -25,6 +25,7 @@ use fuel_storage::{
 };
 use fuel_tx::ConsensusParameters;
 use fuel_types::{
+    AssetId,
     BlobId,
     BlockHeight,
     Bytes32,
@@ -162,6 +163,86 @@ impl PredicateStorageProvider for EmptyStorage {
     }
 }
 
+/// Read-only view over an [`InterpreterStorage`], used to check or estimate predicates
+/// against the live state.
+///
+/// Only the reads available to predicates are forwarded to the underlying storage.
+/// Predicates can't mutate the storage, so any write attempted during the execution
+/// fails with [`PredicateStorageError::UnsupportedStorageOperation`].
+#[derive(Debug)]
+pub struct ReadOnlyStorage<'a, S> {
+    storage: &'a S,
+}
+
+impl<'a, S> ReadOnlyStorage<'a, S> {
+    /// Creates a read-only view over the `storage`.
+    pub fn new(storage: &'a S) -> Self {
+        Self { storage }
+    }
+}
+
+impl<S> Clone for ReadOnlyStorage<'_, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for ReadOnlyStorage<'_, S> {}
+
+impl<S> StorageInspect<BlobData> for ReadOnlyStorage<'_, S>
+where
+    S: InterpreterStorage,
+{
+    type Error = S::DataError;
+
+    fn get(
+        &self,
+        key: &BlobId,
+    ) -> Result<Option<Cow<<BlobData as Mappable>::OwnedValue>>, Self::Error> {
+        StorageInspect::<BlobData>::get(self.storage, key)
+    }
+
+    fn contains_key(&self, key: &BlobId) -> Result<bool, Self::Error> {
+        StorageInspect::<BlobData>::contains_key(self.storage, key)
+    }
+}
+
+impl<S> StorageSize<BlobData> for ReadOnlyStorage<'_, S>
+where
+    S: InterpreterStorage,
+{
+    fn size_of_value(&self, key: &BlobId) -> Result<Option<usize>, Self::Error> {
+        StorageSize::<BlobData>::size_of_value(self.storage, key)
+    }
+}
+
+impl<S> StorageRead<BlobData> for ReadOnlyStorage<'_, S>
+where
+    S: InterpreterStorage,
+{
+    fn read(
+        &self,
+        key: &BlobId,
+        offset: usize,
+        buf: &mut [u8],
+    ) -> Result<bool, Self::Error> {
+        StorageRead::<BlobData>::read(self.storage, key, offset, buf)
+    }
+
+    fn read_alloc(&self, key: &BlobId) -> Result<Option<Vec<u8>>, Self::Error> {
+        StorageRead::<BlobData>::read_alloc(self.storage, key)
+    }
+}
+
+impl<S> PredicateStorageRequirements for ReadOnlyStorage<'_, S>
+where
+    S: InterpreterStorage,
+{
+    fn storage_error_to_string(error: Self::Error) -> String {
+        alloc::format!("{:?}", error)
+    }
+}
+
 trait NoStorage {}
 
 impl NoStorage for ContractsState {}
@@ -453,6 +534,13 @@ where
         Err(Self::DataError::UnsupportedStorageOperation)
     }
 
+    fn merkle_contract_asset_balances(
+        &self,
+        _contract: &ContractId,
+    ) -> Result<Vec<(AssetId, Word)>, Self::DataError> {
+        Err(Self::DataError::UnsupportedStorageOperation)
+    }
+
     fn contract_state_insert_range<'a, I>(
         &mut self,
         _: &ContractId,
@@ -0,0 +1,236 @@
+use crate::{
+    interpreter::EcalRecord,
+    storage::{
+        BlobData,
+        ContractsAssets,
+        ContractsRawCode,
+        ContractsState,
+        ContractsStateData,
+        InterpreterStorage,
+        UploadedBytecodes,
+        interpreter::ContractsAssetsStorage,
+    },
+};
+
+use fuel_storage::{
+    Mappable,
+    StorageInspect,
+    StorageMutate,
+    StorageRead,
+    StorageSize,
+    StorageWrite,
+};
+use fuel_tx::ConsensusParameters;
+use fuel_types::{
+    AssetId,
+    BlockHeight,
+    Bytes32,
+    ContractId,
+    Word,
+};
+
+use alloc::{
+    borrow::Cow,
+    collections::BTreeMap,
+    vec::Vec,
+};
+use core::cell::RefCell;
+
+/// The values read by the VM during an execution that aren't part of the transaction,
+/// recorded by an [`crate::transactor::ExecutionRecorder`].
+///
+/// Executing the same transaction on top of a [`ReplayStorage`] serving the log, with
+/// the [`crate::interpreter::ReplayEcal`] handler replaying the `ecal_results`,
+/// produces the same receipts as the recorded execution.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReplayLog {
+    /// The current block height, if read.
+    pub block_height: Option<BlockHeight>,
+    /// The block producer, if read.
+    pub coinbase: Option<ContractId>,
+    /// The timestamps read, per block height.
+    pub timestamps: BTreeMap<BlockHeight, Word>,
+    /// The block hashes read, per block height.
+    pub block_hashes: BTreeMap<BlockHeight, Bytes32>,
+    /// The effects of the executed ECAL instructions, in order.
+    pub ecal_results: Vec<EcalRecord>,
+}
+
+/// Storage serving the block information of a [`ReplayLog`] instead of the one of the
+/// wrapped storage.
+///
+/// The values missing from the log are read from the wrapped storage and added to the
+/// log, so wrapping a storage with an empty log records the block information read by
+/// the VM. The other tables and methods are delegated to the wrapped storage.
+#[derive(Debug, Clone)]
+pub struct ReplayStorage<S> {
+    storage: S,
+    log: RefCell<ReplayLog>,
+}
+
+impl<S> ReplayStorage<S> {
+    /// Wraps the `storage`, serving the block information of the `log`.
+    pub fn new(storage: S, log: ReplayLog) -> Self {
+        Self {
+            storage,
+            log: RefCell::new(log),
+        }
+    }
+
+    /// Returns a copy of the log, including the values recorded so far.
+    pub fn log(&self) -> ReplayLog {
+        self.log.borrow().clone()
+    }
+
+    /// Returns the log, leaving an empty one in its place.
+    pub fn take_log(&mut self) -> ReplayLog {
+        core::mem::take(self.log.get_mut())
+    }
+
+    /// Returns the wrapped storage.
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Returns the wrapped storage mutably.
+    pub fn storage_mut(&mut self) -> &mut S {
+        &mut self.storage
+    }
+
+    /// Returns the wrapped storage and the log.
+    pub fn into_inner(self) -> (S, ReplayLog) {
+        (self.storage, self.log.into_inner())
+    }
+}
+
+delegate_to_storage!(
+    ReplayStorage;
+    ContractsRawCode,
+    ContractsState,
+    ContractsAssets,
+    UploadedBytecodes,
+    BlobData,
+);
+
+impl<S: ContractsAssetsStorage> ContractsAssetsStorage for ReplayStorage<S> {}
+
+impl<S> InterpreterStorage for ReplayStorage<S>
+where
+    S: InterpreterStorage,
+{
+    type DataError = S::DataError;
+
+    fn block_height(&self) -> Result<BlockHeight, Self::DataError> {
+        if let Some(block_height) = self.log.borrow().block_height {
+            return Ok(block_height)
+        }
+        let block_height = self.storage.block_height()?;
+        self.log.borrow_mut().block_height = Some(block_height);
+        Ok(block_height)
+    }
+
+    fn consensus_parameters_version(&self) -> Result<u32, Self::DataError> {
+        self.storage.consensus_parameters_version()
+    }
+
+    fn state_transition_version(&self) -> Result<u32, Self::DataError> {
+        self.storage.state_transition_version()
+    }
+
+    fn timestamp(&self, height: BlockHeight) -> Result<Word, Self::DataError> {
+        if let Some(timestamp) = self.log.borrow().timestamps.get(&height) {
+            return Ok(*timestamp)
+        }
+        let timestamp = self.storage.timestamp(height)?;
+        self.log.borrow_mut().timestamps.insert(height, timestamp);
+        Ok(timestamp)
+    }
+
+    fn block_hash(&self, block_height: BlockHeight) -> Result<Bytes32, Self::DataError> {
+        if let Some(block_hash) = self.log.borrow().block_hashes.get(&block_height) {
+            return Ok(*block_hash)
+        }
+        let block_hash = self.storage.block_hash(block_height)?;
+        self.log
+            .borrow_mut()
+            .block_hashes
+            .insert(block_height, block_hash);
+        Ok(block_hash)
+    }
+
+    fn coinbase(&self) -> Result<ContractId, Self::DataError> {
+        if let Some(coinbase) = self.log.borrow().coinbase {
+            return Ok(coinbase)
+        }
+        let coinbase = self.storage.coinbase()?;
+        self.log.borrow_mut().coinbase = Some(coinbase);
+        Ok(coinbase)
+    }
+
+    fn set_consensus_parameters(
+        &mut self,
+        version: u32,
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<Option<ConsensusParameters>, Self::DataError> {
+        self.storage
+            .set_consensus_parameters(version, consensus_parameters)
+    }
+
+    fn set_state_transition_bytecode(
+        &mut self,
+        version: u32,
+        hash: &Bytes32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        self.storage.set_state_transition_bytecode(version, hash)
+    }
+
+    fn contract_state_range(
+        &self,
+        id: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Vec<Option<Cow<'_, ContractsStateData>>>, Self::DataError> {
+        self.storage.contract_state_range(id, start_key, range)
+    }
+
+    fn merkle_contract_asset_balances(
+        &self,
+        contract: &ContractId,
+    ) -> Result<Vec<(AssetId, Word)>, Self::DataError> {
+        self.storage.merkle_contract_asset_balances(contract)
+    }
+
+    fn contract_state_insert_range<'a, I>(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        values: I,
+    ) -> Result<usize, Self::DataError>
+    where
+        I: Iterator<Item = &'a [u8]>,
+    {
+        self.storage
+            .contract_state_insert_range(contract, start_key, values)
+    }
+
+    fn contract_state_remove_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Option<()>, Self::DataError> {
+        self.storage
+            .contract_state_remove_range(contract, start_key, range)
+    }
+
+    #[cfg(feature = "frame-revert")]
+    fn enter_frame(&mut self, depth: usize) {
+        self.storage.enter_frame(depth)
+    }
+
+    #[cfg(feature = "frame-revert")]
+    fn revert_frame(&mut self, depth: usize) -> Result<bool, Self::DataError> {
+        self.storage.revert_frame(depth)
+    }
+}
@@ -1,13 +1,17 @@
 use alloc::vec;
 
 use crate::{
+    backtrace::PanicDetail,
     consts::*,
+    interpreter::InterpreterParams,
     prelude::*,
+    script_with_data_offset,
 };
 use fuel_asm::{
     RegId,
     op,
 };
+use fuel_types::canonical::Serialize;
 
 #[test]
 fn backtrace() {
@@ -91,3 +95,152 @@ fn backtrace() {
         .to();
     assert_eq!(id, &contract_call);
 }
+
+#[test]
+fn backtrace_for_panic__returns_the_backtrace_of_the_panicking_call() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract_ok = test_context
+        .setup_contract(vec![op::ret(RegId::ONE)], None, None)
+        .contract_id;
+    let contract_panic = test_context
+        .setup_contract(vec![op::noop()], None, None)
+        .contract_id;
+
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset as Immediate18),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::addi(0x10, 0x10, Call::LEN as Immediate12),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let mut script_data = Call::new(contract_ok, 0, 0).to_bytes();
+    script_data.extend(Call::new(contract_panic, 0, 0).to_bytes());
+
+    let tx = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_ok)
+        .contract_input(contract_panic)
+        .fee_input()
+        .contract_output(&contract_ok)
+        .contract_output(&contract_panic)
+        .build();
+
+    // Given
+    let mut transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+    transactor.transact(tx);
+    let receipts = transactor.receipts().expect("Expected receipts").to_vec();
+    let panic = receipts
+        .iter()
+        .find(|r| matches!(r, Receipt::Panic { .. }))
+        .expect("Expected a panic receipt");
+    let call = receipts.first().expect("Expected a call receipt");
+
+    // When
+    let backtrace = transactor.backtrace_for_panic(panic);
+
+    // Then
+    let backtrace = backtrace.expect("Expected a backtrace for the panic");
+    assert_eq!(backtrace.contract(), &contract_panic);
+    assert_eq!(panic.id(), Some(&contract_panic));
+    assert!(transactor.backtrace_for_panic(call).is_none());
+}
+
+#[test]
+fn decode_panic__locates_the_instruction_in_the_contract() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let division = op::div(0x10, RegId::ONE, RegId::ZERO);
+    let contract_id = test_context
+        .setup_contract(vec![op::noop(), division, op::ret(RegId::ONE)], None, None)
+        .contract_id;
+
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset as Immediate18),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    let tx = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+
+    // Given
+    let mut transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+    transactor.transact(tx);
+    let receipts = transactor.receipts().expect("Expected receipts").to_vec();
+    let panic = receipts
+        .iter()
+        .find(|r| matches!(r, Receipt::Panic { .. }))
+        .expect("Expected a panic receipt");
+    let call = receipts.first().expect("Expected a call receipt");
+
+    // When
+    let detail = transactor.decode_panic(panic);
+
+    // Then
+    let expected = PanicDetail {
+        contract_id: Some(contract_id),
+        offset: Instruction::SIZE as Word,
+        instruction: division,
+        reason: PanicReason::ArithmeticError,
+    };
+    assert_eq!(detail, Some(expected));
+    assert!(transactor.decode_panic(call).is_none());
+}
+
+#[test]
+fn decode_panic__locates_the_instruction_in_the_script() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let division = op::div(0x10, RegId::ONE, RegId::ZERO);
+    let tx = test_context
+        .start_script(vec![op::noop(), division, op::ret(RegId::ONE)], vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build();
+
+    // Given
+    let mut transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+    transactor.transact(tx);
+    let receipts = transactor.receipts().expect("Expected receipts");
+    let panic = receipts
+        .iter()
+        .find(|r| matches!(r, Receipt::Panic { .. }))
+        .expect("Expected a panic receipt");
+
+    // When
+    let detail = transactor.decode_panic(panic);
+
+    // Then
+    let expected = PanicDetail {
+        contract_id: None,
+        offset: Instruction::SIZE as Word,
+        instruction: division,
+        reason: PanicReason::ArithmeticError,
+    };
+    assert_eq!(detail, Some(expected));
+}
@@ -6,13 +6,18 @@ mod ldc_mode_2;
 use crate::{
     consts::*,
     interpreter::{
+        BlockContext,
+        GasRefundPolicy,
         InterpreterParams,
         Memory,
         NotSupportedEcal,
     },
     prelude::*,
     script_with_data_offset,
-    storage::ContractsStateData,
+    storage::{
+        BlockHashProvider,
+        ContractsStateData,
+    },
     tests::test_helpers::set_full_word,
     util::test_helpers::{
         check_expected_reason_for_instructions,
@@ -20,6 +25,8 @@ use crate::{
     },
 };
 use alloc::{
+    boxed::Box,
+    sync::Arc,
     vec,
     vec::Vec,
 };
@@ -48,6 +55,7 @@ use fuel_tx::{
     field::{
         Outputs,
         Script as ScriptField,
+        ScriptGasLimit,
     },
 };
 use fuel_types::{
@@ -467,6 +475,663 @@ fn state_write_charges_for_new_storage() {
     }
 }
 
+#[cfg(feature = "std")]
+#[derive(Debug, PartialEq, Eq)]
+enum StorageAccess {
+    Read,
+    Write(Option<Vec<u8>>),
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+struct RecordingStorageObserver {
+    accesses: std::sync::Mutex<Vec<(ContractId, Bytes32, StorageAccess)>>,
+}
+
+#[cfg(feature = "std")]
+impl crate::storage::StorageObserver for RecordingStorageObserver {
+    fn on_read(&self, contract: &ContractId, key: &Bytes32) {
+        self.accesses
+            .lock()
+            .unwrap()
+            .push((*contract, *key, StorageAccess::Read));
+    }
+
+    fn on_write(&self, contract: &ContractId, key: &Bytes32, value: Option<&[u8]>) {
+        self.accesses.lock().unwrap().push((
+            *contract,
+            *key,
+            StorageAccess::Write(value.map(<[u8]>::to_vec)),
+        ));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn storage_observer__records_contract_state_accesses() {
+    let mut test_context = TestBuilder::new(2322u64);
+
+    let program = vec![
+        op::movi(0x14, Bytes32::LEN as u32), // Space for the key
+        op::aloc(0x14),                      // Allocate the zeroed key
+        op::sww(RegId::HP, 0x12, RegId::ONE),
+        op::srw(0x13, 0x12, RegId::HP),
+        op::scwq(RegId::HP, 0x12, RegId::ONE),
+        op::ret(RegId::ONE),
+    ];
+    let contract_id = test_context.setup_contract(program, None, None).contract_id;
+
+    let (script_call, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset as Immediate18),
+            op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_call_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    let tx = test_context
+        .start_script(script_call, script_call_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+
+    let observer = std::sync::Arc::new(RecordingStorageObserver::default());
+    let mut transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+    transactor.set_storage_observer(Some(observer.clone()));
+    transactor.transact(tx);
+    assert!(transactor.is_success());
+
+    let key = Bytes32::zeroed();
+    let mut value = Bytes32::zeroed();
+    value.as_mut()[..WORD_SIZE].copy_from_slice(&1u64.to_be_bytes());
+    assert_eq!(
+        *observer.accesses.lock().unwrap(),
+        vec![
+            (contract_id, key, StorageAccess::Write(Some(value.to_vec()))),
+            (contract_id, key, StorageAccess::Read),
+            (contract_id, key, StorageAccess::Write(None)),
+        ]
+    );
+}
+
+#[test]
+fn access_set__aggregates_contract_state_accesses() {
+    let mut test_context = TestBuilder::new(2322u64);
+
+    let program = vec![
+        op::movi(0x14, 2 * Bytes32::LEN as u32), // Space for two slots
+        op::aloc(0x14),                          // Allocate the zeroed keys
+        op::sww(RegId::HP, 0x12, RegId::ONE),
+        op::sww(RegId::HP, 0x12, RegId::ONE),
+        op::movi(0x15, 2),
+        op::srwq(RegId::HP, 0x13, RegId::HP, 0x15),
+        op::ret(RegId::ONE),
+    ];
+    let contract_id = test_context.setup_contract(program, None, None).contract_id;
+
+    let (script_call, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset as Immediate18),
+            op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_call_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    let tx = test_context
+        .start_script(script_call, script_call_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+
+    // Given
+    let mut transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+    transactor.set_access_set_enabled(true);
+
+    // When
+    transactor.transact(tx);
+
+    // Then
+    assert!(transactor.is_success());
+    let first_key = Bytes32::zeroed();
+    let mut second_key = Bytes32::zeroed();
+    second_key.as_mut()[Bytes32::LEN - 1] = 1;
+    let access_set = transactor.access_set();
+    assert_eq!(
+        access_set.reads,
+        [(contract_id, first_key), (contract_id, second_key)].into()
+    );
+    assert_eq!(access_set.writes, [(contract_id, first_key)].into());
+}
+
+#[test]
+fn simulate__does_not_persist_storage_writes() {
+    let mut test_context = TestBuilder::new(2322u64);
+
+    let program = vec![
+        op::movi(0x14, Bytes32::LEN as u32), // Space for the key
+        op::aloc(0x14),                      // Allocate the zeroed key
+        op::sww(RegId::HP, 0x12, RegId::ONE),
+        op::ret(RegId::ONE),
+    ];
+    let contract_id = test_context.setup_contract(program, None, None).contract_id;
+
+    let (script_call, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset as Immediate18),
+            op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_call_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    let tx = test_context
+        .start_script(script_call, script_call_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+    let key = Bytes32::zeroed();
+
+    // Given
+    let mut transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+
+    // When
+    let simulated = transactor.simulate(tx.clone()).unwrap();
+
+    // Then
+    assert!(!simulated.should_revert());
+    let storage: &MemoryStorage = transactor.as_ref();
+    assert!(!storage.contract_state_contains(&contract_id, &key).unwrap());
+
+    transactor.transact(tx);
+    assert!(transactor.is_success());
+    let executed = transactor.to_owned_state_transition().unwrap();
+    assert_eq!(simulated.receipts(), executed.receipts());
+    assert_eq!(simulated.tx(), executed.tx());
+    let storage: &MemoryStorage = transactor.as_ref();
+    assert!(storage.contract_state_contains(&contract_id, &key).unwrap());
+}
+
+#[test]
+fn receipt_verifier__rejected_transaction_leaves_the_storage_untouched() {
+    use crate::verification::GasUsedWithinLimit;
+
+    let mut test_context = TestBuilder::new(2322u64);
+
+    let program = vec![
+        op::movi(0x14, Bytes32::LEN as u32), // Space for the key
+        op::aloc(0x14),                      // Allocate the zeroed key
+        op::sww(RegId::HP, 0x12, RegId::ONE),
+        op::ret(RegId::ONE),
+    ];
+    let contract_id = test_context.setup_contract(program, None, None).contract_id;
+
+    let (script_call, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset as Immediate18),
+            op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_call_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    let tx = test_context
+        .start_script(script_call, script_call_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+    let key = Bytes32::zeroed();
+
+    // Given
+    let mut transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+    transactor.add_receipt_verifier(Box::new(GasUsedWithinLimit { limit: 1 }));
+
+    // When
+    transactor.transact(tx);
+
+    // Then
+    assert!(matches!(
+        transactor.error(),
+        Some(InterpreterError::ReceiptVerification(_))
+    ));
+    let storage: &MemoryStorage = transactor.as_ref();
+    assert!(!storage.contract_state_contains(&contract_id, &key).unwrap());
+}
+
+#[test]
+fn find_min_gas__returns_the_smallest_sufficient_gas_limit() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let consensus_params = ConsensusParameters::standard();
+
+    let script = vec![
+        op::movi(0x10, 10),
+        op::subi(0x10, 0x10, 1),
+        op::jnzb(0x10, RegId::ZERO, 0),
+        op::ret(RegId::ONE),
+    ];
+    let tx = test_context
+        .start_script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build()
+        .transaction()
+        .clone();
+    let transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, &consensus_params),
+    );
+
+    // When
+    let min_gas = transactor
+        .find_min_gas(tx.clone(), &consensus_params)
+        .expect("The script should succeed");
+
+    // Then
+    let run_with_gas_limit = |gas_limit: Word| {
+        let mut tx = tx.clone();
+        *tx.script_gas_limit_mut() = gas_limit;
+        let checked = tx
+            .into_checked_basic(test_context.get_block_height(), &consensus_params)
+            .unwrap();
+        !transactor.simulate(checked).unwrap().should_revert()
+    };
+    assert!(min_gas > 0);
+    assert!(run_with_gas_limit(min_gas));
+    assert!(!run_with_gas_limit(min_gas - 1));
+}
+
+#[test]
+fn find_min_gas__supports_a_max_fee_covering_only_the_own_gas_limit() {
+    use fuel_tx::field::MaxFeeLimit;
+
+    let mut test_context = TestBuilder::new(2322u64);
+    let consensus_params = ConsensusParameters::standard();
+    let gas_price = consensus_params.fee_params().gas_price_factor();
+
+    let script = vec![
+        op::movi(0x10, 10),
+        op::subi(0x10, 0x10, 1),
+        op::jnzb(0x10, RegId::ZERO, 0),
+        op::ret(RegId::ONE),
+    ];
+    let mut tx = test_context
+        .start_script(script, vec![])
+        .script_gas_limit(10_000)
+        .fee_input()
+        .build()
+        .transaction()
+        .clone();
+    let fee = TransactionFee::checked_from_tx(
+        consensus_params.gas_costs(),
+        consensus_params.fee_params(),
+        &tx,
+        gas_price,
+    )
+    .unwrap();
+    tx.set_max_fee_limit(fee.max_fee());
+    let transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(gas_price, &consensus_params),
+    );
+
+    // Given
+    let checked = tx
+        .clone()
+        .into_checked_basic(test_context.get_block_height(), &consensus_params)
+        .unwrap();
+    assert!(!transactor.simulate(checked).unwrap().should_revert());
+
+    // When
+    let min_gas = transactor.find_min_gas(tx, &consensus_params);
+
+    // Then
+    let min_gas = min_gas.expect("The script should succeed");
+    assert!(min_gas > 0);
+    assert!(min_gas <= 10_000);
+}
+
+#[test]
+fn find_min_gas__returns_none_when_the_script_reverts() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let consensus_params = ConsensusParameters::standard();
+
+    // Given
+    let tx = test_context
+        .start_script(vec![op::rvrt(RegId::ONE)], vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build()
+        .transaction()
+        .clone();
+    let transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, &consensus_params),
+    );
+
+    // When
+    let min_gas = transactor.find_min_gas(tx, &consensus_params);
+
+    // Then
+    assert_eq!(min_gas, None);
+}
+
+#[test]
+fn contract_code_root__returns_the_root_of_the_executing_contract() {
+    let mut test_context = TestBuilder::new(2322u64);
+
+    let program = vec![op::noop(), op::ret(RegId::ONE)];
+    let code_root = Contract::from(program.iter().copied().collect::<Vec<u8>>()).root();
+    let contract_id = test_context.setup_contract(program, None, None).contract_id;
+
+    let (script_call, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset as Immediate18),
+            op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_call_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    let tx = test_context
+        .start_script(script_call, script_call_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build()
+        .into_ready(
+            0,
+            test_context.get_gas_costs(),
+            test_context.get_fee_params(),
+            None,
+        )
+        .expect("failed to ready tx");
+
+    // Given
+    let mut vm = Interpreter::<_, _, Script>::with_storage(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+    vm.set_breakpoint(Breakpoint::new(contract_id, 0));
+    assert_eq!(
+        vm.contract_code_root(),
+        Err(RuntimeError::Recoverable(
+            PanicReason::ExpectedInternalContext
+        ))
+    );
+
+    // When
+    let is_debug = vm
+        .transact(tx)
+        .expect("failed to execute tx")
+        .state()
+        .is_debug();
+    assert!(is_debug);
+    let root = vm.contract_code_root();
+
+    // Then
+    assert_eq!(root, Ok(code_root));
+}
+
+#[derive(Debug)]
+struct FixedGasRefund {
+    refund: Word,
+    max_refund: Word,
+}
+
+impl GasRefundPolicy for FixedGasRefund {
+    fn refund_on_clear(&self, _: &ContractId, _: &Bytes32) -> Word {
+        self.refund
+    }
+
+    fn max_refund(&self, _: Word) -> Word {
+        self.max_refund
+    }
+}
+
+fn gas_used_clearing_a_slot(policy: Option<FixedGasRefund>) -> Word {
+    let mut test_context = TestBuilder::new(2322u64);
+
+    let program = vec![
+        op::movi(0x14, Bytes32::LEN as u32), // Space for the key
+        op::aloc(0x14),                      // Allocate the zeroed key
+        op::sww(RegId::HP, 0x12, RegId::ONE),
+        op::sww(RegId::HP, 0x12, RegId::ZERO), // Clears the slot
+        op::sww(RegId::HP, 0x12, RegId::ZERO), // The slot is already cleared
+        op::ret(RegId::ONE),
+    ];
+    let contract_id = test_context.setup_contract(program, None, None).contract_id;
+
+    let (script_call, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset as Immediate18),
+            op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_call_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    let tx = test_context
+        .start_script(script_call, script_call_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+
+    let mut transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+    transactor.set_gas_refund_policy(
+        policy.map(|policy| Arc::new(policy) as Arc<dyn GasRefundPolicy>),
+    );
+    transactor.transact(tx);
+    assert!(transactor.is_success());
+
+    let receipts = transactor.receipts().expect("The transaction was executed");
+    let Some(Receipt::ScriptResult { gas_used, .. }) = receipts.last() else {
+        unreachable!("The last receipt is the script result");
+    };
+    *gas_used
+}
+
+#[test]
+fn gas_refund_policy__refunds_the_cleared_slots() {
+    let gas_used = gas_used_clearing_a_slot(None);
+
+    let refunded_gas_used = gas_used_clearing_a_slot(Some(FixedGasRefund {
+        refund: 7,
+        max_refund: Word::MAX,
+    }));
+
+    assert_eq!(refunded_gas_used, gas_used - 7);
+}
+
+#[test]
+fn gas_refund_policy__caps_the_refund() {
+    let gas_used = gas_used_clearing_a_slot(None);
+
+    let refunded_gas_used = gas_used_clearing_a_slot(Some(FixedGasRefund {
+        refund: 7,
+        max_refund: 3,
+    }));
+
+    assert_eq!(refunded_gas_used, gas_used - 3);
+}
+
+#[test]
+fn gas_refund_policy__refund_survives_pausing_the_execution() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let policy: Arc<dyn GasRefundPolicy> = Arc::new(FixedGasRefund {
+        refund: 7,
+        max_refund: Word::MAX,
+    });
+
+    let program = vec![
+        op::movi(0x14, Bytes32::LEN as u32), // Space for the key
+        op::aloc(0x14),                      // Allocate the zeroed key
+        op::sww(RegId::HP, 0x12, RegId::ONE),
+        op::scwq(RegId::HP, 0x12, RegId::ONE), // Clears the slot
+        op::ret(RegId::ONE),
+    ];
+    let contract_id = test_context.setup_contract(program, None, None).contract_id;
+
+    let (script_call, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset as Immediate18),
+            op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_call_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    let consensus_params = ConsensusParameters::standard();
+    let tx = test_context
+        .start_script(script_call, script_call_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build()
+        .into_ready(
+            0,
+            consensus_params.gas_costs(),
+            consensus_params.fee_params(),
+            None,
+        )
+        .unwrap();
+    let new_vm = || {
+        let mut vm = Interpreter::<_, _, Script>::with_storage(
+            MemoryInstance::new(),
+            test_context.get_storage().clone(),
+            InterpreterParams::new(0, &consensus_params),
+        );
+        vm.set_gas_refund_policy(Some(policy.clone()));
+        vm
+    };
+    let script_gas_used = |receipts: &[Receipt]| match receipts.last() {
+        Some(Receipt::ScriptResult { gas_used, .. }) => *gas_used,
+        _ => unreachable!("The last receipt is the script result"),
+    };
+
+    let mut vm = new_vm();
+    vm.transact(tx.clone()).unwrap();
+    let gas_used_without_pause = script_gas_used(vm.receipts());
+
+    // Given
+    let mut vm = new_vm();
+    vm.set_breakpoint(Breakpoint::new(contract_id, 4));
+    let state = *vm.transact(tx).unwrap().state();
+    assert!(matches!(state, ProgramState::RunProgram(_)));
+    assert_eq!(vm.gas_refund(), 7);
+    let paused = vm.pause();
+    let storage = vm.as_ref().clone();
+
+    // When
+    let mut vm = Interpreter::from_paused(paused, storage);
+    vm.set_gas_refund_policy(Some(policy));
+    let state = vm.resume().unwrap();
+
+    // Then
+    assert_eq!(state, ProgramState::Return(1));
+    assert_eq!(script_gas_used(vm.receipts()), gas_used_without_pause);
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+struct RecordingOpcodeGasObserver {
+    charges: std::sync::Mutex<Vec<(fuel_asm::Opcode, Word)>>,
+}
+
+#[cfg(feature = "std")]
+impl crate::interpreter::OpcodeGasObserver for RecordingOpcodeGasObserver {
+    fn on_gas_charged(&self, opcode: fuel_asm::Opcode, gas_charged: Word) {
+        self.charges.lock().unwrap().push((opcode, gas_charged));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn opcode_gas_observer__is_notified_only_about_the_observed_opcodes() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let len = Bytes32::LEN as Word;
+
+    let script = vec![
+        op::movi(0x10, len as Immediate18),
+        op::aloc(0x10),
+        op::k256(RegId::HP, RegId::ZERO, 0x10),
+        op::ret(RegId::ONE),
+    ];
+    let tx = test_context
+        .start_script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build();
+
+    // Given
+    let observer = Arc::new(RecordingOpcodeGasObserver::default());
+    let mut transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+    transactor.set_opcode_gas_observer(Some(observer.clone()), [fuel_asm::Opcode::K256]);
+    let expected_charge = transactor.gas_costs().k256().resolve(len);
+
+    // When
+    transactor.transact(tx);
+
+    // Then
+    assert!(transactor.is_success());
+    let charges = observer.charges.lock().unwrap();
+    assert_eq!(*charges, vec![(fuel_asm::Opcode::K256, expected_charge)]);
+}
+
 #[test]
 fn ldc__offset_changes_cost() {
     let rng = &mut StdRng::seed_from_u64(2322u64);
@@ -2063,6 +2728,50 @@ fn block_hash_works(
     assert_eq!(data.as_ref().unwrap(), &*expected);
 }
 
+#[derive(Debug)]
+struct FixedBlockHash(Bytes32);
+
+impl BlockHashProvider for FixedBlockHash {
+    fn block_hash(&self, _: BlockHeight) -> Bytes32 {
+        self.0
+    }
+}
+
+#[test]
+fn block_hash__is_read_from_the_block_hash_provider() {
+    // Given
+    let mut client = MemoryClient::default();
+    let current_height: BlockHeight = 10.into();
+    let block_hash = Bytes32::from([7; 32]);
+    client.as_mut().set_block_height(current_height);
+    client
+        .as_mut()
+        .set_block_hash_provider(Some(Arc::new(FixedBlockHash(block_hash))));
+
+    #[rustfmt::skip]
+    let script = vec![
+        op::movi(0x10, 32),              // allocation size
+        op::aloc(0x10),                  // allocate memory
+        op::movi(0x11, 5),               // set the argument
+        op::bhsh(RegId::HP, 0x11),       // perform the instruction
+        op::logd(0, 0, RegId::HP, 0x10), // log output
+        op::ret(RegId::ONE),
+    ];
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_checked(current_height);
+
+    // When
+    let receipts = client.transact(tx);
+
+    // Then
+    let Some(Receipt::LogData { data, .. }) = receipts.first() else {
+        panic!("expected log receipt");
+    };
+    assert_eq!(data.as_ref().unwrap(), &*block_hash);
+}
+
 #[rstest::rstest]
 fn coinbase_works() {
     let mut client = MemoryClient::default();
@@ -2101,6 +2810,161 @@ fn coinbase_works() {
     assert_eq!(data.as_ref().unwrap(), &*expected);
 }
 
+#[test]
+fn block_context_overrides_storage_values() {
+    let block_context = BlockContext {
+        height: 42.into(),
+        time: 1_234_567,
+        coinbase: ContractId::from([0xcb; 32]),
+    };
+    let interpreter_params = InterpreterParams {
+        block_context: Some(block_context),
+        ..Default::default()
+    };
+    let mut client = MemoryClient::<MemoryInstance>::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        interpreter_params,
+    );
+    assert_ne!(
+        client.as_ref().block_height().unwrap(),
+        block_context.height
+    );
+    assert_ne!(client.as_ref().coinbase().unwrap(), block_context.coinbase);
+
+    #[rustfmt::skip]
+    let script = vec![
+        op::bhei(0x20),                     // current block height
+        op::log(0x20, 0, 0, 0),             // log it
+        op::time(0x21, 0x20),               // timestamp of the current block
+        op::log(0x21, 0, 0, 0),             // log it
+        op::movi(0x10, 32),                 // allocation size
+        op::aloc(0x10),                     // allocate memory
+        op::cb(RegId::HP),                  // coinbase
+        op::logd(0, 0, RegId::HP, 0x10),    // log it
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script.into_iter().collect();
+
+    // Replaying the transaction gives the same results.
+    for _ in 0..2 {
+        let tx = TransactionBuilder::script(script.clone(), vec![])
+            .script_gas_limit(1_000_000)
+            .add_fee_input()
+            .finalize_checked(block_context.height);
+
+        let receipts = client.transact(tx);
+        let logged = receipts
+            .iter()
+            .filter_map(|r| match r {
+                Receipt::Log { ra, .. } => Some(*ra),
+                _ => None,
+            })
+            .collect_vec();
+        let Some(Receipt::LogData { data, .. }) = receipts.get(2) else {
+            panic!("expected log data receipt");
+        };
+
+        assert_eq!(
+            logged,
+            vec![u32::from(block_context.height).into(), block_context.time]
+        );
+        assert_eq!(data.as_ref().unwrap(), block_context.coinbase.as_ref());
+    }
+}
+
+#[test]
+fn transactor_reports_the_coinbase_of_the_last_transaction() {
+    let storage_coinbase = ContractId::from([0xaa; 32]);
+    let storage = MemoryStorage::new(Default::default(), storage_coinbase);
+    let mut transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        storage,
+        InterpreterParams::default(),
+    );
+    assert_eq!(transactor.coinbase(), None);
+
+    let script: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+    let tx = || {
+        TransactionBuilder::script(script.clone(), vec![])
+            .script_gas_limit(1_000_000)
+            .add_fee_input()
+            .finalize_checked(Default::default())
+    };
+
+    transactor.transact(tx());
+    assert_eq!(transactor.coinbase(), Some(storage_coinbase));
+
+    let block_context = BlockContext {
+        height: Default::default(),
+        time: 0,
+        coinbase: ContractId::from([0xcb; 32]),
+    };
+    transactor.set_block_context(Some(block_context));
+    transactor.transact(tx());
+    assert_eq!(transactor.coinbase(), Some(block_context.coinbase));
+}
+
+#[test]
+fn transactor_reset__installs_the_storage_and_clears_the_state() {
+    let first_coinbase = ContractId::from([0xaa; 32]);
+    let second_coinbase = ContractId::from([0xbb; 32]);
+
+    #[rustfmt::skip]
+    let script: Vec<u8> = vec![
+        op::movi(0x10, 32),                 // allocation size
+        op::aloc(0x10),                     // allocate memory
+        op::cb(RegId::HP),                  // coinbase
+        op::logd(0, 0, RegId::HP, 0x10),    // log it
+        op::ret(RegId::ONE),
+    ].into_iter().collect();
+    let tx = || {
+        TransactionBuilder::script(script.clone(), vec![])
+            .script_gas_limit(1_000_000)
+            .add_fee_input()
+            .finalize_checked(Default::default())
+    };
+    let logged_coinbase = |receipts: &[Receipt]| {
+        receipts
+            .iter()
+            .filter_map(|r| match r {
+                Receipt::LogData { .. } => r.data().map(<[u8]>::to_vec),
+                _ => None,
+            })
+            .collect_vec()
+    };
+
+    let mut transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        MemoryStorage::new(Default::default(), first_coinbase),
+        InterpreterParams::default(),
+    );
+    transactor.transact(tx());
+    let first_receipts = transactor.receipts().unwrap().to_vec();
+    assert_eq!(
+        logged_coinbase(&first_receipts),
+        vec![first_coinbase.to_vec()]
+    );
+
+    // When
+    let previous_storage =
+        transactor.reset(MemoryStorage::new(Default::default(), second_coinbase));
+
+    // Then
+    assert_eq!(previous_storage.coinbase().unwrap(), first_coinbase);
+    assert!(transactor.state_transition().is_none());
+    assert!(transactor.interpreter().receipts().is_empty());
+    assert_eq!(transactor.interpreter().transaction_id(), None);
+
+    transactor.transact(tx());
+    let second_receipts = transactor.receipts().unwrap();
+    assert_eq!(second_receipts.len(), first_receipts.len());
+    assert_eq!(
+        logged_coinbase(second_receipts),
+        vec![second_coinbase.to_vec()]
+    );
+}
+
 #[test]
 fn various_ldc_issues_poc() {
     let rng = &mut StdRng::seed_from_u64(2322u64);
@@ -862,3 +862,41 @@ fn call_forwarding(
         result,
     )
 }
+
+#[test]
+fn runtime_balances__are_sorted_by_asset_id() {
+    use crate::{
+        interpreter::{
+            InterpreterParams,
+            MemoryInstance,
+        },
+        prelude::Transactor,
+    };
+    use fuel_tx::ConsensusParameters;
+
+    let mut test_context = TestBuilder::new(2322u64);
+    let first_asset = AssetId::new([1; 32]);
+    let second_asset = AssetId::new([2; 32]);
+
+    // Given
+    let tx = test_context
+        .start_script(vec![op::ret(RegId::ONE)], vec![])
+        .script_gas_limit(1_000_000)
+        .coin_input(second_asset, 50)
+        .coin_input(first_asset, 100)
+        .build();
+    let mut transactor = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+
+    // When
+    transactor.transact(tx);
+    let balances = transactor.interpreter().runtime_balances();
+
+    // Then
+    assert!(balances.windows(2).all(|w| w[0].0 < w[1].0));
+    assert!(balances.contains(&(first_asset, 100)));
+    assert!(balances.contains(&(second_asset, 50)));
+}
@@ -1,4 +1,5 @@
 use crate::{
+    interpreter::InterpreterParams,
     prelude::*,
     script_with_data_offset,
     util::test_helpers::TestBuilder,
@@ -14,9 +15,13 @@ use fuel_asm::{
 use fuel_tx::{
     ConsensusParameters,
     Witness,
+    field::Outputs,
     policies::Policies,
 };
-use fuel_types::canonical::Serialize;
+use fuel_types::canonical::{
+    Deserialize,
+    Serialize,
+};
 use rand::{
     Rng,
     SeedableRng,
@@ -90,6 +95,84 @@ fn prevent_contract_id_redeployment() {
     );
 }
 
+#[test]
+fn predicted_contract_id_matches_deployed_contract() {
+    let mut rng = StdRng::seed_from_u64(2322u64);
+    let mut txtor: Transactor<_, _, _> = MemoryClient::default().into();
+
+    let program: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+    let salt: Salt = rng.r#gen();
+    let storage_slots = vec![StorageSlot::new(rng.r#gen(), rng.r#gen())];
+    let state_root = Contract::initial_state_root(storage_slots.iter());
+    let predicted_id = Contract::predict_id(&program, &salt, &state_root);
+
+    let create = TransactionBuilder::create(program.into(), salt, storage_slots)
+        .max_fee_limit(0)
+        .add_fee_input()
+        .add_contract_created()
+        .finalize_checked(Default::default());
+
+    let create = txtor.deploy(create).expect("Deployment should succeed");
+    let created_id = create
+        .outputs()
+        .iter()
+        .find_map(Output::contract_id)
+        .copied()
+        .expect("Expected a contract created output");
+
+    assert_eq!(predicted_id, created_id);
+    let storage: &MemoryStorage = txtor.as_ref();
+    assert!(storage.storage_contract_exists(&predicted_id).unwrap());
+}
+
+#[test]
+fn created_contracts_returns_the_deployed_contract() {
+    let mut rng = StdRng::seed_from_u64(2322u64);
+    let mut txtor = Transactor::<_, _, Create>::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+
+    let program: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+    let salt: Salt = rng.r#gen();
+    let storage_slots = vec![StorageSlot::new(rng.r#gen(), rng.r#gen())];
+    let state_root = Contract::initial_state_root(storage_slots.iter());
+    let predicted_id = Contract::predict_id(&program, &salt, &state_root);
+
+    let create = TransactionBuilder::create(program.into(), salt, storage_slots)
+        .max_fee_limit(0)
+        .add_fee_input()
+        .add_contract_created()
+        .finalize_checked(Default::default());
+
+    let state_transition = txtor
+        .transact(create)
+        .to_owned_state_transition()
+        .expect("Deployment should succeed");
+
+    assert_eq!(
+        state_transition.created_contracts(),
+        vec![(predicted_id, state_root)]
+    );
+    assert_eq!(
+        StateTransitionRef::from(&state_transition).created_contracts(),
+        state_transition.created_contracts()
+    );
+}
+
+#[test]
+fn created_contracts_is_empty_for_script() {
+    let mut test_context = TestBuilder::new(2322u64);
+
+    let state_transition = test_context
+        .start_script(vec![op::ret(RegId::ONE)], vec![])
+        .fee_input()
+        .execute();
+
+    assert!(state_transition.created_contracts().is_empty());
+}
+
 #[test]
 fn mint_consumes_gas_for_new_assets() {
     let mut test_context = TestBuilder::new(2322u64);
@@ -154,3 +237,48 @@ fn mint_consumes_gas_for_new_assets() {
 
     assert!(new_asset > existing_asset);
 }
+
+#[test]
+#[allow(non_snake_case)]
+fn call_frame__from_bytes_decodes_the_frame_written_by_call() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let gas_limit = 1_000_000;
+
+    // Given
+    let program = vec![
+        op::movi(0x10, CallFrame::serialized_size() as Immediate18),
+        op::logd(RegId::ZERO, RegId::ZERO, RegId::FP, 0x10),
+        op::ret(RegId::ONE),
+    ];
+    let contract_id = test_context.setup_contract(program, None, None).contract_id;
+    let (script_call, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset as Immediate18),
+            op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_call_data = Call::new(contract_id, 7, 9).to_bytes();
+
+    // When
+    let result = test_context
+        .start_script(script_call, script_call_data)
+        .script_gas_limit(gas_limit)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .execute();
+
+    // Then
+    let bytes = result
+        .receipts()
+        .iter()
+        .find_map(Receipt::data)
+        .expect("Missing log data receipt");
+    let frame = CallFrame::from_bytes(bytes).expect("Failed to decode the call frame");
+    assert_eq!(frame.to(), &contract_id);
+    assert_eq!((frame.a(), frame.b()), (7, 9));
+    assert_eq!(frame.to_bytes(), bytes);
+}
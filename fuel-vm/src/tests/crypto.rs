@@ -34,9 +34,14 @@ use sha3::{
 };
 
 use crate::{
+    interpreter::InterpreterParams,
     prelude::*,
     tests::test_helpers::set_full_word,
-    util::test_helpers::check_expected_reason_for_instructions,
+    util::test_helpers::{
+        ScriptDataBuilder,
+        check_expected_reason_for_instructions,
+        run_instructions_expect_panic,
+    },
 };
 
 #[cfg(feature = "std")]
@@ -67,11 +72,17 @@ fn secp256k1_recover() {
 
     let signature = Signature::sign(&secret, &message);
 
+    let mut script_data = ScriptDataBuilder::new();
+    let signature_offset = script_data.push_bytes(signature.as_ref());
+    let message_offset = script_data.push_bytes(message.as_ref());
+    let public_offset = script_data.push_bytes(public.as_ref());
+
     #[rustfmt::skip]
     let script = vec![
-        op::gtf_args(0x20, 0x00, GTFArgs::ScriptData),
-        op::addi(0x21, 0x20, signature.as_ref().len() as Immediate12),
-        op::addi(0x22, 0x21, message.as_ref().len() as Immediate12),
+        op::gtf_args(0x1f, 0x00, GTFArgs::ScriptData),
+        op::addi(0x20, 0x1f, signature_offset as Immediate12),
+        op::addi(0x21, 0x1f, message_offset as Immediate12),
+        op::addi(0x22, 0x1f, public_offset as Immediate12),
         op::movi(0x10, PublicKey::LEN as Immediate18),
         op::aloc(0x10),
         op::move_(0x11, RegId::HP),
@@ -81,15 +92,7 @@ fn secp256k1_recover() {
         op::ret(RegId::ONE),
     ].into_iter().collect();
 
-    let script_data = signature
-        .as_ref()
-        .iter()
-        .copied()
-        .chain(message.as_ref().iter().copied())
-        .chain(public.as_ref().iter().copied())
-        .collect();
-
-    let tx = TransactionBuilder::script(script, script_data)
+    let tx = TransactionBuilder::script(script, script_data.build())
         .script_gas_limit(gas_limit)
         .maturity(maturity)
         .add_fee_input()
@@ -161,6 +164,36 @@ fn ecrecover_tx_id() {
     assert!(success);
 }
 
+#[test]
+fn transaction_id_returns_the_id_in_memory() {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let chain_id = ChainId::default();
+    let consensus_params = ConsensusParameters::standard_with_id(chain_id);
+
+    let mut transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams::new(0, &consensus_params),
+    );
+    assert_eq!(transactor.interpreter().transaction_id(), None);
+
+    let script = vec![op::ret(RegId::ONE)].into_iter().collect();
+    let mut tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize();
+    tx.sign_inputs(&SecretKey::random(rng), &chain_id);
+    let expected = tx.id(&chain_id);
+
+    let tx = tx
+        .into_checked(Default::default(), &consensus_params)
+        .unwrap();
+    transactor.transact(tx);
+
+    assert!(transactor.is_success());
+    assert_eq!(transactor.interpreter().transaction_id(), Some(expected));
+}
+
 #[cfg(feature = "std")]
 #[tokio::test]
 async fn recover_tx_id_predicate() {
@@ -340,6 +373,33 @@ fn secp256k1_recover__register_c_overflows() {
     check_expected_reason_for_instructions(script, MemoryOverflow);
 }
 
+#[test]
+fn secp256k1_recover__overflow_panics_at_the_instruction() {
+    let reg_a = 0x20;
+    let reg_b = 0x21;
+    let eck1 = op::eck1(reg_b, reg_b, reg_a);
+
+    #[rustfmt::skip]
+    let script = vec![
+        op::not(reg_a, RegId::ZERO),
+        op::subi(reg_a, reg_a, 31),
+        eck1,
+        op::ret(RegId::ONE),
+    ];
+
+    let (reason, receipts) = run_instructions_expect_panic(script);
+
+    assert_eq!(reason, MemoryOverflow);
+    let Some(Receipt::Panic { reason, pc, is, .. }) = receipts
+        .iter()
+        .find(|receipt| matches!(receipt, Receipt::Panic { .. }))
+    else {
+        panic!("Expected a panic receipt");
+    };
+    assert_eq!(*reason.instruction(), fuel_asm::RawInstruction::from(eck1));
+    assert_eq!(pc - is, 2 * Instruction::SIZE as Word);
+}
+
 #[test]
 fn secp256r1_recover() {
     use p256::ecdsa::SigningKey;
@@ -4,6 +4,7 @@ use alloc::{
 };
 
 use fuel_asm::{
+    PanicReason,
     RegId,
     op,
 };
@@ -17,11 +18,16 @@ use fuel_tx::{
 };
 
 use crate::{
+    error::RuntimeError,
+    interpreter::PausedExecution,
     prelude::{
         Interpreter,
         IntoChecked,
     },
-    state::ProgramState,
+    state::{
+        Breakpoint,
+        ProgramState,
+    },
 };
 
 #[test]
@@ -74,3 +80,113 @@ fn receipts_are_produced_correctly_with_stepping() {
     assert_eq!(receipts_without_debugger, receipts_with_debugger);
     assert_eq!(receipts_root_without_debugger, receipts_root_with_debugger);
 }
+
+#[test]
+fn paused_execution_resumes_to_the_same_receipts_after_serialization() {
+    let script = vec![
+        op::movi(0x20, 1234),
+        op::log(0x20, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::movi(0x21, 32),
+        op::aloc(0x21),
+        op::logd(RegId::ZERO, RegId::ZERO, RegId::HP, 0x21),
+        op::movi(0x20, 5678),
+        op::log(0x20, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+
+    let params = ConsensusParameters::standard();
+    let tx = TransactionBuilder::script(script, Vec::new())
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize()
+        .into_checked(Default::default(), &params)
+        .expect("failed to check tx")
+        .into_ready(0, &GasCosts::default(), params.fee_params(), None)
+        .expect("failed to ready tx");
+
+    let mut vm = Interpreter::<_, _, Script>::with_memory_storage();
+    vm.transact(tx.clone()).expect("panicked");
+    let receipts_without_pause = vm.receipts().to_vec();
+    let receipts_root_without_pause = vm.transaction().receipts_root();
+
+    // Given
+    let mut vm = Interpreter::<_, _, Script>::with_memory_storage();
+    vm.set_breakpoint(Breakpoint::script(5));
+    let state = *vm.transact(tx).expect("panicked").state();
+    assert!(matches!(state, ProgramState::RunProgram(_)));
+    let blob = bincode::serialize(&vm.pause()).expect("failed to serialize");
+    let storage = vm.as_ref().clone();
+    drop(vm);
+
+    // When
+    let paused: PausedExecution<Script> =
+        bincode::deserialize(&blob).expect("failed to deserialize");
+    let mut vm = Interpreter::from_paused(paused, storage);
+    let state = vm.resume().expect("panicked");
+
+    // Then
+    assert_eq!(state, ProgramState::Return(1));
+    assert_eq!(vm.receipts(), receipts_without_pause);
+    assert_eq!(
+        vm.transaction().receipts_root(),
+        receipts_root_without_pause
+    );
+}
+
+#[test]
+fn peek_instruction__returns_the_next_instruction_without_executing_it() {
+    // Given
+    let script: Vec<u8> = vec![
+        op::movi(0x20, 1234),
+        op::log(0x20, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let params = ConsensusParameters::standard();
+    let tx = TransactionBuilder::script(script, Vec::new())
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize()
+        .into_checked(Default::default(), &params)
+        .expect("failed to check tx")
+        .into_ready(0, &GasCosts::default(), params.fee_params(), None)
+        .expect("failed to ready tx");
+    let mut vm = Interpreter::<_, _, Script>::with_memory_storage();
+    vm.set_single_stepping(true);
+    vm.transact(tx).expect("panicked");
+    let pc = vm.registers()[RegId::PC];
+
+    // When
+    let instruction = vm.peek_instruction().expect("valid instruction");
+
+    // Then
+    assert_eq!(instruction, op::movi(0x20, 1234));
+    assert_eq!(vm.registers()[RegId::PC], pc);
+    assert_eq!(vm.registers()[0x20], 0);
+    vm.resume().expect("panicked");
+    assert_eq!(
+        vm.peek_instruction().expect("valid instruction"),
+        op::log(0x20, RegId::ZERO, RegId::ZERO, RegId::ZERO)
+    );
+}
+
+#[test]
+fn peek_instruction__fails_outside_of_executable_memory() {
+    // Given
+    let mut vm = Interpreter::<_, _, Script>::with_memory_storage();
+    vm.registers_mut()[RegId::IS] = 0;
+    vm.registers_mut()[RegId::SSP] = 8;
+    vm.registers_mut()[RegId::PC] = 8;
+
+    // When
+    let result = vm.peek_instruction();
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(RuntimeError::Recoverable(PanicReason::MemoryNotExecutable))
+    ));
+}
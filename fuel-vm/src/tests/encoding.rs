@@ -677,3 +677,59 @@ fn transaction_canonical_serialization_deserialization() {
         ),
     ]);
 }
+
+#[test]
+fn state_transition__encode_decode_roundtrip() {
+    use fuel_asm::op;
+
+    // Given
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::gtf_args(0x11, RegId::ZERO, GTFArgs::ScriptDataLength),
+        op::logd(RegId::ZERO, RegId::ZERO, 0x10, 0x11),
+        op::ret(RegId::ONE),
+    ];
+    let transition = TestBuilder::new(2322u64)
+        .start_script(script, vec![0xab; 8])
+        .fee_input()
+        .execute();
+    let (_, tx, receipts) = transition.clone().into_inner();
+    let breakpoint = Breakpoint::new([3; 32].into(), 4);
+    let states = [
+        ProgramState::Return(1),
+        ProgramState::ReturnData([1; 32].into()),
+        ProgramState::Revert(2),
+        ProgramState::RunProgram(DebugEval::Breakpoint(breakpoint)),
+        ProgramState::VerifyPredicate(DebugEval::Continue),
+    ];
+
+    for state in states {
+        let transition = StateTransition::new(state, tx.clone(), receipts.clone());
+
+        // When
+        let bytes = transition.encode();
+        let decoded = StateTransition::<Script>::decode(&bytes);
+
+        // Then
+        assert_eq!(bytes[0], StateTransition::<Script>::ENCODING_VERSION);
+        assert_eq!(decoded, Ok(transition));
+    }
+}
+
+#[test]
+fn state_transition__decode_fails_on_unknown_version() {
+    // Given
+    let transition = StateTransition::new(
+        ProgramState::Return(1),
+        TransactionBuilder::script(vec![], vec![]).finalize_as_transaction(),
+        vec![],
+    );
+    let mut bytes = transition.encode();
+    bytes[0] = StateTransition::<Transaction>::ENCODING_VERSION.wrapping_add(1);
+
+    // When
+    let result = StateTransition::<Transaction>::decode(&bytes);
+
+    // Then
+    result.expect_err("Expected the decoding to fail");
+}
@@ -14,7 +14,11 @@ use fuel_tx::{
     ScriptExecutionResult,
     TransactionBuilder,
 };
-use fuel_vm::prelude::*;
+use fuel_vm::{
+    interpreter::InterpreterParams,
+    prelude::*,
+    transactor::ExecutionRecorder,
+};
 use itertools::Itertools;
 use test_case::test_case;
 
@@ -225,3 +229,134 @@ fn complex_ecal_fn(val: u32, result: PanicReason) {
 
     assert_panics(receipts, result);
 }
+
+/// An ECAL opcode handler function, which stores the gas charged so far in `a`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GasChargedEcal;
+
+impl ::fuel_vm::interpreter::EcalHandler for GasChargedEcal {
+    fn ecal<M, S, Tx, V>(
+        vm: &mut ::fuel_vm::prelude::Interpreter<M, S, Tx, Self, V>,
+        a: RegId,
+        _: RegId,
+        _: RegId,
+        _: RegId,
+    ) -> ::fuel_vm::error::SimpleResult<()> {
+        vm.registers_mut()[a] = vm.gas_charged_so_far();
+        Ok(())
+    }
+}
+
+#[test]
+fn ecal_handler_reads_the_gas_charged_so_far() {
+    let vm: Interpreter<_, _, Script, GasChargedEcal> =
+        Interpreter::with_memory_storage();
+
+    let script = vec![
+        op::noop(),
+        op::noop(),
+        op::ecal(0x20, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::log(0x20, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+
+    let mut client = MemoryClient::from_txtor(vm.into());
+    let consensus_params = ConsensusParameters::standard();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .maturity(Default::default())
+        .add_fee_input()
+        .finalize()
+        .into_checked(Default::default(), &consensus_params)
+        .expect("failed to generate a checked tx");
+    client.transact(tx);
+    let receipts = client.receipts().expect("Expected receipts");
+
+    let Receipt::Log { ra, .. } = receipts.first().unwrap() else {
+        panic!("Expected a log receipt");
+    };
+    let Receipt::ScriptResult { gas_used, .. } = receipts.last().unwrap() else {
+        panic!("Expected a result receipt");
+    };
+    assert!(*ra > 0);
+    assert!(*ra < *gas_used);
+}
+
+/// An ECAL opcode handler function, which stores a value only the host knows in `a`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HostValueEcal;
+
+impl ::fuel_vm::interpreter::EcalHandler for HostValueEcal {
+    fn ecal<M, S, Tx, V>(
+        vm: &mut ::fuel_vm::prelude::Interpreter<M, S, Tx, Self, V>,
+        a: RegId,
+        _: RegId,
+        _: RegId,
+        _: RegId,
+    ) -> ::fuel_vm::error::SimpleResult<()> {
+        vm.registers_mut()[a] = 0xfeed;
+        Ok(())
+    }
+}
+
+#[test]
+fn execution_recorder__replays_the_same_receipts_on_another_storage() {
+    let script = vec![
+        op::bhei(0x10),
+        op::time(0x11, 0x10),
+        op::subi(0x12, 0x10, 1),
+        op::movi(0x13, 32),
+        op::aloc(0x13),
+        op::bhsh(RegId::HP, 0x12),
+        op::logd(RegId::ZERO, RegId::ZERO, RegId::HP, 0x13),
+        op::cb(RegId::HP),
+        op::logd(RegId::ZERO, RegId::ZERO, RegId::HP, 0x13),
+        op::ecal(0x14, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::log(0x10, 0x11, 0x14, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let consensus_params = ConsensusParameters::standard();
+    let interpreter_params = InterpreterParams::new(0, &consensus_params);
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .maturity(Default::default())
+        .add_fee_input()
+        .finalize();
+    let check = |tx: Script| {
+        tx.into_checked(Default::default(), &consensus_params)
+            .expect("failed to generate a checked tx")
+    };
+
+    // Given
+    let storage = MemoryStorage::new(10.into(), ContractId::from([1; 32]));
+    let mut recorder = ExecutionRecorder::<_, _, Script, HostValueEcal>::new(
+        MemoryInstance::new(),
+        storage,
+        interpreter_params.clone(),
+    );
+    let log = recorder.transact(check(tx.clone()));
+    let receipts = recorder.transactor().receipts().expect("Expected receipts");
+    assert_eq!(log.block_height, Some(10.into()));
+    assert_eq!(log.ecal_results.len(), 1);
+
+    // When
+    let other_storage = MemoryStorage::new(20.into(), ContractId::from([2; 32]));
+    let mut replayer = Transactor::<_, _, Script, _>::with_replay_log(
+        MemoryInstance::new(),
+        other_storage,
+        interpreter_params,
+        log,
+    );
+    replayer.transact(check(tx));
+
+    // Then
+    let Some(Receipt::Log { ra, rc, .. }) = receipts.get(2) else {
+        panic!("Expected a log receipt");
+    };
+    assert_eq!((*ra, *rc), (10, 0xfeed));
+    assert_eq!(replayer.receipts(), Some(receipts));
+}
@@ -6,6 +6,7 @@ use alloc::{
 
 use crate::{
     consts::*,
+    interpreter::InterpreterParams,
     prelude::*,
     script_with_data_offset,
     tests::test_helpers::assert_success,
@@ -393,6 +394,306 @@ fn repeated_nested_calls() {
     }
 }
 
+#[test]
+fn nested_calls_panic_when_max_call_depth_is_reached() {
+    let max_call_depth = 3;
+    let mut test_context = TestBuilder::new(2322u64);
+
+    // setup a contract which calls itself
+    let contract_id = test_context
+        .setup_contract(
+            vec![
+                op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+                op::ret(RegId::ONE),
+            ],
+            None,
+            None,
+        )
+        .contract_id;
+
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data = Call::new(contract_id, 0, 1000).to_bytes();
+
+    let tx = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+
+    let interpreter_params = InterpreterParams {
+        max_call_depth: Some(max_call_depth),
+        ..InterpreterParams::new(0, ConsensusParameters::standard())
+    };
+    let mut transactor = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        interpreter_params,
+    );
+    transactor.transact(tx);
+    let receipts = transactor.receipts().expect("Failed to transact");
+
+    let calls = receipts
+        .iter()
+        .filter(|r| matches!(r, Receipt::Call { .. }))
+        .count();
+    assert_eq!(calls, max_call_depth);
+
+    let Receipt::Panic { reason, .. } = receipts[receipts.len() - 2] else {
+        unreachable!("No panic receipt for a panicked tx");
+    };
+    assert_eq!(*reason.reason(), PanicReason::MaxCallDepthReached);
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+struct RecordingCallObserver {
+    events: std::sync::Mutex<Vec<(Option<ContractId>, Word)>>,
+}
+
+#[cfg(feature = "std")]
+impl CallObserver for RecordingCallObserver {
+    fn on_call(&self, frame: &CallFrame, gas_before: Word) {
+        self.events
+            .lock()
+            .unwrap()
+            .push((Some(*frame.to()), gas_before));
+    }
+
+    fn on_return(&self, gas_after: Word) {
+        self.events.lock().unwrap().push((None, gas_after));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn call_observer__records_the_entered_and_left_frames() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract_id = test_context
+        .setup_contract(vec![op::noop(), op::ret(RegId::ONE)], None, None)
+        .contract_id;
+
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+        op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    let tx = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+
+    // Given
+    let observer = std::sync::Arc::new(RecordingCallObserver::default());
+    let mut transactor = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+    transactor.set_call_observer(Some(observer.clone()));
+
+    // When
+    transactor.transact(tx);
+
+    // Then
+    assert!(transactor.is_success());
+    let events = observer.events.lock().unwrap();
+    let targets: Vec<_> = events.iter().map(|(to, _)| *to).collect();
+    assert_eq!(
+        targets,
+        vec![Some(contract_id), None, Some(contract_id), None]
+    );
+    let gas: Vec<_> = events.iter().map(|(_, gas)| *gas).collect();
+    assert!(gas.windows(2).all(|w| w[0] >= w[1]));
+    assert!(gas[0] > gas[1]);
+}
+
+#[cfg(feature = "opcode-histogram")]
+#[test]
+fn opcode_histogram_counts_instructions_of_called_contracts() {
+    use fuel_asm::Opcode;
+
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract_id = test_context
+        .setup_contract(
+            vec![op::noop(), op::noop(), op::ret(RegId::ONE)],
+            None,
+            None,
+        )
+        .contract_id;
+
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+        op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+    let tx = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+
+    let mut transactor = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+    transactor.transact(tx);
+    assert_success(transactor.receipts().expect("Failed to transact"));
+
+    let histogram = transactor.interpreter().opcode_histogram();
+    let expected = [
+        (Opcode::GTF, 1),
+        (Opcode::CALL, 2),
+        (Opcode::NOOP, 4),
+        (Opcode::RET, 3),
+    ];
+    assert_eq!(histogram.len(), expected.len());
+    for (opcode, count) in expected {
+        assert_eq!(histogram.get(&opcode), Some(&count), "{opcode:?}");
+    }
+}
+
+#[test]
+fn gas_by_contract__attributes_gas_to_the_called_contracts() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let returning = test_context
+        .setup_contract(
+            vec![op::noop(), op::noop(), op::ret(RegId::ONE)],
+            None,
+            None,
+        )
+        .contract_id;
+    let reverting = test_context
+        .setup_contract(vec![op::noop(), op::rvrt(RegId::ONE)], None, None)
+        .contract_id;
+
+    // Given
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::addi(0x11, 0x10, Call::LEN as u16),
+        op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+        op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+        op::call(0x11, RegId::ZERO, 0x10, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let mut script_data = Call::new(returning, 0, 0).to_bytes();
+    script_data.extend(Call::new(reverting, 0, 0).to_bytes());
+    let tx = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(returning)
+        .contract_input(reverting)
+        .fee_input()
+        .contract_output(&returning)
+        .contract_output(&reverting)
+        .build();
+    let params = ConsensusParameters::standard();
+    let gas_costs = params.gas_costs().clone();
+    let mut transactor = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, params),
+    );
+    transactor.set_gas_by_contract_enabled(true);
+
+    // When
+    transactor.transact(tx);
+    let gas_by_contract = transactor.gas_by_contract();
+
+    // Then
+    let returning_gas = 2 * (2 * gas_costs.noop() + gas_costs.ret());
+    let reverting_gas = gas_costs.noop() + gas_costs.rvrt();
+    assert_eq!(gas_by_contract.len(), 2);
+    assert_eq!(gas_by_contract.get(&returning), Some(&returning_gas));
+    assert_eq!(gas_by_contract.get(&reverting), Some(&reverting_gas));
+}
+
+#[test]
+fn calls_to_preloaded_contract_match_the_cold_path() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract_id = test_context
+        .setup_contract(
+            vec![
+                op::movi(0x10, 0x2a),
+                op::log(0x10, RegId::FP, RegId::IS, RegId::PC),
+                op::ret(RegId::ONE),
+            ],
+            None,
+            None,
+        )
+        .contract_id;
+
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+        op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+    let tx = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+    let interpreter_params = InterpreterParams::new(0, ConsensusParameters::standard());
+
+    let mut cold = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        interpreter_params.clone(),
+    );
+    cold.transact(tx.clone());
+    let expected = cold.receipts().expect("Failed to transact").to_vec();
+    assert_success(&expected);
+
+    let interpreter = Interpreter::<_, _, Script>::with_preloaded_contract(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        interpreter_params,
+        contract_id,
+    )
+    .expect("The contract is deployed");
+    let mut preloaded = Transactor::from(interpreter);
+    for _ in 0..2 {
+        preloaded.transact(tx.clone());
+        let receipts = preloaded.receipts().expect("Failed to transact");
+        assert_eq!(receipts, expected.as_slice());
+    }
+}
+
+#[test]
+fn preloading_a_missing_contract_fails() {
+    let result = Interpreter::<_, _, Script>::with_preloaded_contract(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams::default(),
+        ContractId::zeroed(),
+    );
+
+    assert!(matches!(
+        result,
+        Err(InterpreterError::Panic(PanicReason::ContractNotFound))
+    ));
+}
+
 #[test]
 fn revert() {
     let mut test_context = TestBuilder::new(2322u64);
@@ -586,3 +887,89 @@ fn logd_from_top_of_heap() {
     assert_eq!(receipts.len(), 3);
     assert!(matches!(receipts[0], Receipt::LogData { .. }));
 }
+
+#[test]
+fn context__is_script_after_executing_a_script() {
+    let mut vm = Interpreter::<_, _, Script>::with_memory_storage();
+
+    vm.execute_raw(&[op::ret(RegId::ONE)], 1_000);
+
+    assert!(matches!(vm.context(), Context::Script { .. }));
+    assert!(vm.context().is_external());
+    assert!(!vm.context().is_predicate());
+}
+
+#[test]
+fn final_pc__points_after_the_returning_instruction() {
+    let mut vm = Interpreter::<_, _, Script>::with_memory_storage();
+
+    // When
+    let receipts = vm.execute_raw(&[op::noop(), op::ret(RegId::ONE)], 1_000);
+
+    // Then
+    assert_success(&receipts);
+    let Receipt::Return { pc, is, .. } = receipts[0] else {
+        panic!("Expected a return receipt");
+    };
+    assert_eq!(vm.final_is(), is);
+    assert_eq!(vm.final_pc(), pc + Instruction::SIZE as Word);
+    assert_eq!(vm.final_pc(), vm.final_is() + 2 * Instruction::SIZE as Word);
+}
+
+#[test]
+fn final_pc__points_at_the_reverting_instruction() {
+    let mut vm = Interpreter::<_, _, Script>::with_memory_storage();
+
+    // When
+    let receipts = vm.execute_raw(&[op::noop(), op::rvrt(RegId::ONE)], 1_000);
+
+    // Then
+    let Receipt::Revert { pc, is, .. } = receipts[0] else {
+        panic!("Expected a revert receipt");
+    };
+    assert_eq!(vm.final_is(), is);
+    assert_eq!(vm.final_pc(), pc);
+    assert_eq!(vm.final_pc(), vm.final_is() + Instruction::SIZE as Word);
+}
+
+#[test]
+fn unknown_opcode__panics_by_default() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let mut script: Vec<u8> = vec![op::noop()].into_iter().collect();
+    script.extend([0xff; 4]);
+
+    test_context
+        .start_script_bytes(script, vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .execute_expect_panic(PanicReason::InvalidInstruction);
+}
+
+#[test]
+fn unknown_opcode__returns_an_error_when_configured() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let mut script: Vec<u8> = vec![op::noop()].into_iter().collect();
+    script.extend([0xff; 4]);
+
+    // Given
+    let tx = test_context
+        .start_script_bytes(script, vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build();
+    let params = InterpreterParams::builder(ConsensusParameters::standard())
+        .error_on_unknown_opcode(true)
+        .build();
+    let mut transactor = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        params,
+    );
+
+    // When
+    transactor.transact(tx);
+
+    // Then
+    let error = transactor.result().expect_err("Expected an error");
+    assert_eq!(error, &InterpreterError::UnknownOpcode(0xffff_ffff));
+}
@@ -15,6 +15,7 @@ use test_helpers::{
     assert_panics,
     assert_success,
     run_script,
+    run_script_full,
     set_full_word,
 };
 
@@ -40,6 +41,28 @@ fn all_registers_can_be_logged() {
     }
 }
 
+#[test]
+fn logd_logs_script_data() {
+    let script_data = vec![0xab; 8];
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::gtf_args(0x11, RegId::ZERO, GTFArgs::ScriptDataLength),
+        op::logd(RegId::ZERO, RegId::ZERO, 0x10, 0x11),
+        op::ret(RegId::ONE),
+    ];
+
+    let (receipts, state) = run_script_full(script, script_data.clone());
+    assert_success(&receipts);
+    assert_eq!(state.receipts(), receipts.as_slice());
+    assert_eq!(*state.state(), fuel_vm::state::ProgramState::Return(1));
+
+    if let Receipt::LogData { data, .. } = &receipts[0] {
+        assert_eq!(data.as_deref(), Some(script_data.as_slice()));
+    } else {
+        unreachable!("Expected a log data receipt");
+    }
+}
+
 #[test]
 fn logd_memory_range_overflow() {
     let script = vec![
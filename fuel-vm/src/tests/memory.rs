@@ -15,7 +15,10 @@ use fuel_asm::{
 use fuel_tx::Receipt;
 use fuel_vm::{
     consts::VM_MAX_RAM,
-    interpreter::InterpreterParams,
+    interpreter::{
+        InterpreterParams,
+        MemoryGrowthError,
+    },
     prelude::*,
 };
 
@@ -293,6 +296,61 @@ fn test_stack_and_heap_cannot_overlap(offset: u64, cause_error: bool) {
     }
 }
 
+#[test_case(0 => None; "heap fills all memory up to the stack")]
+#[test_case(1 => Some(PanicReason::MemoryGrowthOverlap); "heap grows into the stack")]
+fn heap_growth_into_the_stack_is_reported_as_overlap(extra: u16) -> Option<PanicReason> {
+    let ops = vec![
+        op::sub(0x10, RegId::HP, RegId::SP),
+        op::addi(0x10, 0x10, extra),
+        op::aloc(0x10),
+        op::ret(RegId::ONE),
+    ];
+
+    let vm = setup(ops);
+
+    vm.receipts()
+        .unwrap()
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::Panic { reason, .. } => Some(*reason.reason()),
+            _ => None,
+        })
+}
+
+#[test]
+fn stack_growth_into_the_heap__keeps_the_pointers_and_the_requested_size() {
+    // Given
+    let ops = vec![
+        op::sub(0x10, RegId::HP, RegId::SP),
+        op::addi(0x10, 0x10, 1),
+        op::log(RegId::SP, RegId::HP, RegId::ZERO, RegId::ZERO),
+        op::cfe(0x10),
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let vm = setup(ops);
+
+    // Then
+    let receipts = vm.receipts().unwrap();
+    let Receipt::Log { ra: sp, rb: hp, .. } = receipts[0] else {
+        panic!("Expected a log receipt, got {:?}", receipts[0]);
+    };
+    assert!(matches!(
+        receipts[1],
+        Receipt::Panic { reason, .. } if *reason.reason() == PanicReason::MemoryGrowthOverlap
+    ));
+    assert_eq!(
+        vm.interpreter().memory().last_growth_error(),
+        Some(MemoryGrowthError {
+            reason: PanicReason::MemoryGrowthOverlap,
+            sp,
+            hp,
+            requested: hp + 1,
+        })
+    );
+}
+
 /// tests for cfe & cfs
 #[test]
 fn dynamic_call_frame_ops() {
@@ -632,3 +690,63 @@ fn test_heap_allocation_zeroes_memory() {
         panic!("Expected return receipt");
     }
 }
+
+#[test]
+fn execute_raw__runs_the_instructions_as_a_script() {
+    let mut vm = Interpreter::<_, _, Script>::with_memory_storage();
+
+    let receipts = vm.execute_raw(&[op::movi(0x10, 42), op::ret(0x10)], 1_000);
+
+    assert_success(&receipts);
+    assert!(matches!(receipts[0], Receipt::Return { val: 42, .. }));
+}
+
+#[test]
+fn execute_raw__enforces_the_memory_bounds() {
+    let mut vm = Interpreter::<_, _, Script>::with_memory_storage();
+    let mut script = set_full_word(0x10, VM_MAX_RAM);
+    script.extend([op::lw(0x11, 0x10, 0), op::ret(RegId::ONE)]);
+
+    let receipts = vm.execute_raw(&script, 1_000);
+
+    assert_panics(&receipts, PanicReason::MemoryOverflow);
+}
+
+#[test]
+fn execute_raw__enforces_the_gas_limit() {
+    let mut vm = Interpreter::<_, _, Script>::with_memory_storage();
+
+    let receipts = vm.execute_raw(&[op::noop(), op::jmpb(RegId::ZERO, 0)], 1_000);
+
+    assert_panics(&receipts, PanicReason::OutOfGas);
+}
+
+#[test]
+fn estimate_memory_bound__covers_the_memory_used_by_the_script() {
+    let consensus_params = ConsensusParameters::standard();
+    let script = vec![
+        op::movi(0x10, 1024),
+        op::aloc(0x10),
+        op::cfei(512),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000)
+        .add_fee_input()
+        .finalize()
+        .into_checked(Default::default(), &consensus_params)
+        .expect("failed to check tx");
+    let mut vm = Interpreter::<_, _, Script>::with_memory_storage();
+
+    let bound = vm.estimate_memory_bound(tx.transaction());
+
+    let tx = tx
+        .into_ready(0, vm.gas_costs(), consensus_params.fee_params(), None)
+        .expect("failed to ready tx");
+    vm.transact(tx).expect("failed to transact");
+    let used = vm.registers()[RegId::SP] + (VM_MAX_RAM - vm.registers()[RegId::HP]);
+    assert!(bound as Word >= used);
+    assert!(bound <= fuel_vm::consts::MEM_SIZE);
+}
@@ -16,6 +16,7 @@ use crate::{
         UploadedBytecode,
         predicate::EmptyStorage,
     },
+    util::test_helpers::TestBuilder,
 };
 use fuel_asm::{
     GMArgs,
@@ -33,11 +34,15 @@ use fuel_tx::{
     field::{
         Inputs,
         Outputs,
+        Policies,
         ReceiptsRoot,
         Script as ScriptField,
         Witnesses,
     },
-    policies::PoliciesBits,
+    policies::{
+        PoliciesBits,
+        PolicyType,
+    },
 };
 use fuel_types::{
     BlockHeight,
@@ -340,6 +345,91 @@ fn get_metadata_base_asset_id() {
     }
 }
 
+#[test]
+fn get_metadata_base_asset_id_reflects_interpreter_params_override() {
+    let gas_limit = 1_000_000;
+    let height = BlockHeight::default();
+    let mut storage = MemoryStorage::default();
+
+    let params = ConsensusParameters::standard();
+    let override_asset_id = AssetId::from([7; 32]);
+    assert_ne!(*params.base_asset_id(), override_asset_id);
+
+    let script = TransactionBuilder::script(
+        vec![
+            op::gm_args(0x20, GMArgs::BaseAssetId),
+            op::movi(0x21, AssetId::LEN.try_into().unwrap()),
+            op::logd(RegId::ZERO, RegId::ZERO, 0x20, 0x21),
+            op::ret(RegId::ONE),
+        ]
+        .into_iter()
+        .collect(),
+        vec![],
+    )
+    .script_gas_limit(gas_limit)
+    .add_fee_input()
+    .finalize()
+    .into_checked(height, &params)
+    .unwrap();
+
+    let mut interpreter_params = InterpreterParams::new(0, &params);
+    assert_eq!(interpreter_params.base_asset_id, *params.base_asset_id());
+    interpreter_params.base_asset_id = override_asset_id;
+
+    let receipts = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        &mut storage,
+        interpreter_params,
+    )
+    .transact(script)
+    .receipts()
+    .expect("Failed to transact")
+    .to_owned();
+
+    if let Receipt::LogData { data, .. } = receipts[0].clone() {
+        assert_eq!(data.unwrap(), override_asset_id.to_bytes());
+    } else {
+        panic!("expected LogData receipt, instead of {:?}", receipts[0]);
+    }
+}
+
+#[test]
+fn memory_base_asset_id__returns_the_configured_base_asset_id() {
+    let height = BlockHeight::default();
+    let mut storage = MemoryStorage::default();
+
+    let mut params = ConsensusParameters::standard();
+    params.set_base_asset_id(AssetId::from([5; 32]));
+
+    let script = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .script_gas_limit(1_000_000)
+    .add_fee_input()
+    .finalize()
+    .into_checked(height, &params)
+    .unwrap();
+
+    // Given
+    let mut transactor = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        &mut storage,
+        InterpreterParams::new(0, &params),
+    );
+    assert_eq!(transactor.interpreter().memory_base_asset_id(), None);
+
+    // When
+    transactor.transact(script);
+
+    // Then
+    assert!(transactor.is_success());
+    assert_eq!(
+        transactor.interpreter().memory_base_asset_id(),
+        Some(*params.base_asset_id())
+    );
+}
+
 #[test]
 fn get_metadata_tx_start() {
     let gas_limit = 1_000_000;
@@ -375,6 +465,52 @@ fn get_metadata_tx_start() {
     }
 }
 
+#[test]
+fn interpreter_accessors__match_the_metadata_of_gm() {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let base_asset_id: AssetId = rng.r#gen();
+
+    // Given
+    let interpreter_params = InterpreterParams {
+        chain_id: rng.r#gen(),
+        gas_price: 7,
+        base_asset_id,
+        ..Default::default()
+    };
+    let mut vm = Interpreter::<_, _, Script>::with_storage(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        interpreter_params,
+    );
+
+    // When
+    let receipts = vm.execute_raw(
+        &[
+            op::gm_args(0x10, GMArgs::GetChainId),
+            op::gm_args(0x11, GMArgs::GetGasPrice),
+            op::gm_args(0x12, GMArgs::TxStart),
+            op::gm_args(0x13, GMArgs::BaseAssetId),
+            op::log(0x10, 0x11, 0x12, RegId::ZERO),
+            op::movi(0x14, AssetId::LEN as u32),
+            op::logd(RegId::ZERO, RegId::ZERO, 0x13, 0x14),
+            op::ret(RegId::ONE),
+        ],
+        1_000_000,
+    );
+
+    // Then
+    let Receipt::Log { ra, rb, rc, .. } = receipts[0] else {
+        panic!("expected log receipt, instead of {:?}", receipts[0])
+    };
+    assert_eq!(ra, *vm.chain_id());
+    assert_eq!(rb, vm.gas_price());
+    assert_eq!(rc, vm.tx_offset() as Word);
+    let Receipt::LogData { data, .. } = &receipts[1] else {
+        panic!("expected log data receipt, instead of {:?}", receipts[1])
+    };
+    assert_eq!(data.as_deref(), Some(vm.base_asset_id().as_ref()));
+}
+
 #[test]
 fn get_metadata__gas_price__script() {
     // given
@@ -1557,3 +1693,134 @@ fn get__upgrade_specific_transaction_fields__success() {
     // Then
     client.upgrade(tx).unwrap();
 }
+
+#[test]
+fn resolved_tip_defaults_to_zero_when_policy_is_unset() {
+    let height = BlockHeight::default();
+    let params = ConsensusParameters::standard();
+
+    let tx = TransactionBuilder::script(
+        vec![
+            op::gtf_args(0x10, RegId::ZERO, GTFArgs::PolicyTip),
+            op::ret(0x10),
+        ]
+        .into_iter()
+        .collect(),
+        vec![],
+    )
+    .script_gas_limit(1_000_000)
+    .add_fee_input()
+    .finalize();
+    assert_eq!(tx.policies().get(PolicyType::Tip), None);
+    let tx = tx.into_checked(height, &params).unwrap();
+
+    let mut storage = MemoryStorage::default();
+    let mut transactor = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        &mut storage,
+        InterpreterParams::new(0, &params),
+    );
+    transactor.transact(tx);
+
+    // The raw policy arg still panics when the policy is unset.
+    let receipts = transactor.receipts().expect("Failed to transact");
+    assert!(matches!(
+        receipts[0],
+        Receipt::Panic { reason, .. } if *reason.reason() == PanicReason::PolicyIsNotSet
+    ));
+
+    assert_eq!(transactor.interpreter().resolved_tip(), 0);
+}
+
+#[test]
+fn resolved_tip_and_max_fee_match_gtf_when_policies_are_set() {
+    let height = BlockHeight::default();
+    let params = ConsensusParameters::standard();
+    let tip = 10;
+    let max_fee = 1_000;
+
+    #[rustfmt::skip]
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::PolicyTip),
+        op::gtf_args(0x11, RegId::ZERO, GTFArgs::PolicyMaxFee),
+        op::log(0x10, 0x11, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .tip(tip)
+        .max_fee_limit(max_fee)
+        .add_fee_input()
+        .finalize()
+        .into_checked(height, &params)
+        .unwrap();
+
+    let mut storage = MemoryStorage::default();
+    let mut transactor = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        &mut storage,
+        InterpreterParams::new(0, &params),
+    );
+    transactor.transact(tx);
+
+    let receipts = transactor.receipts().expect("Failed to transact");
+    let Receipt::Log { ra, rb, .. } = receipts[0] else {
+        panic!("expected Log receipt, instead of {:?}", receipts[0]);
+    };
+    assert_eq!(ra, tip);
+    assert_eq!(rb, max_fee);
+
+    assert_eq!(transactor.interpreter().resolved_tip(), tip);
+    assert_eq!(transactor.interpreter().resolved_max_fee(), max_fee);
+}
+
+#[test]
+fn input_contract_output_index_matches_gtf() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract_id = test_context
+        .setup_contract(vec![op::ret(RegId::ONE)], None, None)
+        .contract_id;
+
+    // The fee input goes first, so the contract input is at index 1.
+    let contract_input_index = 1;
+    let script = vec![
+        op::movi(0x11, contract_input_index),
+        op::gtf_args(0x10, 0x11, GTFArgs::InputContractOutputIndex),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let tx = test_context
+        .start_script(script, vec![])
+        .fee_input()
+        .contract_input(contract_id)
+        .change_output(AssetId::BASE)
+        .contract_output(&contract_id)
+        .build();
+
+    let mut transactor = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+    transactor.transact(tx);
+
+    let receipts = transactor.receipts().expect("Failed to transact");
+    let Receipt::Log { ra, .. } = receipts[0] else {
+        panic!("expected Log receipt, instead of {:?}", receipts[0]);
+    };
+
+    let interpreter = transactor.interpreter();
+    let contract_input_index = u16::try_from(contract_input_index).unwrap();
+    assert_eq!(
+        interpreter.input_contract_output_index(contract_input_index),
+        Some(1)
+    );
+    assert_eq!(
+        interpreter
+            .input_contract_output_index(contract_input_index)
+            .map(Word::from),
+        Some(ra)
+    );
+    // The fee input is a coin, so it has no contract output.
+    assert_eq!(interpreter.input_contract_output_index(0), None);
+}
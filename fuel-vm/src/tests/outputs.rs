@@ -92,6 +92,37 @@ fn used_gas_is_deducted_from_base_asset_change_on_revert() {
     assert!(change < input_amount);
 }
 
+#[test]
+fn used_gas_is_deducted_from_base_asset_change_on_panic() {
+    let mut rng = StdRng::seed_from_u64(2322u64);
+    let base_asset_id = rng.r#gen();
+    let input_amount = 1000;
+    let gas_price = 1;
+
+    let state = TestBuilder::new(2322u64)
+        .start_script(
+            vec![
+                // Log some dummy data to burn extra gas
+                op::log(RegId::ONE, RegId::ONE, RegId::ONE, RegId::ONE),
+                // Divide by zero to panic
+                op::div(0x10, RegId::ONE, RegId::ZERO),
+                op::ret(RegId::ONE),
+            ]
+            .into_iter()
+            .collect(),
+            vec![],
+        )
+        .max_fee_limit(1000)
+        .gas_price(gas_price)
+        .base_asset_id(base_asset_id)
+        .coin_input(base_asset_id, input_amount)
+        .change_output(base_asset_id)
+        .execute_expect_panic(PanicReason::ArithmeticError);
+    let change = find_change(state.tx().outputs().to_vec(), base_asset_id);
+
+    assert!(change < input_amount);
+}
+
 #[test]
 fn correct_change_is_provided_for_coin_outputs_script() {
     let mut rng = StdRng::seed_from_u64(2322u64);
@@ -110,6 +141,62 @@ fn correct_change_is_provided_for_coin_outputs_script() {
     assert_eq!(change, input_amount - spend_amount);
 }
 
+#[test]
+fn preview_change__matches_the_change_of_the_execution() {
+    let mut rng = StdRng::seed_from_u64(2322u64);
+    let asset_id: AssetId = rng.r#gen();
+    let gas_price = 1;
+    let mut test_context = TestBuilder::new(2322u64);
+    let base_asset_id = *test_context.get_base_asset_id();
+
+    // Given
+    let tx = test_context
+        .start_script(
+            vec![
+                // Log some dummy data to burn extra gas
+                op::log(RegId::ONE, RegId::ONE, RegId::ONE, RegId::ONE),
+                op::ret(RegId::ONE),
+            ],
+            vec![],
+        )
+        .max_fee_limit(1000)
+        .gas_price(gas_price)
+        .coin_input(base_asset_id, 1000)
+        .coin_input(asset_id, 500)
+        .change_output(base_asset_id)
+        .change_output(asset_id)
+        .build();
+    let state = test_context
+        .execute_tx(tx.clone())
+        .expect("expected successful vm execution");
+    let Some(Receipt::ScriptResult { gas_used, .. }) = state.receipts().last() else {
+        unreachable!("The last receipt is the script result");
+    };
+
+    // When
+    let change = tx
+        .preview_change(
+            *gas_used,
+            gas_price,
+            test_context.get_gas_costs(),
+            test_context.get_fee_params(),
+        )
+        .expect("The change should not overflow");
+
+    // Then
+    assert_eq!(
+        change,
+        vec![
+            (
+                base_asset_id,
+                find_change(state.tx().outputs().to_vec(), base_asset_id)
+            ),
+            (asset_id, 500),
+        ]
+    );
+    assert!(change[0].1 < 1000);
+}
+
 #[test]
 fn correct_change_is_provided_for_coin_outputs_create() {
     let mut rng = StdRng::seed_from_u64(2322u64);
@@ -178,3 +265,53 @@ fn correct_change_is_provided_for_coin_outputs_create() {
 
     assert_eq!(change, input_amount - spend_amount);
 }
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+struct RecordingOutputsObserver {
+    outputs: std::sync::Mutex<Vec<Vec<Output>>>,
+}
+
+#[cfg(feature = "std")]
+impl crate::transactor::OutputsObserver for RecordingOutputsObserver {
+    fn on_finalize_outputs(&self, outputs: &[Output]) {
+        self.outputs.lock().unwrap().push(outputs.to_vec());
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn outputs_observer__receives_the_final_change() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let base_asset_id = *test_context.get_base_asset_id();
+    let input_amount = 1000;
+    let tx = test_context
+        .gas_price(0)
+        .script_gas_limit(1_000_000)
+        .coin_input(base_asset_id, input_amount)
+        .change_output(base_asset_id)
+        .build();
+
+    // Given
+    let observer = std::sync::Arc::new(RecordingOutputsObserver::default());
+    let mut transactor = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        crate::interpreter::InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+    transactor.set_outputs_observer(Some(observer.clone()));
+
+    // When
+    transactor.transact(tx);
+
+    // Then
+    assert!(transactor.is_success());
+    let observed = observer.outputs.lock().unwrap();
+    assert_eq!(observed.len(), 1);
+    assert_eq!(
+        find_change(observed[0].clone(), base_asset_id),
+        input_amount
+    );
+    let state = transactor.to_owned_state_transition().unwrap();
+    assert_eq!(observed[0], state.tx().outputs().to_vec());
+}
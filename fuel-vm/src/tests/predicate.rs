@@ -17,8 +17,16 @@ use rand::{
 use tokio_rayon::AsyncRayonHandle;
 
 use crate::{
-    error::PredicateVerificationFailed,
+    error::{
+        CryptoFailure,
+        PredicateVerificationFailed,
+    },
+    interpreter::InterpreterParams,
     pool::DummyPool,
+    predicate::{
+        PredicateResultCache,
+        predicate_cache_key,
+    },
     prelude::*,
 };
 
@@ -32,9 +40,19 @@ use crate::{
     },
     prelude::predicates::{
         check_predicates,
+        check_predicates_all,
         check_predicates_async,
+        check_predicates_retaining_memory,
+        check_predicates_verbose,
+        estimate_predicates_verbose,
+    },
+    storage::{
+        BlobData,
+        predicate::{
+            EmptyStorage,
+            ReadOnlyStorage,
+        },
     },
-    storage::predicate::EmptyStorage,
 };
 use core::iter;
 use fuel_tx::{
@@ -199,6 +217,55 @@ fn estimate_predicate_works_when_predicate_address_incorrect() {
     assert_ne!(script.inputs()[0].predicate_gas_used(), Some(0));
 }
 
+#[test]
+fn estimate_predicates__reads_blobs_from_read_only_storage() {
+    let mut rng = StdRng::seed_from_u64(2322u64);
+
+    // Given
+    let blob_id = BlobId::zeroed();
+    let blob = vec![0xaa; 100];
+    let mut storage = MemoryStorage::default();
+    storage
+        .storage_as_mut::<BlobData>()
+        .insert(&blob_id, &blob)
+        .unwrap();
+
+    // Succeeds only if the blob is found in the storage
+    let predicate: Vec<u8> = [
+        op::gtf_args(0x10, 0x00, GTFArgs::InputCoinPredicateData),
+        op::bsiz(0x11, 0x10),
+        op::movi(0x12, blob.len() as u32),
+        op::eq(0x13, 0x11, 0x12),
+        op::ret(0x13),
+    ]
+    .into_iter()
+    .collect();
+    let input = Input::coin_predicate(
+        rng.r#gen(),
+        Input::predicate_owner(&predicate),
+        rng.r#gen(),
+        rng.r#gen(),
+        rng.r#gen(),
+        0,
+        predicate,
+        blob_id.to_vec(),
+    );
+    let mut script = TransactionBuilder::script(vec![], vec![])
+        .add_input(input)
+        .finalize();
+
+    // When
+    let result = script.estimate_predicates(
+        &ConsensusParameters::standard().into(),
+        MemoryInstance::new(),
+        &ReadOnlyStorage::new(&storage),
+    );
+
+    // Then
+    result.expect("Should estimate predicate");
+    assert_ne!(script.inputs()[0].predicate_gas_used(), Some(0));
+}
+
 #[test]
 fn estimate_predicate_works_when_max_gas_per_predicate_less_than_tx_gas__10_inputs() {
     let mut rng = StdRng::seed_from_u64(2322u64);
@@ -587,6 +654,78 @@ async fn gas_used_by_predicates_not_causes_out_of_gas_during_script() {
     );
 }
 
+#[test]
+fn transactor_total_gas_used_includes_predicate_gas() {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let gas_price = 1;
+    let coin_amount = 10_000_000;
+    let consensus_params = ConsensusParameters::standard();
+    let base_asset_id = *consensus_params.base_asset_id();
+
+    let scripts: [Vec<u8>; 2] = [
+        vec![],
+        vec![op::addi(0x20, 0x20, 1), op::ret(RegId::ONE)]
+            .into_iter()
+            .collect(),
+    ];
+    for script in scripts {
+        let predicate: Vec<u8> = vec![op::addi(0x20, 0x20, 1), op::ret(RegId::ONE)]
+            .into_iter()
+            .collect();
+        let owner = Input::predicate_owner(&predicate);
+        let mut transaction = TransactionBuilder::script(script, vec![])
+            .max_fee_limit(coin_amount)
+            .script_gas_limit(1_000)
+            .with_params(consensus_params.clone())
+            .add_input(Input::coin_predicate(
+                rng.r#gen(),
+                owner,
+                coin_amount,
+                base_asset_id,
+                rng.r#gen(),
+                0,
+                predicate,
+                vec![],
+            ))
+            .add_output(Output::change(rng.r#gen(), 0, base_asset_id))
+            .finalize();
+        transaction
+            .estimate_predicates(
+                &(&consensus_params).into(),
+                MemoryInstance::new(),
+                &EmptyStorage,
+            )
+            .expect("Predicate estimation failed");
+        let predicate_gas_used = transaction.inputs()[0]
+            .predicate_gas_used()
+            .expect("Input should be a predicate");
+        assert!(predicate_gas_used > 0);
+        let checked = transaction
+            .into_checked(Default::default(), &consensus_params)
+            .expect("Should successfully create checked transaction with predicate");
+        let min_gas = checked.metadata().min_gas;
+        assert!(min_gas > predicate_gas_used);
+
+        let mut transactor = Transactor::<_, _, _>::new(
+            MemoryInstance::new(),
+            MemoryStorage::default(),
+            InterpreterParams::new(gas_price, &consensus_params),
+        );
+        transactor.transact(checked);
+
+        let script_gas_used = transactor
+            .receipts()
+            .expect("Expected receipts")
+            .iter()
+            .find_map(Receipt::gas_used)
+            .expect("Expected script result");
+        let total_gas_used = transactor
+            .total_gas_used()
+            .expect("Transaction should be executed");
+        assert_eq!(total_gas_used, min_gas + script_gas_used);
+    }
+}
+
 #[tokio::test]
 async fn gas_used_by_predicates_more_than_limit() {
     let rng = &mut StdRng::seed_from_u64(2322u64);
@@ -739,3 +878,491 @@ fn synchronous_estimate_predicates_respects_total_tx_gas_limit() {
     // Then
     assert_eq!(Ok(()), result);
 }
+
+fn tx_with_predicates(predicates: Vec<Vec<Instruction>>) -> Script {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let params = CheckPredicateParams::from(&ConsensusParameters::standard());
+
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.script_gas_limit(1_000_000);
+    for predicate in predicates {
+        let predicate: Vec<u8> = predicate.into_iter().collect();
+        let owner = Input::predicate_owner(&predicate);
+        builder.add_input(Input::coin_predicate(
+            rng.r#gen(),
+            owner,
+            0,
+            rng.r#gen(),
+            rng.r#gen(),
+            0,
+            predicate,
+            vec![],
+        ));
+    }
+
+    let mut tx = builder.finalize();
+    tx.estimate_predicates(&params, MemoryInstance::new(), &EmptyStorage)
+        .expect("Should estimate predicates");
+    tx
+}
+
+#[derive(Default)]
+struct CountingPool {
+    taken: core::sync::atomic::AtomicUsize,
+}
+
+impl VmMemoryPool for CountingPool {
+    type Memory = MemoryInstance;
+
+    fn get_new(&self) -> impl core::future::Future<Output = Self::Memory> + Send {
+        self.taken
+            .fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+        core::future::ready(MemoryInstance::new())
+    }
+}
+
+#[tokio::test]
+async fn estimate_predicates_async__takes_the_memory_from_the_given_pool() {
+    let params = CheckPredicateParams::from(&ConsensusParameters::standard());
+    let mut tx =
+        tx_with_predicates(vec![vec![op::ret(RegId::ONE)], vec![op::ret(RegId::ONE)]]);
+    let pool = CountingPool::default();
+
+    tx.estimate_predicates_async::<TokioWithRayon>(&params, &pool, &EmptyStorage)
+        .await
+        .expect("Should estimate predicates");
+
+    assert_eq!(pool.taken.load(core::sync::atomic::Ordering::Relaxed), 2);
+}
+
+#[test]
+fn check_predicates_all__succeeds_when_all_predicates_are_true() {
+    let params = CheckPredicateParams::from(&ConsensusParameters::standard());
+    let tx = tx_with_predicates(vec![
+        vec![op::ret(RegId::ONE)],
+        vec![op::addi(0x10, RegId::ZERO, 1), op::ret(0x10)],
+    ]);
+
+    let result = check_predicates_all(&tx, &params, MemoryInstance::new(), &EmptyStorage);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn check_predicates_all__reports_the_first_failing_input() {
+    let params = CheckPredicateParams::from(&ConsensusParameters::standard());
+    let tx = tx_with_predicates(vec![
+        vec![op::ret(RegId::ONE)],
+        vec![op::ret(RegId::ZERO)],
+        vec![op::rvrt(RegId::ONE)],
+    ]);
+
+    let result = check_predicates_all(&tx, &params, MemoryInstance::new(), &EmptyStorage);
+
+    assert_eq!(
+        result,
+        Err(PredicateVerificationFailed::Panic {
+            index: 1,
+            reason: PanicReason::PredicateReturnedNonOne,
+        })
+    );
+}
+
+#[test]
+fn check_predicates_retaining_memory__returns_memory_of_the_failing_predicate() {
+    let params = CheckPredicateParams::from(&ConsensusParameters::standard());
+    let tx = tx_with_predicates(vec![
+        vec![op::ret(RegId::ONE)],
+        vec![
+            op::movi(0x10, 1),
+            op::aloc(0x10),
+            op::movi(0x11, 0xab),
+            op::sb(RegId::HP, 0x11, 0),
+            op::ret(RegId::ZERO),
+        ],
+    ]);
+    let checked = tx
+        .into_checked_basic(Default::default(), &ConsensusParameters::standard())
+        .expect("Should pass basic checks");
+
+    let failure = check_predicates_retaining_memory(
+        &checked,
+        &params,
+        MemoryInstance::new(),
+        &EmptyStorage,
+    )
+    .expect_err("The second predicate should fail");
+
+    assert_eq!(
+        failure.error,
+        PredicateVerificationFailed::Panic {
+            index: 1,
+            reason: PanicReason::PredicateReturnedNonOne,
+        }
+    );
+    let memory = failure.memory.expect("The memory should be retained");
+    assert_eq!(memory.heap_raw().last(), Some(&0xab));
+}
+
+#[test]
+fn check_predicates_verbose__collects_the_receipts_of_the_predicates() {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let consensus_params = ConsensusParameters::standard();
+    let params = CheckPredicateParams::from(&consensus_params);
+    let predicate: Vec<u8> = [
+        op::movi(0x10, 0xab),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let mut tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(1_000_000)
+        .add_input(Input::coin_predicate(
+            rng.r#gen(),
+            Input::predicate_owner(&predicate),
+            0,
+            rng.r#gen(),
+            rng.r#gen(),
+            0,
+            predicate,
+            vec![],
+        ))
+        .finalize();
+
+    // Given
+    let estimated = estimate_predicates_verbose(
+        &mut tx,
+        &params,
+        MemoryInstance::new(),
+        &EmptyStorage,
+    );
+    estimated.result.expect("Should estimate predicates");
+    let checked = tx
+        .into_checked_basic(Default::default(), &consensus_params)
+        .expect("Should pass basic checks");
+
+    // When
+    let verbose =
+        check_predicates_verbose(&checked, &params, MemoryInstance::new(), &EmptyStorage);
+    let consensus =
+        check_predicates(&checked, &params, MemoryInstance::new(), &EmptyStorage);
+
+    // Then
+    verbose.result.expect("Should pass in verbose mode");
+    assert_eq!(verbose.receipts.len(), 1);
+    let (index, receipts) = &verbose.receipts[0];
+    assert_eq!(*index, 0);
+    assert!(matches!(
+        receipts[..],
+        [
+            Receipt::Log { ra: 0xab, .. },
+            Receipt::Return { val: 1, .. }
+        ]
+    ));
+    assert!(matches!(
+        consensus,
+        Err(PredicateVerificationFailed::PanicInstruction { index: 0, instruction })
+            if *instruction.reason() == PanicReason::ContractInstructionNotAllowed
+    ));
+}
+
+#[test]
+fn check_predicates_all__reports_the_instruction_that_panicked() {
+    let params = CheckPredicateParams::from(&ConsensusParameters::standard());
+    let panicking_instruction = op::divi(0x10, RegId::ONE, 0);
+    let tx = tx_with_predicates(vec![vec![panicking_instruction, op::ret(RegId::ONE)]]);
+
+    let err = check_predicates_all(&tx, &params, MemoryInstance::new(), &EmptyStorage)
+        .expect_err("The predicate should panic");
+
+    assert!(matches!(
+        err,
+        PredicateVerificationFailed::PanicInstruction { index: 0, .. }
+    ));
+    assert_eq!(err.decoded_instruction(), Some(panicking_instruction));
+}
+
+#[test]
+fn check_predicates_all__reports_crypto_failures_when_enabled() {
+    // Given
+    let failing_recovery = vec![
+        op::movi(0x10, 128),
+        op::aloc(0x10),
+        op::eck1(RegId::HP, RegId::HP, RegId::HP),
+        op::ret(RegId::ONE),
+    ];
+    let tx = tx_with_predicates(vec![failing_recovery]);
+    let default_params = CheckPredicateParams::from(&ConsensusParameters::standard());
+    let params = CheckPredicateParams {
+        crypto_failure_to_host: true,
+        ..default_params.clone()
+    };
+
+    // When
+    let default_result =
+        check_predicates_all(&tx, &default_params, MemoryInstance::new(), &EmptyStorage);
+    let result = check_predicates_all(&tx, &params, MemoryInstance::new(), &EmptyStorage);
+
+    // Then
+    assert_eq!(default_result, Ok(()));
+    assert_eq!(
+        result,
+        Err(PredicateVerificationFailed::CryptoFailure {
+            index: 0,
+            failure: CryptoFailure::Secp256k1Recover,
+        })
+    );
+}
+
+#[test]
+fn run_predicate_with_registers__uses_the_overridden_registers() {
+    use crate::util::test_helpers::run_predicate_with_registers;
+
+    // Given
+    let predicate = vec![op::eq(0x12, 0x10, 0x11), op::ret(0x12)];
+    let registers = [(RegId::new(0x10), 42), (RegId::new(0x11), 42)];
+
+    // When
+    let seeded = run_predicate_with_registers(predicate.clone(), &registers);
+    let fresh = run_predicate_with_registers(predicate, &registers[..1]);
+
+    // Then
+    assert_eq!(seeded, Ok(()));
+    assert_eq!(fresh, Err(PanicReason::PredicateReturnedNonOne));
+}
+
+#[test]
+fn run_predicate_with_registers__fails_to_override_reserved_registers() {
+    use crate::util::test_helpers::run_predicate_with_registers;
+
+    // Given
+    let predicate = vec![op::ret(RegId::ONE)];
+
+    // When
+    let result = run_predicate_with_registers(predicate, &[(RegId::PC, 0)]);
+
+    // Then
+    assert_eq!(result, Err(PanicReason::ReservedRegisterNotWritable));
+}
+
+#[test]
+fn estimate_predicates__rejects_witnesses_above_the_limit() {
+    use fuel_tx::field::Witnesses;
+
+    // Given
+    let params = CheckPredicateParams {
+        max_witness_bytes: Some(64),
+        ..Default::default()
+    };
+    let mut tx = tx_with_predicates(vec![vec![op::ret(RegId::ONE)]]);
+    tx.witnesses_mut().push(vec![0; 65].into());
+
+    // When
+    let estimated = tx.estimate_predicates(&params, MemoryInstance::new(), &EmptyStorage);
+    let checked =
+        check_predicates_all(&tx, &params, MemoryInstance::new(), &EmptyStorage);
+
+    // Then
+    let expected = PredicateVerificationFailed::WitnessesTooLarge { size: 65, max: 64 };
+    assert_eq!(
+        estimated,
+        Err(CheckError::PredicateVerificationFailed(expected.clone()))
+    );
+    assert_eq!(checked, Err(expected));
+}
+
+#[test]
+fn estimate_predicates__accepts_witnesses_up_to_the_limit() {
+    use fuel_tx::field::Witnesses;
+
+    // Given
+    let params = CheckPredicateParams {
+        max_witness_bytes: Some(64),
+        ..Default::default()
+    };
+    let mut tx = tx_with_predicates(vec![vec![op::ret(RegId::ONE)]]);
+    tx.witnesses_mut().push(vec![0; 64].into());
+
+    // When
+    let estimated = tx.estimate_predicates(&params, MemoryInstance::new(), &EmptyStorage);
+
+    // Then
+    assert_eq!(estimated, Ok(()));
+}
+
+#[derive(Debug, Default)]
+struct RecordingPredicateCache {
+    keys: std::sync::Mutex<std::collections::HashSet<Bytes32>>,
+}
+
+impl PredicateResultCache for RecordingPredicateCache {
+    fn contains(&self, key: &Bytes32) -> bool {
+        self.keys.lock().unwrap().contains(key)
+    }
+
+    fn insert(&self, key: Bytes32) {
+        self.keys.lock().unwrap().insert(key);
+    }
+}
+
+#[test]
+fn check_predicates__records_successful_verifications_in_the_cache() {
+    let tx = tx_with_predicates(vec![vec![op::ret(RegId::ONE)]]);
+    let cache = std::sync::Arc::new(RecordingPredicateCache::default());
+
+    // Given
+    let params = CheckPredicateParams {
+        predicate_result_cache: Some(cache.clone()),
+        ..Default::default()
+    };
+
+    // When
+    let result = check_predicates_all(&tx, &params, MemoryInstance::new(), &EmptyStorage);
+
+    // Then
+    assert_eq!(result, Ok(()));
+    let key = predicate_cache_key(&tx, 0, &params);
+    assert_eq!(*cache.keys.lock().unwrap(), [key].into());
+}
+
+#[test]
+fn check_predicates__skips_the_verifications_found_in_the_cache() {
+    let tx = tx_with_predicates(vec![vec![op::ret(RegId::ZERO)]]);
+    let cache = std::sync::Arc::new(RecordingPredicateCache::default());
+    let params = CheckPredicateParams {
+        predicate_result_cache: Some(cache.clone()),
+        ..Default::default()
+    };
+    let uncached =
+        check_predicates_all(&tx, &params, MemoryInstance::new(), &EmptyStorage);
+    assert_eq!(
+        uncached,
+        Err(PredicateVerificationFailed::Panic {
+            index: 0,
+            reason: PanicReason::PredicateReturnedNonOne,
+        })
+    );
+
+    // Given
+    cache.insert(predicate_cache_key(&tx, 0, &params));
+
+    // When
+    let cached = check_predicates_all(&tx, &params, MemoryInstance::new(), &EmptyStorage);
+
+    // Then
+    assert_eq!(cached, Ok(()));
+}
+
+#[test]
+fn predicate_cache_key__depends_on_the_transaction_and_the_input() {
+    use fuel_tx::field::Witnesses;
+
+    // Given
+    let params = CheckPredicateParams::default();
+    let tx =
+        tx_with_predicates(vec![vec![op::ret(RegId::ONE)], vec![op::ret(RegId::ONE)]]);
+    let key = predicate_cache_key(&tx, 0, &params);
+
+    // When
+    let mut other_tx = tx.clone();
+    other_tx.witnesses_mut().push(vec![1].into());
+
+    // Then
+    assert_eq!(key, predicate_cache_key(&tx, 0, &params));
+    assert_ne!(key, predicate_cache_key(&tx, 1, &params));
+    assert_ne!(key, predicate_cache_key(&other_tx, 0, &params));
+}
+
+#[test]
+fn predicate_cache_key__depends_on_every_parameter_observed_by_the_predicate() {
+    use fuel_tx::{
+        DependentCost,
+        GasCostsValues,
+        consensus_parameters::gas::GasCostsValuesV1,
+    };
+
+    // Given
+    let tx = tx_with_predicates(vec![vec![op::ret(RegId::ONE)]]);
+    let gas_costs = |costs: GasCostsValuesV5| GasCosts::new(GasCostsValues::V5(costs));
+    let params = CheckPredicateParams {
+        gas_costs: gas_costs(GasCostsValuesV5::free()),
+        ..Default::default()
+    };
+    let key = predicate_cache_key(&tx, 0, &params);
+
+    // When
+    let changed_params = [
+        CheckPredicateParams {
+            chain_id: (u64::from(params.chain_id) + 1).into(),
+            ..params.clone()
+        },
+        CheckPredicateParams {
+            base_asset_id: AssetId::new([1; 32]),
+            ..params.clone()
+        },
+        CheckPredicateParams {
+            tx_offset: params.tx_offset + 1,
+            ..params.clone()
+        },
+        CheckPredicateParams {
+            max_inputs: params.max_inputs + 1,
+            ..params.clone()
+        },
+        CheckPredicateParams {
+            contract_max_size: params.contract_max_size + 1,
+            ..params.clone()
+        },
+        CheckPredicateParams {
+            max_message_data_length: params.max_message_data_length + 1,
+            ..params.clone()
+        },
+        CheckPredicateParams {
+            gas_costs: gas_costs(GasCostsValuesV5 {
+                add: 1,
+                ..GasCostsValuesV5::free()
+            }),
+            ..params.clone()
+        },
+        CheckPredicateParams {
+            gas_costs: gas_costs(GasCostsValuesV5 {
+                new_storage_per_byte: 1,
+                ..GasCostsValuesV5::free()
+            }),
+            ..params.clone()
+        },
+        CheckPredicateParams {
+            gas_costs: gas_costs(GasCostsValuesV5 {
+                s256: DependentCost::LightOperation {
+                    base: 0,
+                    units_per_gas: 0,
+                },
+                ..GasCostsValuesV5::free()
+            }),
+            ..params.clone()
+        },
+        CheckPredicateParams {
+            gas_costs: gas_costs(GasCostsValuesV5 {
+                ecop: 1,
+                ..GasCostsValuesV5::free()
+            }),
+            ..params.clone()
+        },
+        CheckPredicateParams {
+            gas_costs: gas_costs(GasCostsValuesV5 {
+                epar: DependentCost::unit(),
+                ..GasCostsValuesV5::free()
+            }),
+            ..params.clone()
+        },
+        CheckPredicateParams {
+            gas_costs: GasCosts::new(GasCostsValues::V1(GasCostsValuesV1::free())),
+            ..params.clone()
+        },
+    ];
+
+    // Then
+    for changed in changed_params {
+        assert_ne!(key, predicate_cache_key(&tx, 0, &changed), "{changed:?}");
+    }
+}
@@ -5,14 +5,41 @@ use fuel_asm::{
 };
 use fuel_tx::{
     Receipt,
+    Script,
     ScriptExecutionResult,
+    TransactionBuilder,
 };
 
-use alloc::vec;
+use alloc::{
+    boxed::Box,
+    vec,
+    vec::Vec,
+};
 
-use crate::interpreter::ReceiptsCtx;
+use crate::{
+    error::InterpreterError,
+    interpreter::{
+        InterpreterParams,
+        MemoryInstance,
+        ReceiptsCtx,
+    },
+    prelude::{
+        MemoryStorage,
+        TransactionBuilderExt,
+        Transactor,
+    },
+    util::test_helpers::TestBuilder,
+    verification::{
+        GasUsedWithinLimit,
+        NoUnexpectedPanics,
+        VerificationError,
+    },
+};
 
-use super::test_helpers::run_script;
+use super::test_helpers::{
+    run_script,
+    run_script_full,
+};
 
 #[test]
 fn too_many_receipts_panics() {
@@ -68,3 +95,165 @@ fn can_return_successfully_just_below_max_receipts() {
     };
     assert_eq!(result, ScriptExecutionResult::Success);
 }
+
+#[test]
+fn state_transition_diff_reports_changed_receipts_and_gas() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let first = test_context
+        .start_script(
+            vec![
+                op::log(RegId::ONE, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+                op::ret(RegId::ONE),
+            ],
+            vec![],
+        )
+        .fee_input()
+        .execute();
+    let second = test_context
+        .start_script(
+            vec![
+                op::log(RegId::ZERO, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+                op::noop(),
+                op::ret(RegId::ONE),
+            ],
+            vec![],
+        )
+        .fee_input()
+        .execute();
+
+    let diff = first.diff(&second);
+
+    assert!(diff.changed_outputs.is_empty());
+    assert_eq!(diff.changed_receipts, vec![0, 1, 2]);
+    assert!(diff.gas_delta > 0);
+    assert_eq!(second.diff(&first).gas_delta, -diff.gas_delta);
+    assert!(first.diff(&first).is_empty());
+}
+
+fn transact_with_verifier(
+    script: Vec<u8>,
+    verifier: Box<dyn crate::verification::ReceiptVerifier>,
+) -> Option<VerificationError> {
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_checked(Default::default());
+
+    let mut transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams::default(),
+    );
+    transactor.add_receipt_verifier(verifier).transact(tx);
+    transactor.error().map(|e| match e {
+        InterpreterError::ReceiptVerification(e) => e.clone(),
+        e => panic!("Unexpected error: {e:?}"),
+    })
+}
+
+#[test]
+fn receipt_verifier_rejects_unexpected_panic() {
+    let script = vec![op::div(0x10, RegId::ZERO, RegId::ZERO)]
+        .into_iter()
+        .collect();
+
+    let error = transact_with_verifier(script, Box::new(NoUnexpectedPanics::default()));
+
+    assert_eq!(
+        error,
+        Some(VerificationError::UnexpectedPanic(
+            PanicReason::ArithmeticError
+        ))
+    );
+}
+
+#[test]
+fn receipt_verifier_accepts_allowed_panic() {
+    let script = vec![op::div(0x10, RegId::ZERO, RegId::ZERO)]
+        .into_iter()
+        .collect();
+    let verifier = NoUnexpectedPanics {
+        allowed_reasons: vec![PanicReason::ArithmeticError],
+    };
+
+    let error = transact_with_verifier(script, Box::new(verifier));
+
+    assert_eq!(error, None);
+}
+
+#[test]
+fn receipt_verifier_rejects_gas_used_above_limit() {
+    let script = vec![op::noop(), op::noop(), op::ret(RegId::ONE)]
+        .into_iter()
+        .collect();
+
+    let error = transact_with_verifier(script, Box::new(GasUsedWithinLimit { limit: 1 }));
+
+    assert!(matches!(
+        error,
+        Some(VerificationError::GasLimitExceeded { limit: 1, .. })
+    ));
+}
+
+#[test]
+fn max_receipts_limits_the_number_of_receipts() {
+    let max_receipts = 10;
+    let script = vec![
+        op::log(RegId::ZERO, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::jmpb(RegId::ZERO, 0),
+    ]
+    .into_iter()
+    .collect();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_checked(Default::default());
+
+    let mut transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams {
+            max_receipts,
+            ..Default::default()
+        },
+    );
+    let receipts = transactor
+        .transact(tx)
+        .receipts()
+        .expect("Failed to transact")
+        .to_vec();
+
+    assert_eq!(receipts.len(), max_receipts);
+    let Receipt::Panic { reason, .. } = receipts[max_receipts - 2] else {
+        panic!("Expect panic receipt");
+    };
+    assert_eq!(*reason.reason(), PanicReason::TooManyReceipts);
+}
+
+#[test]
+fn receipts_root__is_the_binary_merkle_root_of_the_serialized_receipts() {
+    use fuel_merkle::binary::in_memory::MerkleTree;
+    use fuel_types::canonical::Serialize;
+
+    // Given
+    let (receipts, state) = run_script_full(
+        vec![
+            op::log(RegId::ONE, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+            op::ret(RegId::ONE),
+        ],
+        vec![],
+    );
+    assert_eq!(receipts.len(), 3);
+    let mut tree = MerkleTree::new();
+    for receipt in &receipts {
+        tree.push(&receipt.to_bytes());
+    }
+    let expected = tree.root();
+
+    // When
+    let root = state.receipts_root();
+
+    // Then
+    assert_eq!(root, expected.into());
+    assert_eq!(root, ReceiptsCtx::from(receipts).root());
+}
@@ -25,6 +25,15 @@ pub fn set_full_word(r: RegisterId, v: Word) -> Vec<Instruction> {
 
 /// Run a instructions-only script with reasonable defaults, and return receipts
 pub fn run_script(script: Vec<Instruction>) -> Vec<Receipt> {
+    run_script_full(script, vec![]).0
+}
+
+/// Run a script with the given script data and reasonable defaults, and return
+/// receipts along with the final state transition
+pub fn run_script_full(
+    script: Vec<Instruction>,
+    script_data: Vec<u8>,
+) -> (Vec<Receipt>, StateTransition<Script>) {
     use rand::{
         Rng,
         SeedableRng,
@@ -36,7 +45,7 @@ pub fn run_script(script: Vec<Instruction>) -> Vec<Receipt> {
     let consensus_params = ConsensusParameters::standard();
 
     let mut rng = rand::rngs::StdRng::seed_from_u64(2322u64);
-    let tx = TransactionBuilder::script(script, vec![])
+    let tx = TransactionBuilder::script(script, script_data)
         .max_fee_limit(arb_max_fee)
         .script_gas_limit(1_000_000)
         .maturity(Default::default())
@@ -51,7 +60,11 @@ pub fn run_script(script: Vec<Instruction>) -> Vec<Receipt> {
         .into_checked(Default::default(), &consensus_params)
         .expect("failed to generate a checked tx");
     client.transact(tx);
-    client.receipts().expect("Expected receipts").to_vec()
+    let state: StateTransition<Script> = client
+        .state_transition()
+        .expect("Expected state transition")
+        .into();
+    (state.receipts().to_vec(), state)
 }
 
 /// Assert that transaction didn't panic
@@ -19,7 +19,10 @@ use fuel_tx::{
     field::Outputs,
     policies::Policies,
 };
-use fuel_types::AssetId;
+use fuel_types::{
+    AssetId,
+    Bytes32,
+};
 
 use crate::{
     checked_transaction::Ready,
@@ -289,6 +292,35 @@ fn check__fails_when_subsection_index_more_than_total_number() {
     );
 }
 
+#[test]
+fn check__fails_when_proof_set_is_tampered() {
+    let subsections = UploadSubsection::split_bytecode(&bytecode(), 123).unwrap();
+    assert!(subsections.len() >= 3);
+
+    // Given
+    let mut subsection = subsections[1].clone();
+    assert!(!subsection.proof_set.is_empty());
+    subsection.proof_set[0] = Bytes32::from([1; 32]);
+
+    // When
+    let result = Transaction::upload_from_subsection(
+        subsection,
+        Policies::new().with_max_fee(AMOUNT),
+        vec![valid_input()],
+        vec![],
+        vec![],
+    )
+    .into_checked_basic(Default::default(), &Default::default());
+
+    // Then
+    assert_eq!(
+        result,
+        Err(CheckError::Validity(
+            ValidityError::TransactionUploadRootVerificationFailed
+        ))
+    );
+}
+
 #[test]
 fn check__fails_when_total_number_is_zero() {
     let subsections = UploadSubsection::split_bytecode(&bytecode(), 123).unwrap();
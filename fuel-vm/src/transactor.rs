@@ -1,7 +1,11 @@
 //! State machine of the interpreter.
 
 use crate::{
-    backtrace::Backtrace,
+    backtrace::{
+        Backtrace,
+        PanicDetail,
+    },
+    call::CallObserver,
     checked_transaction::{
         Checked,
         IntoChecked,
@@ -9,38 +13,75 @@ use crate::{
     },
     error::InterpreterError,
     interpreter::{
+        BlockContext,
         CheckedMetadata,
         EcalHandler,
         ExecutableTransaction,
+        GasRefundPolicy,
         Interpreter,
         InterpreterParams,
         Memory,
+        MemoryInstance,
         NotSupportedEcal,
+        OpcodeGasObserver,
+        ReplayEcal,
     },
     state::{
         ProgramState,
         StateTransition,
         StateTransitionRef,
     },
-    storage::InterpreterStorage,
+    storage::{
+        AccessSet,
+        InterpreterStorage,
+        OverlayStorage,
+        ReplayLog,
+        ReplayStorage,
+        StorageObserver,
+    },
     verification::{
         Normal,
+        ReceiptVerifier,
+        VerificationError,
         Verifier,
     },
 };
+use alloc::{
+    boxed::Box,
+    sync::Arc,
+    vec::Vec,
+};
 use fuel_tx::{
     Blob,
+    ConsensusParameters,
     Create,
     FeeParameters,
     GasCosts,
+    Output,
     Receipt,
     Script,
     Upgrade,
     Upload,
+    field::{
+        Script as ScriptField,
+        ScriptGasLimit,
+    },
+};
+use fuel_types::{
+    ContractId,
+    Word,
 };
+use hashbrown::HashMap;
 
-#[cfg(any(test, feature = "test-helpers"))]
-use crate::interpreter::MemoryInstance;
+/// Observes the outputs of the transactions executed by a [`Transactor`].
+///
+/// Useful to audit the value flows of a transaction without re-deriving the change
+/// from the receipts.
+pub trait OutputsObserver: core::fmt::Debug + Send + Sync {
+    /// Called with the final outputs of an executed transaction, i.e. after the change
+    /// and variable outputs were computed, before the state transition is produced.
+    fn on_finalize_outputs(&self, outputs: &[Output]);
+}
 
 #[derive(Debug)]
 /// State machine to execute transactions and provide runtime entities on
@@ -57,6 +98,10 @@ where
     interpreter: Interpreter<M, S, Tx, Ecal, V>,
     program_state: Option<ProgramState>,
     error: Option<InterpreterError<S::DataError>>,
+    min_gas: Option<Word>,
+    coinbase: Option<ContractId>,
+    receipt_verifiers: Vec<Box<dyn ReceiptVerifier>>,
+    outputs_observer: Option<Arc<dyn OutputsObserver>>,
 }
 
 impl<M, S, Tx, Ecal, V> Transactor<M, S, Tx, Ecal, V>
@@ -76,6 +121,10 @@ where
             ),
             program_state: None,
             error: None,
+            min_gas: None,
+            coinbase: None,
+            receipt_verifiers: Vec::new(),
+            outputs_observer: None,
         }
     }
 }
@@ -153,11 +202,57 @@ where
         }
     }
 
+    /// Total gas used by the last executed transaction: the minimal gas computed
+    /// during the check of the transaction, which includes the gas used by the
+    /// predicates, plus the gas used by the script, if any.
+    ///
+    /// Follows the same criteria as [`Self::state_transition`] to return
+    /// `None`.
+    pub fn total_gas_used(&self) -> Option<Word> {
+        let state = self.state_transition()?;
+        let min_gas = self.min_gas?;
+        let script_gas_used = state
+            .receipts()
+            .iter()
+            .find_map(|receipt| match receipt {
+                Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        Some(min_gas.saturating_add(script_gas_used))
+    }
+
+    /// Coinbase observed by the last executed transaction: the one of the block
+    /// context if set, otherwise the one provided by the storage at execution time.
+    ///
+    /// Will be `None` if no transaction was executed, or if the storage failed to
+    /// provide the coinbase.
+    pub const fn coinbase(&self) -> Option<ContractId> {
+        self.coinbase
+    }
+
     /// Gets the interpreter.
     pub fn interpreter(&self) -> &Interpreter<M, S, Tx, Ecal, V> {
         &self.interpreter
     }
 
+    /// Installs the `storage` and clears the state left by the previous transactions,
+    /// see [`Interpreter::reset`]. The receipt verifiers are kept.
+    ///
+    /// Returns the previous storage.
+    pub fn reset(&mut self, storage: S) -> S
+    where
+        M: Memory,
+        V: Default,
+    {
+        self.program_state = None;
+        self.error = None;
+        self.min_gas = None;
+        self.coinbase = None;
+        self.interpreter.reset(storage)
+    }
+
     /// Gas costs of opcodes
     pub fn gas_costs(&self) -> &GasCosts {
         self.interpreter.gas_costs()
@@ -174,6 +269,117 @@ where
         self.interpreter.set_gas_price(gas_price);
     }
 
+    /// Sets the block information used by the next transactions instead of the one
+    /// provided by the storage. `None` restores the storage values.
+    pub fn set_block_context(
+        &mut self,
+        block_context: Option<BlockContext>,
+    ) -> &mut Self {
+        self.interpreter.set_block_context(block_context);
+        self
+    }
+
+    /// Sets the observer notified about the contract state slots accessed by the next
+    /// transactions. `None` removes the observer.
+    pub fn set_storage_observer(
+        &mut self,
+        storage_observer: Option<Arc<dyn StorageObserver>>,
+    ) -> &mut Self {
+        self.interpreter.set_storage_observer(storage_observer);
+        self
+    }
+
+    /// Enables or disables the collection of the contract state slots accessed by the
+    /// next transactions, see [`Transactor::access_set`].
+    pub fn set_access_set_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.interpreter.set_access_set_enabled(enabled);
+        self
+    }
+
+    /// Enables or disables the collection of the gas charged by each contract called
+    /// by the next transactions, see [`Transactor::gas_by_contract`].
+    pub fn set_gas_by_contract_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.interpreter.set_gas_by_contract_enabled(enabled);
+        self
+    }
+
+    /// Sets the observer notified about the call frames entered and left by the next
+    /// transactions. `None` removes the observer.
+    pub fn set_call_observer(
+        &mut self,
+        call_observer: Option<Arc<dyn CallObserver>>,
+    ) -> &mut Self {
+        self.interpreter.set_call_observer(call_observer);
+        self
+    }
+
+    /// Sets the policy of the gas refunds for the storage slots cleared by the next
+    /// transactions. `None` disables the refunds.
+    pub fn set_gas_refund_policy(
+        &mut self,
+        gas_refund_policy: Option<Arc<dyn GasRefundPolicy>>,
+    ) -> &mut Self {
+        self.interpreter.set_gas_refund_policy(gas_refund_policy);
+        self
+    }
+
+    /// Sets the observer notified about the gas charged by the instructions with one
+    /// of the `opcodes`. See [`Interpreter::set_opcode_gas_observer`].
+    pub fn set_opcode_gas_observer<I>(
+        &mut self,
+        observer: Option<Arc<dyn OpcodeGasObserver>>,
+        opcodes: I,
+    ) -> &mut Self
+    where
+        I: IntoIterator<Item = fuel_asm::Opcode>,
+    {
+        self.interpreter.set_opcode_gas_observer(observer, opcodes);
+        self
+    }
+
+    /// Adds a verifier that checks the receipts of the next executed transactions.
+    ///
+    /// The verifiers run in the order they were added, on the receipts of a successful
+    /// execution of the transaction on an [`OverlayStorage`] of the storage, like
+    /// [`Transactor::simulate`] does. If one of them rejects the receipts, the
+    /// transaction results in an [`InterpreterError::ReceiptVerification`] error and
+    /// the storage is left untouched. Otherwise, the transaction is executed against
+    /// the storage.
+    pub fn add_receipt_verifier(
+        &mut self,
+        receipt_verifier: Box<dyn ReceiptVerifier>,
+    ) -> &mut Self {
+        self.receipt_verifiers.push(receipt_verifier);
+        self
+    }
+
+    /// Sets the observer notified about the final outputs of the next successfully
+    /// executed transactions. `None` removes the observer.
+    pub fn set_outputs_observer(
+        &mut self,
+        outputs_observer: Option<Arc<dyn OutputsObserver>>,
+    ) -> &mut Self {
+        self.outputs_observer = outputs_observer;
+        self
+    }
+
+    /// Pauses the next transactions with a `DebugEval::LogMatch` after each `LOG` or
+    /// `LOGD` instruction whose receipt matches the `filter`. The execution continues
+    /// with [`Transactor::resume`]. The filter is removed by [`Transactor::reset`].
+    pub fn set_log_filter<F>(&mut self, filter: F) -> &mut Self
+    where
+        F: Fn(&Receipt) -> bool + Send + Sync + 'static,
+    {
+        self.interpreter.set_log_filter(filter);
+        self
+    }
+
+    /// Removes the log filter, if existent.
+    pub fn clear_log_filter(&mut self) -> &mut Self {
+        self.interpreter.clear_log_filter();
+        self
+    }
+
     /// Tx memory offset
     pub fn tx_offset(&self) -> usize {
         self.interpreter.tx_offset()
@@ -203,6 +409,64 @@ where
             .copied()
             .map(|result| Backtrace::from_vm_error(&self.interpreter, result))
     }
+
+    /// The gas charged by each contract called by the last transaction, see
+    /// [`Interpreter::gas_by_contract`]. Empty if the collection is disabled.
+    pub fn gas_by_contract(&self) -> HashMap<ContractId, Word> {
+        self.interpreter
+            .gas_by_contract()
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// The contract state slots read and written by the last transaction, see
+    /// [`Interpreter::access_set`]. Empty if the collection is disabled.
+    pub fn access_set(&self) -> AccessSet {
+        self.interpreter.access_set().cloned().unwrap_or_default()
+    }
+
+    /// Generate the backtrace captured at the panic that produced `receipt`.
+    ///
+    /// A panic halts the script, even if it happens in a nested call, so the backtrace
+    /// is only returned if `receipt` is the `Panic` receipt of the last transaction.
+    /// Its [`Backtrace::contract`] is then the contract that panicked.
+    pub fn backtrace_for_panic(&self, receipt: &Receipt) -> Option<Backtrace> {
+        if !matches!(receipt, Receipt::Panic { .. }) {
+            return None;
+        }
+        self.receipts()?
+            .iter()
+            .rfind(|r| matches!(r, Receipt::Panic { .. }))
+            .filter(|panic| *panic == receipt)?;
+        self.backtrace()
+    }
+
+    /// Locates the instruction that produced the `Panic` `receipt`: the contract that
+    /// panicked, the offset of the instruction in its bytecode, read from the storage,
+    /// and the instruction itself. If the panic happened in the script, the contract
+    /// is `None` and the instruction is read from the script of the transaction.
+    ///
+    /// Returns `None` if `receipt` isn't a `Panic` receipt or if the bytecode doesn't
+    /// contain a valid instruction at the offset, e.g. because the panicking code was
+    /// loaded with `LDC`.
+    pub fn decode_panic(&self, receipt: &Receipt) -> Option<PanicDetail> {
+        let Receipt::Panic {
+            id, reason, pc, is, ..
+        } = receipt
+        else {
+            return None
+        };
+        let offset = pc.checked_sub(*is)?;
+        let reason = *reason.reason();
+
+        if *id == ContractId::zeroed() {
+            let script = self.interpreter.transaction().as_script()?;
+            return PanicDetail::decode(None, script.script(), offset, reason)
+        }
+        let contract = S::storage_contract(self.interpreter.as_ref(), id).ok()??;
+        let bytecode: &[u8] = (*contract).as_ref();
+        PanicDetail::decode(Some(*id), bytecode, offset, reason)
+    }
 }
 
 impl<M, S, Tx, Ecal, V> Transactor<M, S, Tx, Ecal, V>
@@ -317,15 +581,7 @@ where
 {
     /// Execute a transaction, and return the new state of the transactor
     pub fn transact(&mut self, tx: Checked<Tx>) -> &mut Self {
-        let gas_price = self.interpreter.gas_price();
-        let gas_costs = self.interpreter.gas_costs();
-        let fee_params = self.interpreter.fee_params();
-        let block_height = self.interpreter.context().block_height();
-
-        let res = tx
-            .into_ready(gas_price, gas_costs, fee_params, block_height)
-            .map_err(InterpreterError::CheckError);
-        match res {
+        match self.ready(tx) {
             Ok(ready_tx) => self.transact_ready_tx(ready_tx),
             Err(e) => self.handle_error(e),
         }
@@ -333,9 +589,41 @@ where
 
     /// Transact a `Ready` transaction directly instead of letting `Transactor` construct
     pub fn transact_ready_tx(&mut self, ready_tx: Ready<Tx>) -> &mut Self {
+        if let Err(e) = self.verify_receipts(&ready_tx) {
+            return self.handle_error(InterpreterError::ReceiptVerification(e));
+        }
+        self.execute_ready_tx(ready_tx)
+    }
+
+    fn ready(
+        &self,
+        tx: Checked<Tx>,
+    ) -> Result<Ready<Tx>, InterpreterError<S::DataError>> {
+        let gas_price = self.interpreter.gas_price();
+        let gas_costs = self.interpreter.gas_costs();
+        let fee_params = self.interpreter.fee_params();
+        let block_height = self.interpreter.context().block_height();
+
+        tx.into_ready(gas_price, gas_costs, fee_params, block_height)
+            .map_err(InterpreterError::CheckError)
+    }
+
+    /// Executes `ready_tx` without running the receipt verifiers.
+    fn execute_ready_tx(&mut self, ready_tx: Ready<Tx>) -> &mut Self {
+        self.min_gas = Some(ready_tx.metadata().min_gas());
+        self.coinbase = match self.interpreter.block_context() {
+            Some(block_context) => Some(block_context.coinbase),
+            None => S::coinbase(self.interpreter.as_ref()).ok(),
+        };
         match self.interpreter.transact(ready_tx) {
             Ok(s) => {
-                self.program_state.replace(s.into());
+                let state: ProgramState = s.into();
+                if let Some(observer) = &self.outputs_observer {
+                    let outputs = self.interpreter.transaction().outputs();
+                    observer.on_finalize_outputs(outputs);
+                }
+
+                self.program_state.replace(state);
                 self.error.take();
                 self
             }
@@ -344,6 +632,77 @@ where
         }
     }
 
+    /// Execute a transaction without persisting its effects to the storage.
+    ///
+    /// The transaction runs in a separate transactor on top of an [`OverlayStorage`]
+    /// of the storage of this one, which keeps the writes in memory and discards them
+    /// afterwards. The receipts and the state transition are the same as for
+    /// [`Transactor::transact`], but the state of the transactor is left untouched.
+    /// The observers and the receipt verifiers of the transactor aren't applied to the
+    /// simulation.
+    pub fn simulate(
+        &self,
+        tx: Checked<Tx>,
+    ) -> Result<StateTransition<Tx>, InterpreterError<S::DataError>> {
+        let ready_tx = self.ready(tx)?;
+        let mut transactor = self.overlay_transactor();
+        transactor.execute_ready_tx(ready_tx);
+
+        match (transactor.to_owned_state_transition(), transactor.error) {
+            (Some(state), None) => Ok(state),
+            (_, Some(error)) => Err(error),
+            (None, None) => Err(InterpreterError::NoTransactionInitialized),
+        }
+    }
+
+    /// Runs the receipt verifiers on the receipts of the execution of `ready_tx` on an
+    /// overlay of the storage. A failed execution isn't verified, the execution
+    /// against the storage reports the failure.
+    fn verify_receipts(&self, ready_tx: &Ready<Tx>) -> Result<(), VerificationError> {
+        if self.receipt_verifiers.is_empty() {
+            return Ok(())
+        }
+
+        let mut transactor = self.overlay_transactor();
+        transactor.execute_ready_tx(ready_tx.clone());
+        if transactor.error.is_some() {
+            return Ok(())
+        }
+
+        let receipts = transactor.interpreter.receipts();
+        self.receipt_verifiers
+            .iter()
+            .try_for_each(|verifier| verifier.verify(receipts))
+    }
+
+    /// A transactor with the same parameters and gas refund policy as this one,
+    /// executing on an [`OverlayStorage`] of its storage.
+    fn overlay_transactor(
+        &self,
+    ) -> Transactor<MemoryInstance, OverlayStorage<'_, S>, Tx, Ecal, V> {
+        let mut interpreter = Interpreter::with_storage_and_ecal(
+            MemoryInstance::new(),
+            OverlayStorage::new(AsRef::<S>::as_ref(self)),
+            self.interpreter.interpreter_params().clone(),
+            self.interpreter.ecal_state().clone(),
+        );
+        interpreter.set_gas_refund_policy(self.interpreter.gas_refund_policy().cloned());
+        Transactor::from(interpreter)
+    }
+
+    /// Continues the execution of the transaction paused by a debug event, see
+    /// [`Interpreter::resume`].
+    pub fn resume(&mut self) -> &mut Self {
+        match self.interpreter.resume() {
+            Ok(state) => {
+                self.program_state.replace(state);
+                self.error.take();
+                self
+            }
+            Err(e) => self.handle_error(e),
+        }
+    }
+
     fn handle_error(&mut self, error: InterpreterError<S::DataError>) -> &mut Self {
         self.program_state.take();
         self.error.replace(error);
@@ -351,6 +710,181 @@ where
     }
 }
 
+impl<M, S, Ecal, V> Transactor<M, S, Script, Ecal, V>
+where
+    M: Memory,
+    S: InterpreterStorage,
+    Ecal: EcalHandler,
+    V: Verifier + Default,
+{
+    /// Finds the smallest script gas limit under which `tx` succeeds.
+    ///
+    /// Binary-searches the gas limit between zero and the largest limit accepted by
+    /// the check of `tx`, i.e. the block gas limit of `consensus_params`, capped by
+    /// the maximum gas per transaction, minus the gas `tx` needs besides the script.
+    /// Each attempt re-checks `tx` with the candidate limit, skipping the signatures
+    /// and the coverage of the fee by the max fee policy, and runs it on an
+    /// [`OverlayStorage`] like [`Transactor::simulate`], so the storage is left
+    /// untouched. Returns `None` if the transaction fails with the largest limit, e.g.
+    /// because it reverts for a reason other than the gas.
+    pub fn find_min_gas(
+        &self,
+        tx: Script,
+        consensus_params: &ConsensusParameters,
+    ) -> Option<Word> {
+        let block_height = match self.interpreter.block_context() {
+            Some(block_context) => block_context.height,
+            None => S::block_height(self.interpreter.as_ref()).ok()?,
+        };
+        let check = |gas_limit: Word| {
+            let mut tx = tx.clone();
+            *tx.script_gas_limit_mut() = gas_limit;
+            tx.into_checked_basic(block_height, consensus_params).ok()
+        };
+
+        let max_gas_without_script = check(0)?.metadata().max_gas();
+        let max_gas_limit = consensus_params
+            .block_gas_limit()
+            .min(consensus_params.tx_params().max_gas_per_tx())
+            .saturating_sub(max_gas_without_script);
+
+        let succeeds = |gas_limit: Word| {
+            let ready_tx = check(gas_limit).and_then(|checked| {
+                checked
+                    .into_ready_ignoring_max_fee(
+                        self.interpreter.gas_price(),
+                        self.interpreter.gas_costs(),
+                        self.interpreter.fee_params(),
+                        self.interpreter.context().block_height(),
+                    )
+                    .ok()
+            });
+            let Some(ready_tx) = ready_tx else {
+                return false
+            };
+
+            let mut transactor = self.overlay_transactor();
+            transactor.execute_ready_tx(ready_tx);
+            transactor.is_success()
+        };
+
+        if !succeeds(max_gas_limit) {
+            return None;
+        }
+
+        // Invariant: the transaction succeeds with `high` and fails below `low`.
+        let (mut low, mut high) = (0, max_gas_limit);
+        while low < high {
+            let mid = low.saturating_add(high.saturating_sub(low) / 2);
+            if succeeds(mid) {
+                high = mid;
+            } else {
+                low = mid.saturating_add(1);
+            }
+        }
+
+        Some(high)
+    }
+}
+
+impl<M, S, Tx, V> Transactor<M, ReplayStorage<S>, Tx, ReplayEcal, V>
+where
+    S: InterpreterStorage,
+    Tx: ExecutableTransaction,
+    V: Verifier + Default,
+{
+    /// Creates a transactor replaying an execution recorded by an
+    /// [`ExecutionRecorder`]: the block information is served from the `log` instead
+    /// of the `storage`, and the ECAL instructions reproduce the recorded effects.
+    pub fn with_replay_log(
+        memory: M,
+        storage: S,
+        interpreter_params: InterpreterParams,
+        log: ReplayLog,
+    ) -> Self {
+        let ecal = ReplayEcal::new(log.ecal_results.iter().cloned());
+        let mut transactor =
+            Self::new(memory, ReplayStorage::new(storage, log), interpreter_params);
+        *transactor.interpreter.ecal_state_mut() = ecal;
+        transactor
+    }
+}
+
+/// Executes transactions with a [`Transactor`], recording the values read by the VM
+/// that aren't part of the transactions into a [`ReplayLog`]: the block information
+/// provided by the storage and the effects of the ECAL instructions.
+///
+/// The recorded executions can be reproduced with [`Transactor::with_replay_log`],
+/// e.g. to debug a transaction executed in production.
+pub struct ExecutionRecorder<M, S, Tx, Ecal = NotSupportedEcal, V = Normal>
+where
+    S: InterpreterStorage,
+{
+    transactor: Transactor<M, ReplayStorage<S>, Tx, Ecal, V>,
+}
+
+impl<M, S, Tx, Ecal, V> ExecutionRecorder<M, S, Tx, Ecal, V>
+where
+    S: InterpreterStorage,
+    Tx: ExecutableTransaction,
+    Ecal: EcalHandler + Default,
+    V: Verifier + Default,
+{
+    /// Creates a recorder executing the transactions on top of the `storage`.
+    pub fn new(memory: M, storage: S, interpreter_params: InterpreterParams) -> Self {
+        let storage = ReplayStorage::new(storage, ReplayLog::default());
+        let mut transactor = Transactor::new(memory, storage, interpreter_params);
+        transactor.interpreter.set_ecal_recording_enabled(true);
+        Self { transactor }
+    }
+}
+
+impl<M, S, Tx, Ecal, V> ExecutionRecorder<M, S, Tx, Ecal, V>
+where
+    M: Memory,
+    S: InterpreterStorage,
+    Tx: ExecutableTransaction,
+    <Tx as IntoChecked>::Metadata: CheckedMetadata,
+    Ecal: EcalHandler,
+    V: Verifier,
+{
+    /// Executes the transaction and returns the log of its execution. The result of
+    /// the execution is available from [`ExecutionRecorder::transactor`].
+    pub fn transact(&mut self, tx: Checked<Tx>) -> ReplayLog {
+        // Every transaction gets a complete log, even if it reads the same values as
+        // the previous ones.
+        let storage: &mut ReplayStorage<S> = self.transactor.as_mut();
+        storage.take_log();
+
+        self.transactor.transact(tx);
+
+        let storage: &mut ReplayStorage<S> = self.transactor.as_mut();
+        let mut log = storage.take_log();
+        log.ecal_results = self
+            .transactor
+            .interpreter
+            .ecal_records()
+            .unwrap_or_default()
+            .to_vec();
+        log
+    }
+}
+
+impl<M, S, Tx, Ecal, V> ExecutionRecorder<M, S, Tx, Ecal, V>
+where
+    S: InterpreterStorage,
+{
+    /// Gets the transactor executing the transactions.
+    pub fn transactor(&self) -> &Transactor<M, ReplayStorage<S>, Tx, Ecal, V> {
+        &self.transactor
+    }
+
+    /// Returns the transactor executing the transactions.
+    pub fn into_transactor(self) -> Transactor<M, ReplayStorage<S>, Tx, Ecal, V> {
+        self.transactor
+    }
+}
+
 impl<M, S, Tx, Ecal, V> From<Interpreter<M, S, Tx, Ecal, V>>
     for Transactor<M, S, Tx, Ecal, V>
 where
@@ -360,11 +894,19 @@ where
     fn from(interpreter: Interpreter<M, S, Tx, Ecal, V>) -> Self {
         let program_state = None;
         let error = None;
+        let min_gas = None;
+        let coinbase = None;
+        let receipt_verifiers = Vec::new();
+        let outputs_observer = None;
 
         Self {
             interpreter,
             program_state,
             error,
+            min_gas,
+            coinbase,
+            receipt_verifiers,
+            outputs_observer,
         }
     }
 }
@@ -97,15 +97,25 @@ pub mod test_helpers {
             IntoChecked,
             builder::TransactionBuilderExt,
         },
+        context::Context,
         interpreter::{
+            Interpreter,
             Memory,
             NotSupportedEcal,
         },
         memory_client::MemoryClient,
-        state::StateTransition,
+        predicate::RuntimePredicate,
+        state::{
+            ProgramState,
+            StateTransition,
+        },
         storage::{
             ContractsAssetsStorage,
             MemoryStorage,
+            predicate::{
+                EmptyStorage,
+                PredicateStorage,
+            },
         },
         transactor::Transactor,
         verification::{
@@ -149,6 +159,7 @@ pub mod test_helpers {
         PredicateParameters,
         Receipt,
         Script,
+        ScriptExecutionResult,
         ScriptParameters,
         StorageSlot,
         Transaction,
@@ -182,6 +193,55 @@ pub mod test_helpers {
         prelude::StdRng,
     };
 
+    /// Assembles the script data from typed fields and returns the offset of each of
+    /// them, so the script can find them relative to `GTFArgs::ScriptData`.
+    #[derive(Debug, Default, Clone)]
+    pub struct ScriptDataBuilder {
+        data: Vec<u8>,
+    }
+
+    impl ScriptDataBuilder {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Appends the `bytes` and returns their offset in the script data.
+        pub fn push_bytes(&mut self, bytes: &[u8]) -> usize {
+            let offset = self.data.len();
+            self.data.extend_from_slice(bytes);
+            offset
+        }
+
+        /// Appends the big-endian `word` and returns its offset in the script data.
+        pub fn push_word(&mut self, word: Word) -> usize {
+            self.push_bytes(&word.to_be_bytes())
+        }
+
+        pub fn push_address(&mut self, address: &Address) -> usize {
+            self.push_bytes(address.as_ref())
+        }
+
+        pub fn push_asset_id(&mut self, asset_id: &AssetId) -> usize {
+            self.push_bytes(asset_id.as_ref())
+        }
+
+        pub fn push_contract_id(&mut self, contract_id: &ContractId) -> usize {
+            self.push_bytes(contract_id.as_ref())
+        }
+
+        pub fn len(&self) -> usize {
+            self.data.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.data.is_empty()
+        }
+
+        pub fn build(self) -> Vec<u8> {
+            self.data
+        }
+    }
+
     pub struct CreatedContract {
         pub tx: Create,
         pub contract_id: ContractId,
@@ -681,6 +741,40 @@ pub mod test_helpers {
                 .expect("expected successful vm execution")
         }
 
+        /// Build test tx, execute it and assert that it panicked with the expected
+        /// reason
+        #[track_caller]
+        pub fn execute_expect_panic(
+            &mut self,
+            expected_reason: PanicReason,
+        ) -> StateTransition<Script> {
+            let state = self.execute();
+            let receipts = state.receipts();
+
+            match receipts.last() {
+                Some(Receipt::ScriptResult { result, .. }) => assert_eq!(
+                    *result,
+                    ScriptExecutionResult::Panic,
+                    "Expected vm panic, got {result:?} instead"
+                ),
+                _ => panic!("No script result"),
+            }
+
+            let reason = receipts
+                .iter()
+                .find_map(|receipt| match receipt {
+                    Receipt::Panic { reason, .. } => Some(*reason.reason()),
+                    _ => None,
+                })
+                .expect("No panic receipt for a panicked tx");
+            assert_eq!(
+                expected_reason, reason,
+                "Expected {expected_reason}, found {reason}"
+            );
+
+            state
+        }
+
         pub fn get_storage(&self) -> &MemoryStorage {
             &self.storage
         }
@@ -726,11 +820,33 @@ pub mod test_helpers {
     }
 
     pub fn check_expected_reason_for_instructions_with_client<M>(
-        mut client: MemoryClient<M>,
+        client: MemoryClient<M>,
         instructions: Vec<Instruction>,
         expected_reason: PanicReason,
     ) where
         M: Memory,
+    {
+        let (reason, _) = run_instructions_expect_panic_with_client(client, instructions);
+        assert_eq!(
+            expected_reason, reason,
+            "Expected {expected_reason}, found {reason}"
+        );
+    }
+
+    /// Calls a contract made of the `instructions` and returns the reason of the
+    /// panic along with all the receipts. Panics if the execution didn't panic.
+    pub fn run_instructions_expect_panic(
+        instructions: Vec<Instruction>,
+    ) -> (PanicReason, Vec<Receipt>) {
+        run_instructions_expect_panic_with_client(MemoryClient::default(), instructions)
+    }
+
+    pub fn run_instructions_expect_panic_with_client<M>(
+        mut client: MemoryClient<M>,
+        instructions: Vec<Instruction>,
+    ) -> (PanicReason, Vec<Receipt>)
+    where
+        M: Memory,
     {
         let tx_params = TxParameters::default().with_max_gas_per_tx(Word::MAX / 2);
         // The gas should be huge enough to cover the execution but still much less than
@@ -792,35 +908,94 @@ pub mod test_helpers {
             .add_output(Output::contract(0, Default::default(), Default::default()))
             .finalize_checked(height);
 
-        check_reason_for_transaction(client, tx_deploy_loader, expected_reason);
+        transact_expect_panic(client, tx_deploy_loader)
     }
 
     pub fn check_reason_for_transaction<M>(
-        mut client: MemoryClient<M>,
+        client: MemoryClient<M>,
         checked_tx: Checked<Script>,
         expected_reason: PanicReason,
     ) where
         M: Memory,
     {
-        let receipts = client.transact(checked_tx);
-
-        let panic_found = receipts.iter().any(|receipt| {
-            if let Receipt::Panic { id: _, reason, .. } = receipt {
-                assert_eq!(
-                    &expected_reason,
-                    reason.reason(),
-                    "Expected {}, found {}",
-                    expected_reason,
-                    reason.reason()
-                );
-                true
-            } else {
-                false
-            }
-        });
+        let (reason, _) = transact_expect_panic(client, checked_tx);
+        assert_eq!(
+            expected_reason, reason,
+            "Expected {expected_reason}, found {reason}"
+        );
+    }
+
+    /// Executes the `checked_tx` and returns the reason of the panic along with all
+    /// the receipts. Panics if the execution didn't panic.
+    pub fn transact_expect_panic<M>(
+        mut client: MemoryClient<M>,
+        checked_tx: Checked<Script>,
+    ) -> (PanicReason, Vec<Receipt>)
+    where
+        M: Memory,
+    {
+        let receipts = client.transact(checked_tx).to_vec();
+
+        let reason = receipts
+            .iter()
+            .find_map(|receipt| match receipt {
+                Receipt::Panic { reason, .. } => Some(*reason.reason()),
+                _ => None,
+            })
+            .expect("Script should have panicked");
+        (reason, receipts)
+    }
+
+    /// Runs the `predicate` in a predicate VM after overriding the initial value of
+    /// the `registers`, e.g. to test the predicate logic in isolation.
+    ///
+    /// The reserved registers are read-only, so overriding any of them fails with
+    /// [`PanicReason::ReservedRegisterNotWritable`]. A reverted predicate fails with
+    /// [`PanicReason::Revert`].
+    pub fn run_predicate_with_registers(
+        predicate: Vec<Instruction>,
+        registers: &[(RegId, Word)],
+    ) -> Result<(), PanicReason> {
+        if registers.iter().any(|(reg, _)| *reg < RegId::WRITABLE) {
+            return Err(PanicReason::ReservedRegisterNotWritable)
+        }
+
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let params = ConsensusParameters::standard();
+        let predicate: Vec<u8> = predicate.into_iter().collect();
+        let owner = Input::predicate_owner(&predicate);
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_input(Input::coin_predicate(
+                rng.r#gen(),
+                owner,
+                0,
+                rng.r#gen(),
+                rng.r#gen(),
+                0,
+                predicate,
+                vec![],
+            ))
+            .finalize();
+        let program = RuntimePredicate::from_tx(&tx, params.tx_params().tx_offset(), 0)
+            .expect("The transaction has a predicate input");
+        let context = Context::PredicateEstimation { program };
+        let gas_limit = params.predicate_params().max_gas_per_predicate();
+
+        let mut vm = Interpreter::<_, _, Script>::with_storage(
+            MemoryInstance::new(),
+            PredicateStorage::new(EmptyStorage),
+            InterpreterParams::new(0, &params),
+        );
+        vm.init_predicate(context, tx, gas_limit)
+            .map_err(|e| e.panic_reason().unwrap_or_default())?;
+        for (reg, value) in registers {
+            vm.registers_mut()[*reg] = *value;
+        }
 
-        if !panic_found {
-            panic!("Script should have panicked");
+        match vm.verify_predicate() {
+            Ok(ProgramState::Revert(_)) => Err(PanicReason::Revert),
+            Ok(_) => Ok(()),
+            Err(e) => Err(e.panic_reason().unwrap_or_default()),
         }
     }
 
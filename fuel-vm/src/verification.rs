@@ -2,15 +2,22 @@
 //! The default strategy, [`Normal`], simply returns an error on failed verification.
 //! Alternative strategy, [`AttemptContinue`], continues execution and collects multiple
 //! errors.
+//!
+//! The receipts produced by a transaction can also be checked against custom policies
+//! with a [`ReceiptVerifier`], see
+//! [`crate::transactor::Transactor::add_receipt_verifier`].
 
 use alloc::{
     collections::BTreeSet,
+    string::String,
     vec::Vec,
 };
 
+use fuel_asm::Word;
 use fuel_tx::{
     ContractId,
     PanicReason,
+    Receipt,
 };
 
 use crate::{
@@ -26,7 +33,7 @@ trait Seal {}
 #[allow(private_bounds)] // For selaed trait
 pub trait Verifier
 where
-    Self: Sized + Seal,
+    Self: Sized + Default + Seal,
 {
     /// Handle an error after a contract is missing from the inputs
     #[allow(private_interfaces)] // PanicContext is an internal type, so this isn't callable by external code
@@ -41,6 +48,7 @@ where
 /// The default verification strategy.
 /// Performs the standard verification checks and panics on failure.
 #[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Normal;
 
 impl Verifier for Normal
@@ -94,3 +102,78 @@ where
 }
 
 impl Seal for AttemptContinue {}
+
+/// The receipts of a transaction were rejected by a [`ReceiptVerifier`].
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum VerificationError {
+    /// The script used more gas than allowed.
+    #[display(fmt = "The script used {gas_used} gas, but the limit is {limit}")]
+    GasLimitExceeded {
+        /// Gas used by the script
+        gas_used: Word,
+        /// Maximum gas allowed
+        limit: Word,
+    },
+    /// The execution panicked for a reason that isn't allowed.
+    #[display(fmt = "Unexpected panic: {_0:?}")]
+    UnexpectedPanic(PanicReason),
+    /// A custom policy rejected the receipts.
+    #[display(fmt = "{_0}")]
+    Custom(String),
+}
+
+/// Custom invariant checked over the receipts produced by a transaction.
+///
+/// The verifiers run after a successful execution. A transaction rejected by one of
+/// them is reported as [`crate::error::InterpreterError::ReceiptVerification`].
+pub trait ReceiptVerifier: core::fmt::Debug + Send + Sync {
+    /// Checks the receipts of the executed transaction.
+    fn verify(&self, receipts: &[Receipt]) -> Result<(), VerificationError>;
+}
+
+/// Rejects the transactions whose script used more gas than `limit`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GasUsedWithinLimit {
+    /// Maximum gas the script is allowed to use
+    pub limit: Word,
+}
+
+impl ReceiptVerifier for GasUsedWithinLimit {
+    fn verify(&self, receipts: &[Receipt]) -> Result<(), VerificationError> {
+        for receipt in receipts {
+            if let Receipt::ScriptResult { gas_used, .. } = receipt {
+                if *gas_used > self.limit {
+                    return Err(VerificationError::GasLimitExceeded {
+                        gas_used: *gas_used,
+                        limit: self.limit,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Rejects the transactions that panicked, unless the panic reason is one of
+/// `allowed_reasons`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NoUnexpectedPanics {
+    /// Panic reasons that are expected and accepted
+    pub allowed_reasons: Vec<PanicReason>,
+}
+
+impl ReceiptVerifier for NoUnexpectedPanics {
+    fn verify(&self, receipts: &[Receipt]) -> Result<(), VerificationError> {
+        for receipt in receipts {
+            if let Receipt::Panic { reason, .. } = receipt {
+                let reason = *reason.reason();
+                if !self.allowed_reasons.contains(&reason) {
+                    return Err(VerificationError::UnexpectedPanic(reason));
+                }
+            }
+        }
+        Ok(())
+    }
+}